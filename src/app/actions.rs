@@ -1,3 +1,5 @@
+use crate::ui::types::View;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Action {
     // Application Lifecycle
@@ -10,27 +12,62 @@ pub enum Action {
     SelectPageDown,
     SelectFirst,
     SelectLast,
+    NextProblem,
+    PrevProblem,
 
     // View Toggles
     ToggleHelp,
     HelpScrollUp,
     HelpScrollDown,
     TogglePause,
+    PauseChart,
+    ClearHistory,
     ToggleGraph,
+    ToggleMovingAverage,
+    CycleChartMetric,
     ToggleIndices,
     ToggleSystemIndices,
+    ToggleSystemInList,
+    ToggleSystemInRate,
     ToggleHealth,
+    GrowChart,
+    ShrinkChart,
+    WidenColumn,
+    NarrowColumn,
+    ToggleRowLayout,
+    NextView,
+    PrevView,
+    SelectView(View),
 
     // Data Operations
+    RetryNow,
     ShowDetails,
     ToggleExclude,
+    TogglePin,
     ClearExclusions,
+    UndoClearExclusions,
+    ToggleExcludedSection,
+    MarkSnapshot,
+    WatchSelected,
+    ShowIndexChart,
+    ShowHotThreads,
+    ExplainAllocation,
+    ShowClusterInfo,
+    EditReplicas,
 
     // Settings
     IncreaseRefreshRate,
     DecreaseRefreshRate,
+    IncreaseRateSamples,
+    DecreaseRateSamples,
     NextColormap,
     PrevColormap,
+    ToggleGradientDirection,
+    ToggleSizeBasis,
+    ToggleRawBytes,
+    CycleColorMode,
+    ToggleRollup,
+    ToggleUnhealthyFirst,
     NextColumn,
     PrevColumn,
     ToggleSortOrder,
@@ -39,11 +76,38 @@ pub enum Action {
     EnterFilterMode,
     ExitFilterMode,
     ClearFilter,
+    CopyFilter,
+    ShowFilterError,
+
+    // Replica Prompt
+    SubmitReplicas,
+    CancelReplicaPrompt,
 
     // Details Popup
     CloseDetails,
+    ToggleDetailsFullscreen,
+    ExportDetails,
+    RolloverDataStream,
+    LoadShards,
+    NextUnhealthyDetails,
+    PrevUnhealthyDetails,
     DetailsScrollUp,
     DetailsScrollDown,
     DetailsScrollPageUp,
     DetailsScrollPageDown,
+
+    // Index Chart Popup
+    CloseIndexChart,
+
+    // Text Popup
+    CloseTextPopup,
+    TextPopupScrollUp,
+    TextPopupScrollDown,
+    TextPopupScrollPageUp,
+    TextPopupScrollPageDown,
+
+    // Watch Mode
+    StopWatch,
+    WatchScrollUp,
+    WatchScrollDown,
 }
@@ -0,0 +1,133 @@
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use crate::utils::health_severity;
+
+// Minimum time between two alerts, so a flapping cluster doesn't spam. Escalations past
+// the last alerted status are never held back.
+const ALERT_DEBOUNCE: Duration = Duration::from_secs(30);
+// How long the header stays highlighted after an alert
+const ALERT_FLASH: Duration = Duration::from_secs(3);
+
+/// Bell/flash/command alerting on cluster health transitions.
+pub struct AlertState {
+    pub enabled: bool,
+    pub command: Option<String>,
+    /// Also alert when health improves, not only when it worsens
+    pub on_recovery: bool,
+    // When the last alert fired, and the severity of the status it was about
+    last_alert: Option<(Instant, u8)>,
+    flash_until: Option<Instant>,
+    bell_pending: bool,
+}
+
+impl AlertState {
+    pub fn new(enabled: bool, command: Option<String>, on_recovery: bool) -> Self {
+        Self {
+            enabled,
+            command,
+            on_recovery,
+            last_alert: None,
+            flash_until: None,
+            bell_pending: false,
+        }
+    }
+
+    /// Records a status transition and returns whether it raised an alert.
+    pub fn check(&mut self, old_status: &str, new_status: &str) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        // Ignore the first fetch and statuses we can't rank
+        let (Some(old), Some(new)) = (health_severity(old_status), health_severity(new_status))
+        else {
+            return false;
+        };
+        if new == old || (new < old && !self.on_recovery) {
+            return false;
+        }
+        if self
+            .last_alert
+            .is_some_and(|(at, severity)| new <= severity && at.elapsed() < ALERT_DEBOUNCE)
+        {
+            return false;
+        }
+
+        let now = Instant::now();
+        self.last_alert = Some((now, new));
+        self.flash_until = Some(now + ALERT_FLASH);
+        self.bell_pending = true;
+        true
+    }
+
+    pub fn is_flashing(&self) -> bool {
+        self.flash_until.is_some_and(|until| Instant::now() < until)
+    }
+
+    // Returns true once per alert, for the run loop to ring the terminal bell
+    pub fn take_bell(&mut self) -> bool {
+        std::mem::take(&mut self.bell_pending)
+    }
+}
+
+/// Spawns the configured alert command with the new status as its argument,
+/// without waiting for it to finish. It gets no terminal: the TUI owns the screen and keys.
+pub fn run_alert_command(command: &str, status: &str) -> std::io::Result<()> {
+    #[cfg(unix)]
+    let mut process = tokio::process::Command::new("sh");
+    #[cfg(unix)]
+    process
+        .arg("-c")
+        .arg(format!("{} \"$1\"", command))
+        .arg("esticli")
+        .arg(status);
+    #[cfg(not(unix))]
+    let mut process = tokio::process::Command::new("cmd");
+    #[cfg(not(unix))]
+    process.arg("/C").arg(format!("{} {}", command, status));
+
+    let mut child = process
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // Reap the process in the background
+    tokio::spawn(async move {
+        let _ = child.wait().await;
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alerts_only_on_worsening_by_default() {
+        let mut alert = AlertState::new(true, None, false);
+        assert!(!alert.check("", "green"));
+        assert!(!alert.check("yellow", "green"));
+        assert!(alert.check("green", "red"));
+        assert!(alert.take_bell());
+        assert!(!alert.take_bell());
+        assert!(alert.is_flashing());
+    }
+
+    #[test]
+    fn test_alerts_are_debounced() {
+        let mut alert = AlertState::new(true, None, true);
+        assert!(alert.check("green", "yellow"));
+        assert!(!alert.check("yellow", "green"));
+        // Worse than anything alerted so far, so it isn't held back
+        assert!(alert.check("green", "red"));
+        assert!(!alert.check("red", "yellow"));
+        assert!(!alert.check("yellow", "red"));
+    }
+
+    #[test]
+    fn test_disabled_never_alerts() {
+        let mut alert = AlertState::new(false, None, false);
+        assert!(!alert.check("green", "red"));
+    }
+}
@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use std::fs::OpenOptions;
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::export;
+use crate::models::{IndexRate, StatGroup};
+
+/// CSV snapshot of every index written on its own schedule (`--snapshot-export`),
+/// independent of the refresh interval.
+pub struct AutoExport {
+    pub path: PathBuf,
+    pub interval: Duration,
+    /// Add rows to the file instead of replacing it
+    pub append: bool,
+    last_written: Option<Instant>,
+}
+
+impl AutoExport {
+    pub fn new(path: PathBuf, interval: Duration, append: bool) -> Self {
+        Self {
+            path,
+            interval,
+            append,
+            last_written: None,
+        }
+    }
+
+    // Due right away, then once per interval
+    pub fn is_due(&self) -> bool {
+        self.last_written
+            .map_or(true, |written| written.elapsed() >= self.interval)
+    }
+
+    // A failed write still waits a full interval, so a bad path doesn't retry every frame
+    pub fn write(
+        &mut self,
+        indices: &[&IndexRate],
+        groups: &[StatGroup],
+        at: DateTime<Utc>,
+    ) -> io::Result<()> {
+        self.last_written = Some(Instant::now());
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(self.append)
+            .truncate(!self.append)
+            .open(&self.path)?;
+        // Appending keeps the header of the first snapshot only
+        let header = !self.append || file.metadata()?.len() == 0;
+        export::write_csv(BufWriter::new(file), indices, groups, at, header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DEFAULT_STAT_GROUPS;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_append_writes_header_once() {
+        let path = std::env::temp_dir().join(format!("esticli-export-{}.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let logs = IndexRate::named("logs");
+        let at = Utc.with_ymd_and_hms(2024, 2, 1, 12, 0, 0).unwrap();
+
+        let mut export = AutoExport::new(path.clone(), Duration::from_secs(3600), true);
+        assert!(export.is_due());
+        export.write(&[&logs], &DEFAULT_STAT_GROUPS, at).unwrap();
+        assert!(!export.is_due());
+        export.write(&[&logs], &DEFAULT_STAT_GROUPS, at).unwrap();
+        let appended = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(appended.lines().count(), 3);
+
+        // Overwriting leaves only the latest snapshot
+        export.append = false;
+        export.write(&[&logs], &DEFAULT_STAT_GROUPS, at).unwrap();
+        let replaced = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(replaced.lines().count(), 2);
+        assert!(replaced.starts_with("timestamp,name"));
+    }
+}
@@ -1,32 +1,56 @@
+use std::path::Path;
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::Mutex;
 
+use super::generational::GenerationalChannel;
 use crate::elasticsearch::EsClient;
-use crate::models::IndexDetails;
+use crate::models::{IndexDetails, ShardInfo};
 
 pub type DetailsResult = Result<IndexDetails, String>;
 
+// Shard allocation loaded on demand, with its raw response for export
+type ShardsResult = Result<(Vec<ShardInfo>, serde_json::Value), String>;
+
 pub struct DetailsState {
     pub show_popup: bool,
+    /// Index the popup is showing or loading
+    pub index: Option<String>,
     pub data: Option<IndexDetails>,
     pub loading: bool,
     pub error: Option<String>,
     pub scroll: usize,
-    pub rx: mpsc::Receiver<DetailsResult>,
-    pub tx: mpsc::Sender<DetailsResult>,
+    /// Take over the whole terminal instead of the centered overlay
+    pub fullscreen: bool,
+    /// Outcome of the last export or rollover, shown in the popup footer; `Err` for failures
+    pub status: Option<Result<String, String>>,
+    /// A first `O` asked to confirm the rollover; the next `O` performs it
+    pub rollover_armed: bool,
+    /// Shards were asked for, so refetches include them even when they load lazily
+    pub shards_requested: bool,
+    pub shards_loading: bool,
+    pub shards_error: Option<String>,
+    results: GenerationalChannel<DetailsResult>,
+    /// Invalidated along with `results`, so shards never land on another index's details
+    shards: GenerationalChannel<ShardsResult>,
 }
 
 impl DetailsState {
     pub fn new() -> Self {
-        let (tx, rx) = mpsc::channel(1);
         Self {
             show_popup: false,
+            index: None,
             data: None,
             loading: false,
             error: None,
             scroll: 0,
-            rx,
-            tx,
+            fullscreen: false,
+            status: None,
+            rollover_armed: false,
+            shards_requested: false,
+            shards_loading: false,
+            shards_error: None,
+            results: GenerationalChannel::new(),
+            shards: GenerationalChannel::new(),
         }
     }
 
@@ -37,56 +61,127 @@ impl DetailsState {
         doc_count: u64,
         rate_per_sec: f64,
         size_bytes: u64,
+        lazy_shards: bool,
     ) {
         self.show_popup = true;
+        self.index = Some(index_name.clone());
         self.loading = true;
         self.error = None;
         self.data = None;
         self.scroll = 0;
+        self.status = None;
+        self.rollover_armed = false;
+        self.shards_loading = false;
+        self.shards_error = None;
+        self.invalidate();
 
-        let tx = self.tx.clone();
-
-        tokio::spawn(async move {
-            let result = {
-                let client = es_client.lock().await;
-                client
-                    .fetch_index_details(&index_name, doc_count, rate_per_sec, size_bytes)
-                    .await
-            };
+        let include_shards = !lazy_shards || self.shards_requested;
 
-            let details_result = result.map_err(|e| e.to_string());
-            let _ = tx.send(details_result).await;
+        self.results.spawn(async move {
+            let client = es_client.lock().await;
+            client
+                .fetch_index_details(
+                    &index_name,
+                    doc_count,
+                    rate_per_sec,
+                    size_bytes,
+                    include_shards,
+                )
+                .await
+                .map_err(|e| e.to_string())
         });
     }
 
     pub fn close(&mut self) {
         self.show_popup = false;
+        self.index = None;
         self.data = None;
         self.error = None;
         self.loading = false;
         self.scroll = 0;
+        self.fullscreen = false;
+        self.status = None;
+        self.rollover_armed = false;
+        self.shards_requested = false;
+        self.shards_loading = false;
+        self.shards_error = None;
+        self.invalidate();
+    }
+
+    // Drops details and shards of every fetch started so far
+    fn invalidate(&mut self) {
+        self.results.invalidate();
+        self.shards.invalidate();
+    }
+
+    // Fetch the shard allocation left out of the loaded details
+    pub fn load_shards(&mut self, es_client: Arc<Mutex<EsClient>>) {
+        let Some(ref details) = self.data else {
+            return;
+        };
+        if details.shard_allocation.is_some() || self.shards_loading {
+            return;
+        }
+
+        self.shards_requested = true;
+        self.shards_loading = true;
+        self.shards_error = None;
+
+        let index_name = details.name.clone();
+
+        self.shards.spawn(async move {
+            let client = es_client.lock().await;
+            client
+                .fetch_shard_allocation(&index_name)
+                .await
+                .map_err(|e| e.to_string())
+        });
+    }
+
+    // Write the loaded details, raw responses included, to `<index>-details.json` in `dir`
+    pub fn export(&mut self, dir: &Path) {
+        let Some(ref details) = self.data else {
+            return;
+        };
+
+        let path = dir.join(format!("{}-details.json", details.name));
+        let result = serde_json::to_vec_pretty(details)
+            .map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+
+        self.status = Some(match result {
+            Ok(()) => Ok(format!("Exported to {}", path.display())),
+            Err(e) => Err(format!("Export failed: {}", e)),
+        });
+    }
+
+    pub fn toggle_fullscreen(&mut self) {
+        self.fullscreen = !self.fullscreen;
     }
 
     pub fn poll(&mut self) {
-        match self.rx.try_recv() {
-            Ok(result) => {
-                self.loading = false;
-                match result {
-                    Ok(details) => {
-                        self.data = Some(details);
-                        self.error = None;
-                    }
-                    Err(e) => {
-                        self.error = Some(e);
-                    }
+        if let Some(result) = self.results.poll() {
+            self.loading = false;
+            match result {
+                Ok(details) => {
+                    self.data = Some(details);
+                    self.error = None;
+                }
+                Err(e) => {
+                    self.error = Some(e);
                 }
             }
-            Err(mpsc::error::TryRecvError::Empty) => {
-                // No result yet
-            }
-            Err(mpsc::error::TryRecvError::Disconnected) => {
-                self.loading = false;
-                self.error = Some("Details fetch disconnected".to_string());
+        }
+
+        while let Some(result) = self.shards.poll() {
+            self.shards_loading = false;
+            match (result, self.data.as_mut()) {
+                (Ok((shards, raw)), Some(details)) => {
+                    details.shard_allocation = Some(shards);
+                    details.raw_responses.insert("cat_shards".to_string(), raw);
+                }
+                (Ok(_), None) => {}
+                (Err(e), _) => self.shards_error = Some(e),
             }
         }
     }
@@ -107,3 +202,45 @@ impl DetailsState {
         self.scroll = self.scroll.saturating_add(page_size);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_ignores_outdated_generation() {
+        let mut details = DetailsState::new();
+        details.invalidate();
+        details.invalidate();
+        details.loading = true;
+
+        // A result from an earlier fetch must not be shown
+        details.results.complete(1, Err("stale".to_string()));
+        details.poll();
+        assert!(details.loading);
+        assert!(details.error.is_none());
+
+        details.results.complete(2, Err("current".to_string()));
+        details.poll();
+        assert!(!details.loading);
+        assert_eq!(details.error.as_deref(), Some("current"));
+    }
+
+    #[test]
+    fn test_poll_ignores_shards_of_outdated_generation() {
+        let mut details = DetailsState::new();
+        details.invalidate();
+        details.invalidate();
+        details.shards_loading = true;
+
+        details.shards.complete(1, Err("stale".to_string()));
+        details.poll();
+        assert!(details.shards_loading);
+        assert!(details.shards_error.is_none());
+
+        details.shards.complete(2, Err("timed out".to_string()));
+        details.poll();
+        assert!(!details.shards_loading);
+        assert_eq!(details.shards_error.as_deref(), Some("timed out"));
+    }
+}
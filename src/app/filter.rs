@@ -1,19 +1,40 @@
-use jaq_core::{load, Compiler, Ctx, Native, RcIter};
+use jaq_core::{load, Compiler, Ctx, Native, RcIter, ValT};
 use jaq_json::Val;
 use serde::Serialize;
+use std::fmt::Write;
+use std::ops::Range;
 use std::sync::Arc;
-use tui_input::Input;
+use std::time::{Duration, Instant};
+use tui_input::{Input, InputRequest};
+
+// Idle time after the last keystroke before the filter is recompiled
+const RECOMPILE_DEBOUNCE: Duration = Duration::from_millis(150);
 
 /// Compiled filter that can be reused across multiple matches
 type CompiledFilter = Arc<jaq_core::Filter<Native<Val>>>;
 
+/// One problem found while compiling a filter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterError {
+    pub message: String,
+    /// Byte range of the offending part of the filter text, when jaq points at one
+    pub span: Option<Range<usize>>,
+}
+
 #[derive(Default)]
 pub struct FilterState {
     pub active: bool,
     pub input: Input,
+    /// All compile errors on one line, for the table title
     pub error: Option<String>,
+    /// The compile errors behind `error`, with positions for the error popup
+    pub error_details: Vec<FilterError>,
     /// Cached compiled filter - only recompiled when input changes
     compiled: Option<CompiledFilter>,
+    /// Literal the index name is matched against, for highlighting; None for other filters
+    name_match: Option<String>,
+    /// Time of the last edit not yet compiled
+    edited_at: Option<Instant>,
 }
 
 impl FilterState {
@@ -23,34 +44,102 @@ impl FilterState {
 
     pub fn exit(&mut self) {
         self.active = false;
+        // Apply any pending edit right away instead of waiting for the debounce
+        if self.edited_at.is_some() {
+            self.recompile();
+        }
     }
 
     pub fn clear(&mut self) {
         self.input.reset();
         self.error = None;
+        self.error_details.clear();
         self.compiled = None;
+        self.name_match = None;
+        self.edited_at = None;
         self.active = false;
     }
 
+    // Record an edit; compilation is deferred until typing pauses
+    pub fn mark_edited(&mut self) {
+        self.edited_at = Some(Instant::now());
+    }
+
+    // Recompiles once no edit happened for the debounce interval
+    pub fn recompile_if_idle(&mut self) {
+        if self
+            .edited_at
+            .is_some_and(|edited| edited.elapsed() >= RECOMPILE_DEBOUNCE)
+        {
+            self.recompile();
+        }
+    }
+
+    // Insert pasted text at the cursor and compile right away, so a broken filter shows its error
+    pub fn paste(&mut self, text: &str) {
+        // The input is a single line: line breaks and other control characters become spaces
+        for c in text.trim_end().chars() {
+            let c = if c.is_control() { ' ' } else { c };
+            self.input.handle(InputRequest::InsertChar(c));
+        }
+        self.recompile();
+    }
+
     pub fn recompile(&mut self) {
+        self.edited_at = None;
+        self.name_match = None;
+        self.error = None;
+        self.error_details.clear();
         let text = self.input.value();
         if text.is_empty() {
-            self.error = None;
             self.compiled = None;
         } else {
             match compile_filter(text) {
                 Ok(filter) => {
-                    self.error = None;
                     self.compiled = Some(Arc::new(filter));
+                    self.name_match = name_match(text);
                 }
-                Err(e) => {
-                    self.error = Some(e);
+                Err(errors) => {
+                    let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+                    self.error = Some(messages.join(", "));
+                    self.error_details = errors;
                     self.compiled = None;
                 }
             }
         }
     }
 
+    /// The filter with each compile error pointed out beneath it, for the error popup.
+    pub fn error_report(&self) -> Option<String> {
+        if self.error_details.is_empty() {
+            return None;
+        }
+        let text = self.input.value();
+        let mut report = String::new();
+        for error in &self.error_details {
+            let _ = writeln!(report, "{}", text);
+            if let Some(ref span) = error.span {
+                // Columns count characters, so multi-byte text keeps the markers aligned
+                let start = text[..span.start].chars().count();
+                let width = text[span.clone()].chars().count().max(1);
+                let _ = writeln!(report, "{}{}", " ".repeat(start), "^".repeat(width));
+            }
+            let _ = writeln!(report, "{}", error.message);
+            let _ = writeln!(report);
+        }
+        Some(report.trim_end().to_string())
+    }
+
+    /// Whether a compiled filter is narrowing the table
+    pub fn is_applied(&self) -> bool {
+        self.compiled.is_some()
+    }
+
+    /// Substring the applied filter requires in the index name, if it is that simple
+    pub fn name_match(&self) -> Option<&str> {
+        self.name_match.as_deref()
+    }
+
     pub fn is_match<T: Serialize>(&self, item: &T) -> bool {
         // No filter or error means match everything
         let Some(filter) = &self.compiled else {
@@ -64,16 +153,40 @@ impl FilterState {
                 let val = Val::from(json);
                 let mut results = filter.run((Ctx::new([], &inputs), val));
 
-                // For select() filters, a match produces output; no match produces nothing
-                results.next().is_some()
+                // select() filters output the index or nothing; predicates like `.doc_count > 1000`
+                // output a boolean, so false and null outputs don't count as a match. Only the
+                // first output decides, so a filter emitting many (or endless) values stops early.
+                // A runtime error keeps the old "produces output" rule and matches.
+                results
+                    .next()
+                    .is_some_and(|result| result.map_or(true, |val| val.as_bool()))
             }
             Err(_) => true,
         }
     }
 }
 
+/// Recognizes `select(.name | contains("..."))` (or `startswith`/`endswith`) and returns
+/// the literal; arbitrary jq filters have no single substring to point at.
+fn name_match(filter: &str) -> Option<String> {
+    let inner = filter.trim().strip_prefix("select(")?.strip_suffix(')')?;
+    let (field, test) = inner.split_once('|')?;
+    if field.trim() != ".name" {
+        return None;
+    }
+    let test = test.trim();
+    let argument = ["contains(", "startswith(", "endswith("]
+        .iter()
+        .find_map(|function| test.strip_prefix(function))?
+        .strip_suffix(')')?;
+    // The argument is a JSON string literal, escapes included
+    serde_json::from_str::<String>(argument.trim())
+        .ok()
+        .filter(|literal| !literal.is_empty())
+}
+
 /// Compile a jq filter expression (called once when filter text changes)
-fn compile_filter(filter_str: &str) -> Result<jaq_core::Filter<Native<Val>>, String> {
+fn compile_filter(filter_str: &str) -> Result<jaq_core::Filter<Native<Val>>, Vec<FilterError>> {
     // Create the program
     let program = load::File {
         code: filter_str,
@@ -86,9 +199,8 @@ fn compile_filter(filter_str: &str) -> Result<jaq_core::Filter<Native<Val>>, Str
 
     let modules = loader.load(&arena, program).map_err(|errs| {
         errs.into_iter()
-            .map(|e| format!("{:?}", e.1))
+            .flat_map(|(_, error)| load_errors(filter_str, error))
             .collect::<Vec<_>>()
-            .join(", ")
     })?;
 
     // Compile with standard library functions
@@ -97,14 +209,75 @@ fn compile_filter(filter_str: &str) -> Result<jaq_core::Filter<Native<Val>>, Str
         .compile(modules)
         .map_err(|errs| {
             errs.into_iter()
-                .map(|e| format!("{:?}", e.1))
+                .flat_map(|(_, undefined)| undefined)
+                .map(|(name, undefined)| {
+                    let arity = match undefined {
+                        jaq_core::compile::Undefined::Filter(arity) => format!("/{}", arity),
+                        _ => String::new(),
+                    };
+                    FilterError {
+                        message: format!("undefined {} {}{}", undefined.as_str(), name, arity),
+                        span: span(filter_str, name),
+                    }
+                })
                 .collect::<Vec<_>>()
-                .join(", ")
         })?;
 
     Ok(filter)
 }
 
+// Readable lexer and parser errors, located in the filter text
+fn load_errors(code: &str, error: load::Error<&str>) -> Vec<FilterError> {
+    match error {
+        load::Error::Io(errors) => errors
+            .into_iter()
+            .map(|(path, message)| FilterError {
+                message: format!("{}: {}", path, message),
+                span: None,
+            })
+            .collect(),
+        load::Error::Lex(errors) => errors
+            .into_iter()
+            .map(|(expect, found)| FilterError {
+                message: format!("expected {}", lex_expectation(&expect)),
+                span: span(code, found).map(|span| span.start..span.start),
+            })
+            .collect(),
+        load::Error::Parse(errors) => errors
+            .into_iter()
+            .map(|(expect, found)| {
+                let found_text = if found.is_empty() {
+                    "end of filter".to_string()
+                } else {
+                    format!("'{}'", found)
+                };
+                FilterError {
+                    message: format!("expected {}, found {}", expect.as_str(), found_text),
+                    span: span(code, found),
+                }
+            })
+            .collect(),
+    }
+}
+
+// Byte range of `part` in `code`; jaq's errors borrow from the text they were parsed from,
+// but a name from the standard library definitions would lie outside the filter
+fn span(code: &str, part: &str) -> Option<Range<usize>> {
+    let start = (part.as_ptr() as usize).checked_sub(code.as_ptr() as usize)?;
+    let end = start + part.len();
+    (end <= code.len()).then_some(start..end)
+}
+
+// `lex::Expect::as_str` panics on delimiters it doesn't name
+fn lex_expectation(expect: &load::lex::Expect<&str>) -> String {
+    match expect {
+        load::lex::Expect::Delim(delim) if !["(", "[", "{", "\""].contains(delim) => {
+            format!("closing {}", delim)
+        }
+        _ => expect.as_str().to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,10 +307,40 @@ mod tests {
         assert!(filter.error.is_some());
     }
 
+    #[test]
+    fn test_error_report_points_at_problem() {
+        let mut filter = FilterState {
+            input: "select(.name | foo(\"x\"))".into(),
+            ..Default::default()
+        };
+        filter.recompile();
+        assert_eq!(filter.error.as_deref(), Some("undefined filter foo/1"));
+        assert_eq!(
+            filter.error_report().unwrap(),
+            "select(.name | foo(\"x\"))\n               ^^^\nundefined filter foo/1"
+        );
+
+        filter.input = "select(.a > )".into();
+        filter.recompile();
+        assert_eq!(
+            filter.error_details,
+            vec![FilterError {
+                message: "expected term, found ')'".to_string(),
+                span: Some(12..13),
+            }]
+        );
+
+        filter.input = "select(.a > 1)".into();
+        filter.recompile();
+        assert_eq!(filter.error_report(), None);
+    }
+
     #[test]
     fn test_filter_clear() {
-        let mut filter = FilterState::default();
-        filter.input = "select(.name == \"test\")".into();
+        let mut filter = FilterState {
+            input: "select(.name == \"test\")".into(),
+            ..Default::default()
+        };
         filter.recompile();
         filter.enter();
 
@@ -155,7 +358,10 @@ mod tests {
             active: false,
             input: "select(.doc_count > 1000)".into(),
             error: None,
+            error_details: Vec::new(),
             compiled: None,
+            name_match: None,
+            edited_at: None,
         };
         filter_state.recompile();
 
@@ -163,25 +369,109 @@ mod tests {
         assert!(!filter_state.is_match(&serde_json::json!({"doc_count": 500})));
     }
 
+    #[test]
+    fn test_predicate_filter_without_select() {
+        let mut filter = FilterState {
+            input: ".doc_count > 1000".into(),
+            ..Default::default()
+        };
+        filter.recompile();
+        assert!(filter.is_match(&serde_json::json!({"doc_count": 2000})));
+        assert!(!filter.is_match(&serde_json::json!({"doc_count": 500})));
+
+        // A null output is falsy too, as in jq's `if`
+        filter.input = ".missing".into();
+        filter.recompile();
+        assert!(!filter.is_match(&serde_json::json!({"doc_count": 500})));
+
+        // select() still matches by producing the (truthy) index
+        filter.input = "select(.doc_count > 1000)".into();
+        filter.recompile();
+        assert!(filter.is_match(&serde_json::json!({"doc_count": 2000})));
+        assert!(!filter.is_match(&serde_json::json!({"doc_count": 500})));
+    }
+
+    #[test]
+    fn test_runtime_error_filter_matches() {
+        // Indexing into a number compiles but fails on every index
+        let mut filter = FilterState {
+            input: ".doc_count[0]".into(),
+            ..Default::default()
+        };
+        filter.recompile();
+        assert!(filter.error.is_none());
+        assert!(filter.is_match(&serde_json::json!({"doc_count": 500})));
+
+        // The first output decides, even when more (here: endless) outputs follow
+        filter.input = "false, repeat(true)".into();
+        filter.recompile();
+        assert!(!filter.is_match(&serde_json::json!({"doc_count": 500})));
+    }
+
     #[test]
     fn test_filter_string_contains() {
         let mut filter_state = FilterState {
             active: false,
             input: "select(.name | contains(\"test\"))".into(),
             error: None,
+            error_details: Vec::new(),
             compiled: None,
+            name_match: None,
+            edited_at: None,
         };
         filter_state.recompile();
 
         assert!(filter_state.is_match(&serde_json::json!({"name": "my-test-index"})));
         assert!(!filter_state.is_match(&serde_json::json!({"name": "production-index"})));
+        assert_eq!(filter_state.name_match(), Some("test"));
+    }
+
+    #[test]
+    fn test_name_match_only_for_simple_name_filters() {
+        assert_eq!(
+            name_match(r#" select( .name | startswith("logs-\"x") ) "#),
+            Some("logs-\"x".to_string())
+        );
+        assert_eq!(name_match(r#"select(.health | contains("ye"))"#), None);
+        assert_eq!(name_match(r#"select(.name | test("^logs"))"#), None);
+        assert_eq!(name_match("select(.doc_count > 1000)"), None);
+    }
+
+    #[test]
+    fn test_paste_compiles_immediately() {
+        let mut filter = FilterState::default();
+        filter.paste("select(.doc_count >\n 1000)\n");
+
+        assert_eq!(filter.input.value(), "select(.doc_count >  1000)");
+        assert!(!filter.is_match(&serde_json::json!({"doc_count": 5})));
+
+        filter.paste(" and");
+        assert!(filter.error.is_some());
+    }
+
+    #[test]
+    fn test_filter_recompile_is_debounced() {
+        let mut filter = FilterState::default();
+        filter.enter();
+        filter.input = "select(.doc_count > 1000)".into();
+        filter.mark_edited();
+
+        // Still typing: the previous (empty) filter stays in effect
+        filter.recompile_if_idle();
+        assert!(filter.is_match(&serde_json::json!({"doc_count": 5})));
+
+        // Leaving filter mode applies the pending edit immediately
+        filter.exit();
+        assert!(!filter.is_match(&serde_json::json!({"doc_count": 5})));
     }
 
     #[test]
     fn test_filter_performance() {
         // Verify that multiple matches reuse the compiled filter
-        let mut filter_state = FilterState::default();
-        filter_state.input = "select(.doc_count > 100)".into();
+        let mut filter_state = FilterState {
+            input: "select(.doc_count > 100)".into(),
+            ..Default::default()
+        };
         filter_state.recompile();
 
         // This should be fast since filter is pre-compiled
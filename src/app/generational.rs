@@ -0,0 +1,76 @@
+use std::future::Future;
+use tokio::sync::mpsc;
+
+/// Results of background fetches, each tagged with the generation it was started in.
+/// Only results of the current generation come out of `poll`; `invalidate` supersedes
+/// everything still in flight.
+pub struct GenerationalChannel<T> {
+    rx: mpsc::Receiver<(u64, T)>,
+    tx: mpsc::Sender<(u64, T)>,
+    /// Bumped on every fetch and close, so results from superseded fetches are dropped
+    generation: u64,
+}
+
+impl<T: Send + 'static> GenerationalChannel<T> {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::channel(1);
+        Self {
+            rx,
+            tx,
+            generation: 0,
+        }
+    }
+
+    pub fn invalidate(&mut self) {
+        self.generation += 1;
+    }
+
+    // Resolves `task` in the background and sends its result tagged with the current generation
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = T> + Send + 'static,
+    {
+        let tx = self.tx.clone();
+        let generation = self.generation;
+        tokio::spawn(async move {
+            let _ = tx.send((generation, task.await)).await;
+        });
+    }
+
+    // The current generation's result once it arrived. The channel keeps a sender of its
+    // own, so it never disconnects; no result yet and a superseded one look the same.
+    pub fn poll(&mut self) -> Option<T> {
+        // Drain results of superseded fetches until the current one (or nothing) is left
+        while let Ok((generation, result)) = self.rx.try_recv() {
+            if generation == self.generation {
+                return Some(result);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+impl<T> GenerationalChannel<T> {
+    // Stands in for a task of `generation` finishing with `result`
+    pub fn complete(&self, generation: u64, result: T) {
+        self.tx.try_send((generation, result)).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_drops_superseded_results() {
+        let mut channel = GenerationalChannel::new();
+        channel.invalidate();
+        channel.complete(0, "stale");
+        assert_eq!(channel.poll(), None);
+
+        channel.complete(1, "current");
+        assert_eq!(channel.poll(), Some("current"));
+        assert_eq!(channel.poll(), None);
+    }
+}
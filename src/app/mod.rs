@@ -1,32 +1,100 @@
 pub mod actions;
+pub mod alert;
+pub mod auto_export;
 pub mod details;
 pub mod filter;
+pub mod generational;
+pub mod popup;
+pub mod replicas;
+pub mod rollup;
+pub mod snapshot;
 pub mod sort;
+pub mod watch;
+pub mod webhook;
 
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use crate::config::NameTransform;
 use crate::elasticsearch::{AuthConfig, EsClient};
-use crate::error::{EstiCliError, Result};
-use crate::models::{ClusterHealth, IndexRate};
-use crate::ui::types::Colormap;
-use crate::utils::{format_bytes, format_number};
+use crate::error::Result;
+use crate::models::{ClusterHealth, IndexRate, NodeHeap, ShardDistribution, StatGroup};
+use crate::ui::theme::Theme;
+use crate::ui::types::{
+    ChartMetric, ColorMode, Colormap, GradientScale, RateUnit, RowLayout, SelectionStyle,
+    SortColumn, SpinnerStyle, StatusIndicator, Timezone, View,
+};
+use crate::utils::{
+    finite_or_zero, format_bytes, format_bytes_exact, format_number, glob_matches, health_worsened,
+};
 use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+use url::Url;
 
 use self::actions::Action;
+use self::alert::{run_alert_command, AlertState};
+use self::auto_export::AutoExport;
 use self::details::DetailsState;
 use self::filter::FilterState;
+use self::popup::TextPopupState;
+use self::replicas::ReplicaPrompt;
+use self::rollup::{RowKind, TableRow};
+use self::snapshot::Snapshot;
 use self::sort::SortState;
+use self::watch::WatchState;
+use self::webhook::HealthChangePayload;
 
 const MAX_HISTORY_POINTS: usize = 60;
 const MIN_REFRESH_SECS: u64 = 1;
 const MAX_REFRESH_SECS: u64 = 60;
-
-pub type FetchResult = std::result::Result<(Vec<IndexRate>, ClusterHealth), EstiCliError>;
-
-const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+// Rate smoothing window adjustable at runtime, in samples
+const MAX_RATE_SAMPLES: usize = MAX_HISTORY_POINTS;
+const DEFAULT_CHART_SPLIT: u16 = 70;
+const MIN_CHART_SPLIT: u16 = 20;
+const MAX_CHART_SPLIT: u16 = 85;
+const CHART_SPLIT_STEP: u16 = 5;
+// Relative table column widths, indexed by `SortColumn` and normalized to fill the table
+const DEFAULT_COLUMN_WIDTHS: [u16; 9] = [50, 10, 10, 10, 10, 10, 8, 11, 11];
+const MIN_COLUMN_WIDTH: u16 = 5;
+const MAX_COLUMN_WIDTH: u16 = 80;
+const COLUMN_WIDTH_STEP: u16 = 5;
+const DEFAULT_SYSTEM_PATTERN: &str = ".*";
+const SPINNER_FRAME_INTERVAL: Duration = Duration::from_millis(80);
+// Delay before reconnecting after the fetch channel is lost, doubling up to the max
+const RECONNECT_BACKOFF_MIN: Duration = Duration::from_secs(1);
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+// Cluster status shown when `_cluster/health` fails but index rates came through
+const UNKNOWN_HEALTH: &str = "unknown";
+
+// How long the footer offers to undo clearing the exclusions
+const EXCLUSIONS_UNDO_HINT: Duration = Duration::from_secs(5);
+
+// How long the footer shows a status message (copied filter, applied setting)
+const STATUS_MESSAGE: Duration = Duration::from_secs(3);
+
+// How long the chart confirms a history reset
+const HISTORY_CLEARED_NOTICE: Duration = Duration::from_secs(3);
+
+// Number of `_cluster/health` round trips averaged into the displayed API latency
+const LATENCY_SAMPLES: usize = 5;
+
+// Rates and health succeed or fail independently, so a `_cluster/health` hiccup keeps
+// the indices table and a failed stats call keeps the last good indices.
+// Shard distribution is optional: when `_cat/shards` fails the previous one is kept.
+// Node heap is only fetched with `--heap-warning`, and likewise kept when the call fails.
+// The duration is the round trip of the lightweight `_cluster/health` call alone.
+pub type FetchResult = (
+    Result<Vec<IndexRate>>,
+    Result<ClusterHealth>,
+    Option<ShardDistribution>,
+    Option<Vec<NodeHeap>>,
+    Duration,
+);
 
 /// Aggregated metrics for cluster-wide indexing performance.
 #[derive(Debug, Clone, Copy, Default)]
@@ -35,8 +103,94 @@ pub struct ClusterMetrics {
     pub rate_per_sec: f64,
     /// Total bytes indexed per second across all indices
     pub bytes_per_sec: f64,
+    /// Total documents stored across all indices
+    pub doc_count: u64,
+}
+
+/// Sums over the indices the filter lets through, for the footer.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FilterTotals {
+    pub rate_per_sec: f64,
+    pub doc_count: u64,
+    /// Primaries only or with replicas, following the Size column
+    pub size_bytes: u64,
+}
+
+/// Startup configuration for [`App`], assembled from the command line.
+pub struct AppConfig {
+    pub base_url: String,
+    pub auth: AuthConfig,
+    pub insecure: bool,
+    pub ca_cert: Option<PathBuf>,
+    pub refresh_secs: u64,
+    pub connect_timeout_secs: u64,
+    pub timeout_secs: u64,
+    pub max_error_body: usize,
+    pub colormap: Colormap,
+    /// Per-column overrides of `colormap`, from the config file
+    pub column_colormaps: HashMap<SortColumn, Colormap>,
+    /// Column widths from the config file, overriding the defaults
+    pub column_widths: Vec<(SortColumn, u16)>,
+    pub rate_samples: usize,
+    pub theme: Theme,
+    pub avg_window: usize,
+    pub timezone: Timezone,
+    pub rate_unit: RateUnit,
+    pub gradient_scale: GradientScale,
+    pub spinner_style: SpinnerStyle,
+    /// Spinner glyphs or "loading"/"idle" words in the indices title
+    pub status_indicator: StatusIndicator,
+    /// Highlight of the selected row, from the config file
+    pub selection_style: SelectionStyle,
+    /// Alternate row background in the indices table, from the config file
+    pub zebra: bool,
+    pub row_layout: RowLayout,
+    pub compact: bool,
+    pub total_size: bool,
+    pub watch: Option<String>,
+    pub alert: bool,
+    pub alert_command: Option<String>,
+    pub alert_recovery: bool,
+    pub webhook_url: Option<Url>,
+    /// Globs naming system indices; empty falls back to dot-prefixed names
+    pub system_patterns: Vec<String>,
+    /// Only indices matching one of these globs are shown (all when empty)
+    pub include_patterns: Vec<String>,
+    /// Indices matching any of these globs are hidden
+    pub exclude_patterns: Vec<String>,
+    pub age_tiers: Vec<chrono::Duration>,
+    pub index_pattern: Option<String>,
+    /// `_stats` groups requested on each refresh; columns of the others show `-`
+    pub stat_groups: Vec<StatGroup>,
+    pub rollup_separators: String,
+    /// Cap on concurrent index details requests; unbounded when unset
+    pub details_concurrency: Option<NonZeroUsize>,
+    /// Leave shard allocation out of index details until asked for
+    pub lazy_shards: bool,
+    /// Directory receiving a copy of every raw JSON response
+    pub dump_dir: Option<PathBuf>,
+    /// Poll node JVM heap and warn at this used percentage; off when unset
+    pub heap_warning: Option<u64>,
+    /// Allow write operations on the cluster, such as changing replica counts
+    pub allow_destructive: bool,
+    /// CSV file receiving a snapshot of every index each `snapshot_interval`
+    pub snapshot_export: Option<PathBuf>,
+    pub snapshot_interval: Duration,
+    /// Append snapshots to `snapshot_export` instead of overwriting it
+    pub snapshot_append: bool,
+    /// Pause refreshing while the terminal has lost focus
+    pub pause_on_blur: bool,
+    /// Display-only index name rewrites, from the config file
+    pub name_transforms: Vec<NameTransform>,
 }
 
+// Index, requested replica count and the outcome of the settings update
+type ReplicasUpdate = (String, u32, std::result::Result<bool, String>);
+
+// Index whose details asked for the rollover, its data stream, and the new write index or
+// why the rollover failed
+type RolloverOutcome = (String, String, std::result::Result<String, String>);
+
 /// Main application state and logic controller.
 ///
 /// This struct holds all the state necessary to render the TUI and handles
@@ -45,103 +199,332 @@ pub struct App {
     pub indices: Vec<IndexRate>,
     pub running: bool,
     pub error: Option<String>,
+    /// Why the last `_cluster/health` call failed, while health shows as unknown
+    pub health_error: Option<String>,
     pub loading: bool,
     pub spinner_frame: usize,
+    pub spinner_style: SpinnerStyle,
+    pub status_indicator: StatusIndicator,
+    pub selection_style: SelectionStyle,
+    pub zebra: bool,
+    /// Shorten index names in the table; everything else keeps using the real name
+    pub name_transforms: Vec<NameTransform>,
     pub refresh_interval: Duration,
     pub last_refresh: Option<Instant>,
     pub rate_history: VecDeque<u64>,
+    pub bytes_rate_history: VecDeque<u64>,
+    pub doc_count_history: VecDeque<u64>,
+    /// Cluster-wide unassigned shards, from `_cluster/health`
+    pub unassigned_history: VecDeque<u64>,
+    /// Set by a history reset, so the chart can confirm it for a moment
+    history_cleared_until: Option<Instant>,
+    pub chart_metric: ChartMetric,
     pub es_url: String,
+    /// When a rates fetch last succeeded; `last_refresh` also counts failed attempts. Unset
+    /// until the first success, telling "connecting" apart from an empty cluster.
+    pub last_success: Option<Instant>,
+    /// `--index` pattern the stats requests are scoped to
+    pub index_pattern: Option<String>,
+    /// `--stats` groups the refresh requests
+    pub stat_groups: Vec<StatGroup>,
+    /// Index details skip `_cat/shards` until `s` is pressed
+    pub lazy_shards: bool,
+    /// Heap used percentage that triggers the header warning, when heap is polled
+    pub heap_warning: Option<u64>,
+    /// Latest `_nodes/stats/jvm` heap usage, empty unless heap is polled
+    pub node_heap: Vec<NodeHeap>,
+    /// Collapse indices sharing a prefix (e.g. `logs-*`) into one summed row
+    pub rollup: bool,
+    /// Yellow and red indices above green ones, within the chosen sort order
+    pub unhealthy_first: bool,
+    /// Separators after which a digit starts the varying part of a rolled-up name
+    pub rollup_separators: String,
+    pub expanded_groups: HashSet<String>,
+    /// List excluded indices in their own section under the table instead of hiding them
+    pub excluded_section: bool,
+    pub excluded_section_expanded: bool,
     pub fetch_start: Option<Instant>,
     pub last_fetch_duration: Option<Duration>,
+    /// Recent `_cluster/health` round trips, newest last
+    pub api_latency: VecDeque<Duration>,
     pub show_graph: bool,
+    pub show_moving_average: bool,
+    pub avg_window: usize,
     pub show_health: bool,
+    /// Percentage of the chart/health row given to the chart when both are visible
+    pub chart_split: u16,
+    /// Relative widths of the table columns, in `SortColumn` order
+    pub column_widths: [u16; 9],
+    pub row_layout: RowLayout,
+    /// Single-line header/footer without borders, for small terminals
+    pub compact: bool,
+    /// Size column and details show primaries plus replicas instead of primaries only
+    pub show_total_size: bool,
+    /// Sizes show exact byte counts instead of rounded binary units
+    pub show_raw_bytes: bool,
+    pub view: View,
+    /// Doc counts/sizes marked for the compare view
+    pub snapshot: Option<Snapshot>,
+    /// Single index being watched in the focused layout
+    pub watch: Option<WatchState>,
+    pub alert: AlertState,
     pub show_indices: bool,
-    pub show_system_indices: bool,
+    /// System indices are listed in the table
+    pub show_system_in_list: bool,
+    /// System indices count toward the cluster rate headline and graph
+    pub count_system_in_rate: bool,
+    /// Globs deciding which indices count as system indices
+    pub system_patterns: Vec<String>,
+    /// Startup `--include`/`--exclude` globs, applied alongside the jq filter
+    pub include_patterns: Vec<String>,
+    pub exclude_patterns: Vec<String>,
     pub paused: bool,
+    /// `--pause-on-blur`: refreshing stops while the terminal is unfocused
+    pub pause_on_blur: bool,
+    /// Paused by losing focus rather than by the user, so regaining it resumes
+    pub blur_paused: bool,
+    /// Chart history stops growing while the table keeps refreshing
+    pub chart_paused: bool,
     pub selected_index: Option<usize>,
     pub excluded_indices: HashSet<String>,
+    /// Indices kept at the top of the table whatever the sort; unlike exclusions they
+    /// still count in every total
+    pub pinned_indices: HashSet<String>,
+    // Exclusions removed by the last clear, restorable until they change again
+    cleared_exclusions: Option<HashSet<String>>,
+    exclusions_cleared_at: Option<Instant>,
+    /// Filter text waiting for the run loop to send it to the clipboard
+    pub clipboard: Option<String>,
+    // Outcome of the last user operation, shown in the footer for a moment
+    status_message: Option<(String, Instant)>,
+    /// Write operations are refused unless `--allow-destructive` is given
+    pub allow_destructive: bool,
+    /// Open replica count prompt (E)
+    pub replica_prompt: Option<ReplicaPrompt>,
+    /// Periodic CSV snapshot from `--snapshot-export`
+    pub auto_export: Option<AutoExport>,
     pub show_help_popup: bool,
     pub help_scroll: usize,
+    /// Fallback for columns without their own entry in `column_colormaps`
     pub colormap: Colormap,
+    pub column_colormaps: HashMap<SortColumn, Colormap>,
+    /// Give the largest value the end of the colormap instead of the start
+    pub invert_gradient: bool,
+    pub color_mode: ColorMode,
+    /// Ascending index ages that separate the tiers of `ColorMode::Age`
+    pub age_tiers: Vec<chrono::Duration>,
     pub rate_samples: usize,
     pub cluster_health: ClusterHealth,
+    // Last status that came from `_cluster/health`, so alerts span an unknown gap
+    last_known_status: String,
+    pub shard_distribution: ShardDistribution,
+    pub theme: Theme,
+    pub timezone: Timezone,
+    pub rate_unit: RateUnit,
+    pub gradient_scale: GradientScale,
 
     // Sub-states
     pub sort: SortState,
     pub filter: FilterState,
     pub details: DetailsState,
+    pub text_popup: TextPopupState,
+    /// Index whose rate history is shown in the chart popup
+    pub index_chart: Option<String>,
 
     index_rate_history: HashMap<String, VecDeque<f64>>,
+    // Selection of views that aren't currently active, restored when switching back
+    view_selections: HashMap<View, Option<usize>>,
+    spinner_epoch: Instant,
     es_client: Arc<Mutex<EsClient>>,
     fetch_rx: mpsc::Receiver<FetchResult>,
     fetch_tx: mpsc::Sender<FetchResult>,
+    // Running fetch, watched so a task that dies without a result doesn't hang `loading`
+    fetch_task: Option<JoinHandle<()>>,
+    // Pending retry after a fetch task died
+    reconnect_at: Option<Instant>,
+    // Fetch on the next loop turn instead of after the refresh interval
+    refresh_requested: bool,
+    reconnect_backoff: Duration,
+    webhook_url: Option<Url>,
+    webhook_error_rx: mpsc::Receiver<String>,
+    webhook_error_tx: mpsc::Sender<String>,
+    replicas_rx: mpsc::Receiver<ReplicasUpdate>,
+    replicas_tx: mpsc::Sender<ReplicasUpdate>,
+    rollover_rx: mpsc::Receiver<RolloverOutcome>,
+    rollover_tx: mpsc::Sender<RolloverOutcome>,
 }
 
 impl App {
     /// Creates a new App instance with the given configuration.
     ///
     /// This initializes the Elasticsearch client and background channels.
-    pub fn new(
-        base_url: String,
-        auth: AuthConfig,
-        insecure: bool,
-        ca_cert: Option<PathBuf>,
-        refresh_secs: u64,
-        colormap: Colormap,
-        rate_samples: usize,
-    ) -> Result<Self> {
-        let es_client = EsClient::new(base_url.clone(), auth, insecure, ca_cert)?;
+    pub fn new(config: AppConfig) -> Result<Self> {
+        let es_client = EsClient::new(
+            config.base_url.clone(),
+            config.auth,
+            config.insecure,
+            config.ca_cert,
+            Duration::from_secs(config.connect_timeout_secs),
+            Duration::from_secs(config.timeout_secs),
+            config.max_error_body,
+        )?
+        .with_index_pattern(config.index_pattern.clone())
+        .with_stat_groups(config.stat_groups.clone())
+        .with_details_concurrency(config.details_concurrency)
+        .with_dump_dir(config.dump_dir);
         let (fetch_tx, fetch_rx) = mpsc::channel(1);
+        let (webhook_error_tx, webhook_error_rx) = mpsc::channel(4);
+        let (replicas_tx, replicas_rx) = mpsc::channel(4);
+        let (rollover_tx, rollover_rx) = mpsc::channel(1);
 
         Ok(Self {
             indices: Vec::new(),
             running: true,
             error: None,
+            health_error: None,
             loading: false,
             spinner_frame: 0,
-            refresh_interval: Duration::from_secs(refresh_secs),
+            spinner_style: config.spinner_style,
+            status_indicator: config.status_indicator,
+            selection_style: config.selection_style,
+            zebra: config.zebra,
+            name_transforms: config.name_transforms,
+            refresh_interval: Duration::from_secs(config.refresh_secs),
             last_refresh: None,
             rate_history: VecDeque::with_capacity(MAX_HISTORY_POINTS),
-            es_url: base_url,
+            bytes_rate_history: VecDeque::with_capacity(MAX_HISTORY_POINTS),
+            doc_count_history: VecDeque::with_capacity(MAX_HISTORY_POINTS),
+            unassigned_history: VecDeque::with_capacity(MAX_HISTORY_POINTS),
+            history_cleared_until: None,
+            chart_metric: ChartMetric::default(),
+            es_url: config.base_url,
+            last_success: None,
+            index_pattern: config.index_pattern,
+            stat_groups: config.stat_groups,
+            lazy_shards: config.lazy_shards,
+            heap_warning: config.heap_warning,
+            node_heap: Vec::new(),
+            rollup: false,
+            unhealthy_first: false,
+            excluded_section: false,
+            excluded_section_expanded: false,
+            rollup_separators: config.rollup_separators,
+            expanded_groups: HashSet::new(),
             fetch_start: None,
             last_fetch_duration: None,
-            show_graph: true,
+            api_latency: VecDeque::with_capacity(LATENCY_SAMPLES),
+            show_graph: !config.compact,
+            show_moving_average: true,
+            avg_window: config.avg_window.max(1),
             show_health: true,
+            chart_split: DEFAULT_CHART_SPLIT,
+            column_widths: initial_column_widths(&config.column_widths),
+            row_layout: config.row_layout,
+            compact: config.compact,
+            show_total_size: config.total_size,
+            show_raw_bytes: false,
+            view: View::default(),
+            snapshot: None,
+            watch: config.watch.map(WatchState::new),
+            alert: AlertState::new(config.alert, config.alert_command, config.alert_recovery),
             show_indices: true,
-            show_system_indices: false,
+            show_system_in_list: false,
+            count_system_in_rate: false,
+            system_patterns: if config.system_patterns.is_empty() {
+                vec![DEFAULT_SYSTEM_PATTERN.to_string()]
+            } else {
+                config.system_patterns
+            },
+            include_patterns: config.include_patterns,
+            exclude_patterns: config.exclude_patterns,
             paused: false,
+            pause_on_blur: config.pause_on_blur,
+            blur_paused: false,
+            chart_paused: false,
             selected_index: None,
             excluded_indices: HashSet::new(),
+            pinned_indices: HashSet::new(),
+            cleared_exclusions: None,
+            exclusions_cleared_at: None,
+            clipboard: None,
+            allow_destructive: config.allow_destructive,
+            replica_prompt: None,
+            auto_export: config.snapshot_export.map(|path| {
+                AutoExport::new(path, config.snapshot_interval, config.snapshot_append)
+            }),
+            status_message: None,
             show_help_popup: false,
             help_scroll: 0,
-            colormap,
-            rate_samples: rate_samples.max(1), // At least 1 sample
+            colormap: config.colormap,
+            column_colormaps: config.column_colormaps,
+            invert_gradient: false,
+            color_mode: ColorMode::default(),
+            age_tiers: {
+                let mut tiers = config.age_tiers;
+                tiers.sort();
+                tiers
+            },
+            rate_samples: config.rate_samples.max(1), // At least 1 sample
             cluster_health: ClusterHealth::default(),
+            last_known_status: String::new(),
+            shard_distribution: ShardDistribution::default(),
+            theme: config.theme,
+            timezone: config.timezone,
+            rate_unit: config.rate_unit,
+            gradient_scale: config.gradient_scale,
 
             sort: SortState::default(),
             filter: FilterState::default(),
             details: DetailsState::new(),
+            text_popup: TextPopupState::new(),
+            index_chart: None,
 
             index_rate_history: HashMap::new(),
+            view_selections: HashMap::new(),
+            spinner_epoch: Instant::now(),
             es_client: Arc::new(Mutex::new(es_client)),
             fetch_rx,
             fetch_tx,
+            fetch_task: None,
+            reconnect_at: None,
+            refresh_requested: false,
+            reconnect_backoff: RECONNECT_BACKOFF_MIN,
+            webhook_url: config.webhook_url,
+            webhook_error_rx,
+            webhook_error_tx,
+            replicas_rx,
+            replicas_tx,
+            rollover_rx,
+            rollover_tx,
         })
     }
 
-    // Advance the spinner animation (call on each frame when loading)
+    // Advance the spinner animation (call on each frame when loading).
+    // The frame is derived from wall-clock time so the speed doesn't depend on the
+    // redraw rate and the animation continues smoothly across consecutive fetches.
     pub fn tick_spinner(&mut self) {
         if self.loading {
-            self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len();
+            let ticks =
+                self.spinner_epoch.elapsed().as_millis() / SPINNER_FRAME_INTERVAL.as_millis();
+            self.spinner_frame = ticks as usize % self.spinner_style.frames().len();
         }
     }
 
-    // Get the current spinner character (spinner when loading, checkmark when idle)
+    // Get the current spinner character (spinner when loading, idle marker otherwise)
     pub fn spinner_char(&self) -> char {
         if self.loading {
-            SPINNER_FRAMES[self.spinner_frame]
+            self.spinner_style.frames()[self.spinner_frame]
         } else {
-            '✓' // Checkmark when idle
+            self.spinner_style.idle_char()
+        }
+    }
+
+    // Fetch status for the indices title: the spinner glyph, or a word with `--status-indicator text`
+    pub fn status_marker(&self) -> String {
+        match self.status_indicator {
+            StatusIndicator::Icon => self.spinner_char().to_string(),
+            StatusIndicator::Text if self.loading => "loading".to_string(),
+            StatusIndicator::Text => "idle".to_string(),
         }
     }
 
@@ -152,20 +535,10 @@ impl App {
     fn total_cluster_metrics(&self) -> ClusterMetrics {
         self.indices
             .iter()
-            .filter(|i| {
-                // Filter excluded indices
-                if self.excluded_indices.contains(&i.name) {
-                    return false;
-                }
-                // Filter system indices if not showing them
-                if !self.show_system_indices && i.name.starts_with('.') {
-                    return false;
-                }
-                // Apply regex filter from FilterState
-                self.filter.is_match(i)
-            })
+            .filter(|i| self.is_visible(i, self.count_system_in_rate))
             .fold(ClusterMetrics::default(), |mut acc, i| {
                 acc.rate_per_sec += i.rate_per_sec;
+                acc.doc_count += i.doc_count;
 
                 // Calculate bytes per second based on average document size
                 if i.doc_count > 0 {
@@ -181,9 +554,12 @@ impl App {
         self.total_cluster_metrics().rate_per_sec
     }
 
-    /// Returns a human-readable string of the total cluster indexing rate.
+    /// Returns a human-readable string of the total cluster indexing rate, in the display unit.
     pub fn total_cluster_rate_human(&self) -> String {
-        format_number(self.total_cluster_rate())
+        self.if_fetched(
+            &[StatGroup::Indexing],
+            format_number(self.rate_unit.scale(self.total_cluster_rate())),
+        )
     }
 
     /// Returns the total cluster bytes per second across all indices.
@@ -191,10 +567,13 @@ impl App {
         self.total_cluster_metrics().bytes_per_sec
     }
 
-    /// Returns a human-readable string of the total cluster bytes per second.
+    /// Returns a human-readable string of the total cluster ingest throughput, in the display unit.
     pub fn total_cluster_bytes_per_sec_human(&self) -> String {
         let bytes_per_sec = self.total_cluster_bytes_per_sec();
-        format_bytes(bytes_per_sec as u64)
+        self.if_fetched(
+            &[StatGroup::Indexing, StatGroup::Docs, StatGroup::Store],
+            format_bytes(self.rate_unit.scale(bytes_per_sec) as u64),
+        )
     }
 
     // Starts a background fetch of index rates from Elasticsearch.
@@ -204,71 +583,252 @@ impl App {
         }
 
         self.loading = true;
+        self.reconnect_at = None;
         self.fetch_start = Some(Instant::now());
         let client = Arc::clone(&self.es_client);
         let tx = self.fetch_tx.clone();
+        let poll_heap = self.heap_warning.is_some();
+        let nodes_view = self.view == View::Nodes;
+        self.refresh_requested = false;
 
-        tokio::spawn(async move {
+        self.fetch_task = Some(tokio::spawn(async move {
             let result = {
-                let mut client = client.lock().await;
-                let rates_res = client.fetch_index_rates().await;
-                let health_res = client.fetch_cluster_health().await;
-
-                match (rates_res, health_res) {
-                    (Ok(rates), Ok(health)) => Ok((rates, health)),
-                    (Err(e), _) => Err(e),
-                    (_, Err(e)) => Err(e),
-                }
+                let client = client.lock().await;
+                let health_and_shards = async {
+                    let ping_start = Instant::now();
+                    let health_res = client.fetch_cluster_health().await;
+                    let latency = ping_start.elapsed();
+                    // `_cat/shards` lists every shard in the cluster, so it is only requested
+                    // for the Nodes view or to place unassigned shards on their indices
+                    let shards = match &health_res {
+                        Ok(health) if nodes_view || health.unassigned_shards > 0 => {
+                            client.fetch_shard_distribution().await.ok()
+                        }
+                        Ok(_) => Some(ShardDistribution::default()),
+                        Err(_) => None,
+                    };
+                    (health_res, shards, latency)
+                };
+                let heap = async {
+                    if poll_heap {
+                        client.fetch_node_heap().await.ok()
+                    } else {
+                        None
+                    }
+                };
+                let (rates_res, (health_res, shards, latency), heap) =
+                    tokio::join!(client.fetch_index_rates(), health_and_shards, heap);
+
+                (rates_res, health_res, shards, heap, latency)
             };
 
             let _ = tx.send(result).await;
-        });
+        }));
     }
 
     // Check for fetch results (non-blocking)
     pub fn poll_fetch_result(&mut self) {
+        // Checked before the channel, so a result sent right before the task ended still counts
+        let task_ended = self
+            .fetch_task
+            .as_ref()
+            .is_some_and(JoinHandle::is_finished);
         match self.fetch_rx.try_recv() {
             Ok(result) => {
+                self.fetch_task = None;
                 self.loading = false;
                 self.last_refresh = Some(Instant::now());
+                self.reconnect_backoff = RECONNECT_BACKOFF_MIN;
 
                 if let Some(start) = self.fetch_start.take() {
                     self.last_fetch_duration = Some(start.elapsed());
                 }
 
-                match result {
-                    Ok((mut indices, health)) => {
-                        self.update_indices_with_rates(&mut indices);
-                        self.sort.sort(&mut indices);
-                        self.indices = indices;
-                        self.cluster_health = health;
-                        self.error = None;
-
-                        // Prune index_rate_history for indices that no longer exist
-                        let current_index_names: HashSet<String> =
-                            self.indices.iter().map(|i| i.name.clone()).collect();
-                        self.index_rate_history
-                            .retain(|name, _| current_index_names.contains(name));
-
-                        let total_rate = self.total_cluster_rate() as u64;
-                        if self.rate_history.len() >= MAX_HISTORY_POINTS {
-                            self.rate_history.pop_front();
-                        }
-                        self.rate_history.push_back(total_rate);
-                    }
-                    Err(e) => {
-                        self.error = Some(e.to_string());
-                    }
+                let (rates, health, shards, heap, latency) = result;
+                if let Some(shards) = shards {
+                    self.shard_distribution = shards;
+                }
+                if let Some(heap) = heap {
+                    self.node_heap = heap;
+                }
+                match rates {
+                    Ok(indices) => self.apply_rates(indices),
+                    // Keep showing the last good indices
+                    Err(e) => self.error = Some(e.to_string()),
+                }
+                self.apply_health(health, latency);
+                // Only successful health calls add a sample, so an outage doesn't read as zero
+                if !self.chart_paused && self.health_error.is_none() {
+                    push_history(
+                        &mut self.unassigned_history,
+                        self.cluster_health.unassigned_shards as u64,
+                    );
                 }
             }
-            Err(mpsc::error::TryRecvError::Empty) => {}
-            Err(mpsc::error::TryRecvError::Disconnected) => {
+            Err(mpsc::error::TryRecvError::Empty) if !task_ended => {}
+            // The task ended without a result (e.g. it panicked); the app keeps a sender, so
+            // the channel itself can't disconnect
+            Err(_) => {
+                self.fetch_task = None;
                 self.loading = false;
-                self.error = Some("Fetch task disconnected".to_string());
+                self.back_off();
+            }
+        }
+    }
+
+    fn apply_rates(&mut self, mut indices: Vec<IndexRate>) {
+        for index in &mut indices {
+            index.unassigned_shards = self
+                .shard_distribution
+                .unassigned_by_index
+                .get(&index.name)
+                .copied()
+                .unwrap_or(0);
+        }
+        self.update_indices_with_rates(&mut indices);
+        self.sort_indices(&mut indices);
+        self.indices = indices;
+        self.error = None;
+        self.last_success = Some(Instant::now());
+
+        // Prune index_rate_history for indices that no longer exist
+        let current_index_names: HashSet<String> =
+            self.indices.iter().map(|i| i.name.clone()).collect();
+        self.index_rate_history
+            .retain(|name, _| current_index_names.contains(name));
+
+        if !self.chart_paused {
+            let metrics = self.total_cluster_metrics();
+            push_history(&mut self.rate_history, metrics.rate_per_sec as u64);
+            push_history(&mut self.bytes_rate_history, metrics.bytes_per_sec as u64);
+            push_history(&mut self.doc_count_history, metrics.doc_count);
+        }
+
+        self.update_watch();
+    }
+
+    fn apply_health(&mut self, health: Result<ClusterHealth>, latency: Duration) {
+        match health {
+            Ok(health) => {
+                if self.api_latency.len() >= LATENCY_SAMPLES {
+                    self.api_latency.pop_front();
+                }
+                self.api_latency.push_back(latency);
+                self.cluster_health = health;
+                self.health_error = None;
+                if self.cluster_health.status != UNKNOWN_HEALTH {
+                    let previous_status = std::mem::replace(
+                        &mut self.last_known_status,
+                        self.cluster_health.status.clone(),
+                    );
+                    self.check_health_transition(&previous_status);
+                }
+            }
+            // A failed health call says nothing about normal latency
+            Err(e) => {
+                self.cluster_health = ClusterHealth {
+                    cluster_name: self.cluster_health.cluster_name.clone(),
+                    status: UNKNOWN_HEALTH.to_string(),
+                    ..Default::default()
+                };
+                self.health_error = Some(e.to_string());
+            }
+        }
+    }
+
+    // Runs one fetch to completion, for the non-interactive output mode
+    pub async fn fetch_and_wait(&mut self) -> std::result::Result<(), String> {
+        self.start_fetch();
+        while self.loading {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.poll_fetch_result();
+        }
+        self.error.take().map_or(Ok(()), Err)
+    }
+
+    // Clears the error and backoff, then fetches right away instead of waiting for the next tick
+    pub fn retry_now(&mut self) {
+        self.error = None;
+        self.reconnect_backoff = RECONNECT_BACKOFF_MIN;
+        self.reconnect_at = None;
+        self.start_fetch();
+    }
+
+    // Schedules a fresh fetch after a backoff once a fetch task died without a result
+    fn back_off(&mut self) {
+        self.error = Some(format!(
+            "Fetch task failed, retrying in {}s",
+            self.reconnect_backoff.as_secs()
+        ));
+        self.reconnect_at = Some(Instant::now() + self.reconnect_backoff);
+        self.reconnect_backoff = (self.reconnect_backoff * 2).min(RECONNECT_BACKOFF_MAX);
+    }
+
+    fn check_health_transition(&mut self, previous_status: &str) {
+        let status = &self.cluster_health.status;
+
+        if let Some(ref url) = self.webhook_url {
+            if health_worsened(previous_status, status) {
+                webhook::notify(
+                    self.es_client.clone(),
+                    url.clone(),
+                    HealthChangePayload {
+                        cluster_name: self.cluster_health.cluster_name.clone(),
+                        old_status: previous_status.to_string(),
+                        new_status: status.clone(),
+                        unassigned_shards: self.cluster_health.unassigned_shards,
+                    },
+                    self.webhook_error_tx.clone(),
+                );
+            }
+        }
+
+        if self.alert.check(previous_status, status) {
+            if let Some(ref command) = self.alert.command {
+                if let Err(e) = run_alert_command(command, status) {
+                    self.error = Some(format!("Alert command failed: {}", e));
+                }
             }
         }
     }
 
+    // Records the watched index's latest values and refreshes its details
+    fn update_watch(&mut self) {
+        let Some(ref mut watch) = self.watch else {
+            return;
+        };
+        let Some(index) = self.indices.iter().find(|i| i.name == watch.index) else {
+            return;
+        };
+
+        let bytes_per_sec = if index.doc_count > 0 {
+            index.size_bytes as f64 / index.doc_count as f64 * index.rate_per_sec
+        } else {
+            0.0
+        };
+        push_history(&mut watch.rate_history, index.rate_per_sec as u64);
+        push_history(&mut watch.bytes_rate_history, bytes_per_sec as u64);
+        push_history(&mut watch.doc_count_history, index.doc_count);
+        push_history(
+            &mut watch.unassigned_history,
+            index.unassigned_shards as u64,
+        );
+
+        if !watch.details.loading {
+            // Keep the scroll position across refreshes
+            let scroll = watch.details.scroll;
+            watch.details.fetch(
+                self.es_client.clone(),
+                index.name.clone(),
+                index.doc_count,
+                index.rate_per_sec,
+                index.size_bytes,
+                self.lazy_shards,
+            );
+            watch.details.scroll = scroll;
+        }
+    }
+
     fn update_indices_with_rates(&mut self, indices: &mut [IndexRate]) {
         for index in indices {
             let history = self
@@ -279,7 +839,7 @@ impl App {
             if history.len() >= self.rate_samples {
                 history.pop_front();
             }
-            history.push_back(index.rate_per_sec);
+            history.push_back(finite_or_zero(index.rate_per_sec));
 
             if !history.is_empty() {
                 let sum: f64 = history.iter().sum();
@@ -304,6 +864,16 @@ impl App {
         }
     }
 
+    // Rolling average of the `_cluster/health` round trip, e.g. "42ms"
+    pub fn api_latency_display(&self) -> Option<String> {
+        if self.api_latency.is_empty() {
+            return None;
+        }
+        let total: Duration = self.api_latency.iter().sum();
+        let average = total / self.api_latency.len() as u32;
+        Some(format!("{}ms", average.as_millis()))
+    }
+
     pub fn increase_refresh_rate(&mut self) {
         let current_secs = self.refresh_interval.as_secs();
         if current_secs > MIN_REFRESH_SECS {
@@ -318,8 +888,87 @@ impl App {
         }
     }
 
-    pub fn rate_history_vec(&self) -> Vec<u64> {
-        self.rate_history.iter().copied().collect()
+    // Widens the rate smoothing window; it fills up over the next refreshes
+    pub fn increase_rate_samples(&mut self) {
+        if self.rate_samples < MAX_RATE_SAMPLES {
+            self.rate_samples += 1;
+        }
+    }
+
+    // Narrows the rate smoothing window, dropping the oldest samples beyond it
+    pub fn decrease_rate_samples(&mut self) {
+        self.rate_samples = self.rate_samples.saturating_sub(1).max(1);
+        for history in self.index_rate_history.values_mut() {
+            let excess = history.len().saturating_sub(self.rate_samples);
+            history.drain(..excess);
+        }
+    }
+
+    /// Returns the history of the metric currently selected for the chart.
+    pub fn chart_history_vec(&self) -> Vec<u64> {
+        let history = match self.chart_metric {
+            ChartMetric::IndexingRate => &self.rate_history,
+            ChartMetric::BytesRate => &self.bytes_rate_history,
+            ChartMetric::DocCount => &self.doc_count_history,
+            ChartMetric::UnassignedShards => &self.unassigned_history,
+        };
+        history.iter().copied().collect()
+    }
+
+    // Recent raw rates of one index, the same samples its smoothed rate is built from
+    pub fn index_rate_history_vec(&self, name: &str) -> Vec<u64> {
+        self.index_rate_history
+            .get(name)
+            .map(|history| history.iter().map(|&rate| rate.round() as u64).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn show_index_chart(&mut self) {
+        self.index_chart = self.selected_index_rate().map(|i| i.name.clone());
+    }
+
+    pub fn close_index_chart(&mut self) {
+        self.index_chart = None;
+    }
+
+    pub fn cycle_chart_metric(&mut self) {
+        self.chart_metric = self.chart_metric.next();
+    }
+
+    /// The node with the fullest heap, when it is at or above the `--heap-warning` threshold
+    pub fn heap_pressure(&self) -> Option<&NodeHeap> {
+        let threshold = self.heap_warning?;
+        self.node_heap
+            .iter()
+            .max_by_key(|node| node.heap_used_percent)
+            .filter(|node| node.heap_used_percent >= threshold)
+    }
+
+    // Writes the `--snapshot-export` CSV when due, from the latest fetched indices
+    pub fn run_auto_export(&mut self) {
+        if self.last_refresh.is_none() {
+            return;
+        }
+        let Some(ref mut export) = self.auto_export else {
+            return;
+        };
+        if !export.is_due() {
+            return;
+        }
+        let indices: Vec<&IndexRate> = self.indices.iter().collect();
+        if let Err(e) = export.write(&indices, &self.stat_groups, chrono::Utc::now()) {
+            self.error = Some(format!(
+                "Snapshot export to {} failed: {}",
+                export.path.display(),
+                e
+            ));
+        }
+    }
+
+    /// Time since the displayed data was last refreshed successfully, `None` before the
+    /// first success. Keeps growing while fetches fail.
+    pub fn data_age(&self) -> Option<Duration> {
+        self.last_success.map(|last| last.elapsed())
     }
 
     // Checks if the application should trigger a new background fetch.
@@ -327,6 +976,12 @@ impl App {
         if self.paused {
             return false;
         }
+        if let Some(reconnect_at) = self.reconnect_at {
+            return Instant::now() >= reconnect_at;
+        }
+        if self.refresh_requested {
+            return true;
+        }
         match self.last_refresh {
             None => true,
             Some(last) => last.elapsed() >= self.refresh_interval,
@@ -351,10 +1006,23 @@ impl App {
 
     fn resort(&mut self) {
         let mut indices = std::mem::take(&mut self.indices);
-        self.sort.sort(&mut indices);
+        self.sort_indices(&mut indices);
         self.indices = indices;
     }
 
+    fn sort_indices(&self, indices: &mut [IndexRate]) {
+        self.sort.sort(indices, self.show_total_size);
+        if self.unhealthy_first {
+            sort::pin_unhealthy(indices);
+        }
+        sort::pin_named(indices, &self.pinned_indices);
+    }
+
+    pub fn toggle_unhealthy_first(&mut self) {
+        self.unhealthy_first = !self.unhealthy_first;
+        self.resort();
+    }
+
     pub fn quit(&mut self) {
         self.running = false;
     }
@@ -363,22 +1031,129 @@ impl App {
         self.show_graph = !self.show_graph;
     }
 
+    pub fn toggle_moving_average(&mut self) {
+        self.show_moving_average = !self.show_moving_average;
+    }
+
     pub fn toggle_health(&mut self) {
         self.show_health = !self.show_health;
     }
 
+    // The split only applies when the chart and health widgets share a row
+    pub fn chart_split_adjustable(&self) -> bool {
+        self.show_graph && self.show_health && self.row_layout == RowLayout::Horizontal
+    }
+
+    pub fn grow_chart(&mut self) {
+        self.chart_split = (self.chart_split + CHART_SPLIT_STEP).min(MAX_CHART_SPLIT);
+    }
+
+    pub fn shrink_chart(&mut self) {
+        self.chart_split = self
+            .chart_split
+            .saturating_sub(CHART_SPLIT_STEP)
+            .max(MIN_CHART_SPLIT);
+    }
+
+    // Widens or narrows the sort column; the others keep their share
+    pub fn resize_sort_column(&mut self, grow: bool) {
+        let width = &mut self.column_widths[self.sort.column as usize];
+        *width = if grow {
+            (*width + COLUMN_WIDTH_STEP).min(MAX_COLUMN_WIDTH)
+        } else {
+            width
+                .saturating_sub(COLUMN_WIDTH_STEP)
+                .max(MIN_COLUMN_WIDTH)
+        };
+    }
+
+    pub fn toggle_row_layout(&mut self) {
+        self.row_layout = self.row_layout.toggle();
+    }
+
+    // Switches the active view, keeping each view's selection separately
+    pub fn set_view(&mut self, view: View) {
+        if view == self.view {
+            return;
+        }
+        self.view_selections.insert(self.view, self.selected_index);
+        self.selected_index = self.view_selections.get(&view).copied().flatten();
+        self.view = view;
+        // Shards are only fetched while the Nodes view shows them; don't wait a full interval
+        if view == View::Nodes && self.shard_distribution.nodes.is_empty() {
+            self.refresh_requested = true;
+        }
+    }
+
+    pub fn mark_snapshot(&mut self) {
+        self.snapshot = Some(Snapshot::capture(&self.indices));
+    }
+
     pub fn toggle_indices(&mut self) {
         self.show_indices = !self.show_indices;
     }
 
+    // Combined toggle: flips the list and brings the rate total in line with it
     pub fn toggle_system_indices(&mut self) {
-        self.show_system_indices = !self.show_system_indices;
+        self.show_system_in_list = !self.show_system_in_list;
+        self.count_system_in_rate = self.show_system_in_list;
         // Reset selection when toggling to avoid out-of-bounds
         self.selected_index = None;
     }
 
+    pub fn toggle_system_in_list(&mut self) {
+        self.show_system_in_list = !self.show_system_in_list;
+        // Reset selection when toggling to avoid out-of-bounds
+        self.selected_index = None;
+    }
+
+    pub fn toggle_system_in_rate(&mut self) {
+        self.count_system_in_rate = !self.count_system_in_rate;
+    }
+
     pub fn toggle_pause(&mut self) {
         self.paused = !self.paused;
+        // A pause the user chose outlasts the focus coming back
+        self.blur_paused = false;
+    }
+
+    pub fn focus_lost(&mut self) {
+        if self.pause_on_blur && !self.paused {
+            self.paused = true;
+            self.blur_paused = true;
+        }
+    }
+
+    pub fn focus_gained(&mut self) {
+        if self.blur_paused {
+            self.paused = false;
+            self.blur_paused = false;
+        }
+    }
+
+    // Restarts the chart, the watched index's chart and per-index rate averaging from empty
+    pub fn clear_history(&mut self) {
+        self.rate_history.clear();
+        self.bytes_rate_history.clear();
+        self.doc_count_history.clear();
+        self.unassigned_history.clear();
+        self.index_rate_history.clear();
+        if let Some(ref mut watch) = self.watch {
+            watch.rate_history.clear();
+            watch.bytes_rate_history.clear();
+            watch.doc_count_history.clear();
+            watch.unassigned_history.clear();
+        }
+        self.history_cleared_until = Some(Instant::now() + HISTORY_CLEARED_NOTICE);
+    }
+
+    pub fn history_just_cleared(&self) -> bool {
+        self.history_cleared_until
+            .is_some_and(|until| Instant::now() < until)
+    }
+
+    pub fn toggle_chart_pause(&mut self) {
+        self.chart_paused = !self.chart_paused;
     }
 
     pub fn select_up(&mut self) {
@@ -398,20 +1173,15 @@ impl App {
     }
 
     pub fn select_first(&mut self) {
-        if !self.filtered_indices().is_empty() {
-            self.selected_index = Some(0);
-        }
+        self.selected_index = if self.row_count() == 0 { None } else { Some(0) };
     }
 
     pub fn select_last(&mut self) {
-        let count = self.filtered_indices().len();
-        if count > 0 {
-            self.selected_index = Some(count.saturating_sub(1));
-        }
+        self.selected_index = self.row_count().checked_sub(1);
     }
 
     fn move_selection(&mut self, delta: i32) {
-        let count = self.filtered_indices().len();
+        let count = self.row_count();
         if count == 0 {
             self.selected_index = None;
             return;
@@ -445,122 +1215,739 @@ impl App {
         self.filter.clear();
     }
 
-    pub fn filtered_indices(&self) -> Vec<&IndexRate> {
-        self.indices
+    // Queue the filter for the clipboard; the run loop writes it to the terminal
+    pub fn copy_filter(&mut self) {
+        let filter = self.filter.input.value();
+        if filter.is_empty() {
+            self.set_status("No filter to copy".to_string());
+        } else {
+            self.clipboard = Some(filter.to_string());
+            self.set_status("Copied filter to clipboard".to_string());
+        }
+    }
+
+    pub fn set_status(&mut self, status: String) {
+        self.status_message = Some((status, Instant::now()));
+    }
+
+    pub fn status_message(&self) -> Option<&str> {
+        self.status_message
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < STATUS_MESSAGE)
+            .map(|(status, _)| status.as_str())
+    }
+
+    // Pasted text goes into the filter input; outside filter mode it replaces the filter
+    pub fn paste_filter(&mut self, text: &str) {
+        if !self.filter.active {
+            self.filter.input.reset();
+            self.filter.enter();
+        }
+        self.filter.paste(text);
+    }
+
+    /// Indexing failures across all indices since the previous refresh.
+    pub fn recent_failures(&self) -> u64 {
+        self.indices.iter().map(|i| i.failed_delta).sum()
+    }
+
+    // Whether the refresh requests this `_stats` group, i.e. its columns have data
+    pub fn fetches(&self, group: StatGroup) -> bool {
+        self.stat_groups.contains(&group)
+    }
+
+    // `text`, or "-" when its `_stats` group isn't requested and it would only read zero
+    pub fn if_fetched(&self, groups: &[StatGroup], text: String) -> String {
+        if groups.iter().all(|group| self.fetches(*group)) {
+            text
+        } else {
+            "-".to_string()
+        }
+    }
+
+    // Table label of an index: its name after the configured transforms
+    pub fn display_name<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        self.name_transforms
             .iter()
-            .filter(|i| {
-                // Filter excluded indices
-                if self.excluded_indices.contains(&i.name) {
-                    return false;
-                }
-                // Filter system indices if not showing them
-                if !self.show_system_indices && i.name.starts_with('.') {
-                    return false;
+            .fold(Cow::Borrowed(name), |name, transform| {
+                match transform.apply(&name) {
+                    Cow::Borrowed(_) => name,
+                    Cow::Owned(changed) => Cow::Owned(changed),
                 }
-                // Apply regex filter from FilterState
-                self.filter.is_match(i)
             })
-            .collect()
     }
 
-    // Details delegation
-    pub fn show_index_details(&mut self) {
-        if let Some(selected) = self.selected_index {
-            let filtered = self.filtered_indices();
-            if let Some(index) = filtered.get(selected) {
-                let index_name = index.name.clone();
-                let doc_count = index.doc_count;
-                let rate_per_sec = index.rate_per_sec;
-                let size_bytes = index.size_bytes;
-
-                self.details.fetch(
-                    self.es_client.clone(),
-                    index_name,
-                    doc_count,
-                    rate_per_sec,
-                    size_bytes,
-                );
-            }
-        }
+    pub fn is_system_index(&self, name: &str) -> bool {
+        self.system_patterns
+            .iter()
+            .any(|pattern| glob_matches(pattern, name))
     }
 
-    pub fn close_details_popup(&mut self) {
-        self.details.close();
+    // Whether an index passes every filter: exclusions, system indices, startup globs and jq
+    fn is_visible(&self, index: &IndexRate, include_system: bool) -> bool {
+        !self.excluded_indices.contains(&index.name) && self.passes_filters(index, include_system)
     }
 
-    pub fn poll_details_result(&mut self) {
-        self.details.poll();
+    // Every filter but the exclusions
+    fn passes_filters(&self, index: &IndexRate, include_system: bool) -> bool {
+        // Filter system indices if not showing them
+        if !include_system && self.is_system_index(&index.name) {
+            return false;
+        }
+        // Startup --include/--exclude globs
+        if !self.include_patterns.is_empty()
+            && !self
+                .include_patterns
+                .iter()
+                .any(|pattern| glob_matches(pattern, &index.name))
+        {
+            return false;
+        }
+        if self
+            .exclude_patterns
+            .iter()
+            .any(|pattern| glob_matches(pattern, &index.name))
+        {
+            return false;
+        }
+        // Apply regex filter from FilterState
+        self.filter.is_match(index)
     }
 
-    pub fn details_scroll_up(&mut self) {
-        self.details.scroll_up();
+    pub fn filtered_indices(&self) -> Vec<&IndexRate> {
+        self.indices
+            .iter()
+            .filter(|i| self.is_visible(i, self.show_system_in_list))
+            .collect()
     }
 
-    pub fn details_scroll_down(&mut self) {
-        self.details.scroll_down();
+    // Excluded indices that every other filter lets through, for the excluded section
+    pub fn excluded_filtered_indices(&self) -> Vec<&IndexRate> {
+        self.indices
+            .iter()
+            .filter(|i| self.excluded_indices.contains(&i.name))
+            .filter(|i| self.passes_filters(i, self.show_system_in_list))
+            .collect()
     }
 
-    pub fn details_scroll_page_up(&mut self, page_size: usize) {
-        self.details.scroll_page_up(page_size);
+    pub fn toggle_excluded_section(&mut self) {
+        self.excluded_section = !self.excluded_section;
+        self.select_first();
     }
 
-    pub fn details_scroll_page_down(&mut self, page_size: usize) {
-        self.details.scroll_page_down(page_size);
+    // Totals of the filtered indices while a filter is applied
+    pub fn filter_totals(&self) -> Option<FilterTotals> {
+        if !self.filter.is_applied() {
+            return None;
+        }
+        Some(
+            self.filtered_indices()
+                .iter()
+                .fold(FilterTotals::default(), |mut acc, i| {
+                    acc.rate_per_sec += i.rate_per_sec;
+                    acc.doc_count += i.doc_count;
+                    acc.size_bytes += i.size(self.show_total_size);
+                    acc
+                }),
+        )
     }
 
-    pub fn toggle_exclude_selected(&mut self) {
-        if let Some(selected) = self.selected_index {
-            let filtered = self.filtered_indices();
-            if let Some(index) = filtered.get(selected) {
-                let name = index.name.clone();
-                if self.excluded_indices.contains(&name) {
-                    self.excluded_indices.remove(&name);
-                } else {
-                    self.excluded_indices.insert(name);
-                    // Move selection to next item if possible
-                    let new_count = self.filtered_indices().len();
-                    if new_count == 0 {
-                        self.selected_index = None;
-                    } else if selected >= new_count {
-                        self.selected_index = Some(new_count.saturating_sub(1));
+    // Placeholder for an empty table, telling an empty cluster apart from everything being hidden
+    pub fn empty_table_message(&self) -> Option<String> {
+        if !self.filtered_indices().is_empty()
+            || (self.excluded_section && !self.excluded_filtered_indices().is_empty())
+        {
+            None
+        } else if self.indices.is_empty() {
+            Some(
+                match (
+                    self.last_success.is_some(),
+                    &self.error,
+                    &self.index_pattern,
+                ) {
+                    // Failed first fetches keep retrying; say why nothing shows yet
+                    (false, Some(error), _) => {
+                        format!("Can't connect to {}\n\n{}", self.es_url, error)
                     }
-                }
-            }
+                    (false, None, _) => format!("Connecting to {}…", self.es_url),
+                    (true, _, Some(pattern)) => format!("No indices match --index {}", pattern),
+                    (true, _, None) => "Cluster has no indices".to_string(),
+                },
+            )
+        } else {
+            Some("No indices match the filter, exclusions or system-index settings".to_string())
         }
     }
 
-    pub fn clear_exclusions(&mut self) {
-        self.excluded_indices.clear();
-    }
-
-    pub fn excluded_count(&self) -> usize {
-        self.excluded_indices.len()
+    // Rows of the indices table: the filtered indices, or their groups when rolled up
+    pub fn table_rows(&self) -> Vec<TableRow<'_>> {
+        let filtered = self.filtered_indices();
+        let mut rows = if self.rollup {
+            rollup::rollup(
+                &filtered,
+                &self.rollup_separators,
+                &self.expanded_groups,
+                &self.sort,
+                self.show_total_size,
+                self.unhealthy_first,
+                &self.pinned_indices,
+            )
+        } else {
+            filtered.into_iter().map(TableRow::index).collect()
+        };
+        if self.excluded_section {
+            rows.extend(rollup::excluded_section(
+                &self.excluded_filtered_indices(),
+                self.excluded_section_expanded,
+            ));
+        }
+        rows
     }
 
-    pub fn toggle_help_popup(&mut self) {
-        self.show_help_popup = !self.show_help_popup;
-        if self.show_help_popup {
-            self.help_scroll = 0;
+    // Selectable rows in the current view; the Compare view always lists plain indices
+    fn row_count(&self) -> usize {
+        if self.view == View::Compare {
+            self.filtered_indices().len()
+        } else {
+            self.table_rows().len()
         }
     }
 
-    pub fn help_scroll_up(&mut self) {
-        self.help_scroll = self.help_scroll.saturating_sub(1);
+    // The index under the selection, or None on a group row
+    pub fn selected_index_rate(&self) -> Option<&IndexRate> {
+        let selected = self.selected_index?;
+        if self.view == View::Compare {
+            self.filtered_indices().get(selected).copied()
+        } else {
+            self.table_rows().get(selected)?.as_index()
+        }
     }
 
-    pub fn help_scroll_down(&mut self) {
-        self.help_scroll = self.help_scroll.saturating_add(1);
+    fn selected_group(&self) -> Option<String> {
+        if self.view == View::Compare {
+            return None;
+        }
+        let rows = self.table_rows();
+        let row = rows.get(self.selected_index?)?;
+        matches!(row.kind, RowKind::Group { .. }).then(|| row.index.name.clone())
     }
 
-    pub fn next_colormap(&mut self) {
-        self.colormap = self.colormap.next();
+    fn excluded_divider_selected(&self) -> bool {
+        self.view != View::Compare
+            && self.selected_index.is_some_and(|selected| {
+                self.table_rows()
+                    .get(selected)
+                    .is_some_and(|row| matches!(row.kind, RowKind::Excluded { .. }))
+            })
     }
 
-    pub fn prev_colormap(&mut self) {
-        self.colormap = self.colormap.prev();
+    pub fn toggle_rollup(&mut self) {
+        self.rollup = !self.rollup;
+        self.select_first();
     }
 
-    pub fn handle_action(&mut self, action: Action) {
+    // Details delegation
+    pub fn show_index_details(&mut self) {
+        // Enter on a group or the excluded divider folds or unfolds it instead
+        if self.excluded_divider_selected() {
+            self.excluded_section_expanded = !self.excluded_section_expanded;
+            return;
+        }
+        if let Some(group) = self.selected_group() {
+            if !self.expanded_groups.remove(&group) {
+                self.expanded_groups.insert(group);
+            }
+            return;
+        }
+        if let Some(index) = self.selected_index_rate() {
+            let index_name = index.name.clone();
+            self.fetch_details(index_name);
+        }
+    }
+
+    // Opens the details popup on `index_name`, with its stats from the latest refresh
+    fn fetch_details(&mut self, index_name: String) {
+        let (doc_count, rate_per_sec, size_bytes) = self
+            .indices
+            .iter()
+            .find(|index| index.name == index_name)
+            .map_or((0, 0.0, 0), |index| {
+                (index.doc_count, index.rate_per_sec, index.size_bytes)
+            });
+
+        self.details.fetch(
+            self.es_client.clone(),
+            index_name,
+            doc_count,
+            rate_per_sec,
+            size_bytes,
+            self.lazy_shards,
+        );
+    }
+
+    // Loads the shard allocation the watched or popped-up details were fetched without
+    pub fn load_shards(&mut self) {
+        let details = match self.watch {
+            Some(ref mut watch) => &mut watch.details,
+            None => &mut self.details,
+        };
+        details.load_shards(self.es_client.clone());
+    }
+
+    // Rows of indices that aren't green, in table order; closed ones have no details to show,
+    // and the excluded section is left out like the exclusions themselves
+    fn unhealthy_rows(&self) -> Vec<usize> {
+        let unhealthy = IndexRate::is_unhealthy;
+        if self.view == View::Compare {
+            self.filtered_indices()
+                .iter()
+                .enumerate()
+                .filter(|(_, index)| unhealthy(index))
+                .map(|(row, _)| row)
+                .collect()
+        } else {
+            self.table_rows()
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| row.kind != RowKind::ExcludedMember)
+                .filter(|(_, row)| row.as_index().is_some_and(unhealthy))
+                .map(|(row, _)| row)
+                .collect()
+        }
+    }
+
+    // Where the selection sits among the unhealthy rows (None when on a healthy one), and
+    // how many there are
+    pub fn unhealthy_position(&self) -> (Option<usize>, usize) {
+        let rows = self.unhealthy_rows();
+        let position = self
+            .selected_index
+            .and_then(|selected| rows.iter().position(|&row| row == selected));
+        (position, rows.len())
+    }
+
+    // Moves the details popup to the next (or previous) unhealthy index, wrapping around
+    pub fn step_unhealthy_details(&mut self, forward: bool) {
+        if self.select_unhealthy(forward) {
+            self.show_index_details();
+        }
+    }
+
+    // Moves the selection to the next (or previous) unhealthy index, wrapping around;
+    // false when there is none
+    pub fn select_unhealthy(&mut self, forward: bool) -> bool {
+        let rows = self.unhealthy_rows();
+        let current = self.selected_index;
+        let next = if forward {
+            rows.iter()
+                .find(|&&row| current.map_or(true, |c| row > c))
+                .or(rows.first())
+        } else {
+            rows.iter()
+                .rev()
+                .find(|&&row| current.map_or(true, |c| row < c))
+                .or(rows.last())
+        };
+        let Some(&next) = next else {
+            return false;
+        };
+
+        self.selected_index = Some(next);
+        true
+    }
+
+    pub fn close_details_popup(&mut self) {
+        self.details.close();
+    }
+
+    // Exports into the directory esticli was started from
+    pub fn export_details(&mut self) {
+        let dir = std::env::current_dir().unwrap_or_default();
+        self.details.export(&dir);
+    }
+
+    // Data stream of the index in the details popup, if it belongs to one
+    pub fn details_data_stream(&self) -> Option<&str> {
+        let details = self.details.data.as_ref()?;
+        details.data_stream.as_ref().map(|ds| ds.name.as_str())
+    }
+
+    // Rolls over the data stream of the index in the details popup
+    pub fn rollover_data_stream(&mut self) {
+        let Some(data_stream) = self.details_data_stream().map(str::to_string) else {
+            return;
+        };
+        let Some(index) = self
+            .details
+            .data
+            .as_ref()
+            .map(|details| details.name.clone())
+        else {
+            return;
+        };
+        if !self.allow_destructive {
+            self.details.status = Some(Err("Rollover needs --allow-destructive".to_string()));
+            return;
+        }
+        // Rolling over a production data stream deserves a second keystroke
+        if !self.details.rollover_armed {
+            self.details.rollover_armed = true;
+            self.details.status = Some(Ok(format!("Press O again to roll over {}", data_stream)));
+            return;
+        }
+        self.details.rollover_armed = false;
+        self.details.status = Some(Ok(format!("Rolling over {}...", data_stream)));
+
+        let client = self.es_client.clone();
+        let tx = self.rollover_tx.clone();
+        tokio::spawn(async move {
+            let result = client
+                .lock()
+                .await
+                .rollover(&data_stream)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send((index, data_stream, result)).await;
+        });
+    }
+
+    // Reloads the details of the rolled-over index and the table so the new write index shows
+    // up. The popup may have moved on to another index meanwhile, which is left alone.
+    fn poll_rollover(&mut self) {
+        let Ok((index, data_stream, result)) = self.rollover_rx.try_recv() else {
+            return;
+        };
+        let status = match result {
+            Ok(new_index) => {
+                if self.details.show_popup && self.details.index.as_deref() == Some(index.as_str())
+                {
+                    self.fetch_details(index);
+                }
+                if !self.loading {
+                    self.start_fetch();
+                }
+                Ok(format!(
+                    "Rolled over {}, new write index {}",
+                    data_stream, new_index
+                ))
+            }
+            Err(e) => Err(format!("Rollover of {} failed: {}", data_stream, e)),
+        };
+        self.details.status = Some(status);
+    }
+
+    // Surfaces background webhook failures without blocking the UI
+    pub fn poll_webhook_errors(&mut self) {
+        if let Ok(error) = self.webhook_error_rx.try_recv() {
+            self.error = Some(error);
+        }
+    }
+
+    pub fn poll_details_result(&mut self) {
+        self.details.poll();
+        self.text_popup.poll();
+        self.poll_replicas_update();
+        self.poll_rollover();
+        if let Some(ref mut watch) = self.watch {
+            watch.details.poll();
+        }
+    }
+
+    // Watch delegation
+    pub fn watch_selected(&mut self) {
+        let Some(name) = self.selected_index_rate().map(|i| i.name.clone()) else {
+            return;
+        };
+        self.watch = Some(WatchState::new(name));
+        self.update_watch();
+    }
+
+    pub fn stop_watch(&mut self) {
+        self.watch = None;
+    }
+
+    pub fn watch_scroll_up(&mut self) {
+        if let Some(ref mut watch) = self.watch {
+            watch.details.scroll_up();
+        }
+    }
+
+    pub fn watch_scroll_down(&mut self) {
+        if let Some(ref mut watch) = self.watch {
+            watch.details.scroll_down();
+        }
+    }
+
+    pub fn details_scroll_up(&mut self) {
+        self.details.scroll_up();
+    }
+
+    pub fn details_scroll_down(&mut self) {
+        self.details.scroll_down();
+    }
+
+    pub fn details_scroll_page_up(&mut self, page_size: usize) {
+        self.details.scroll_page_up(page_size);
+    }
+
+    pub fn details_scroll_page_down(&mut self, page_size: usize) {
+        self.details.scroll_page_down(page_size);
+    }
+
+    // Text popup delegation
+    pub fn show_hot_threads(&mut self) {
+        let client = self.es_client.clone();
+        self.text_popup.fetch("Hot Threads", async move {
+            client.lock().await.fetch_hot_threads().await
+        });
+    }
+
+    // Version and build of the cluster; cached by the client after the first fetch
+    pub fn show_cluster_info(&mut self) {
+        let client = self.es_client.clone();
+        self.text_popup.fetch("Cluster Info", async move {
+            client.lock().await.fetch_cluster_info().await
+        });
+    }
+
+    // Shows the full compile errors of the filter, pointing at where each one is
+    pub fn show_filter_error(&mut self) {
+        if let Some(report) = self.filter.error_report() {
+            self.text_popup.show("Filter Error", report);
+        }
+    }
+
+    // Explains why a shard of the selected index (or else the cluster) is unassigned
+    pub fn explain_allocation(&mut self) {
+        let index_name = self.selected_index_rate().map(|i| i.name.clone());
+        let client = self.es_client.clone();
+        self.text_popup.fetch("Allocation Explain", async move {
+            client
+                .lock()
+                .await
+                .fetch_allocation_explain(index_name.as_deref())
+                .await
+        });
+    }
+
+    // Opens the replica count prompt for the selected index
+    pub fn edit_replicas(&mut self) {
+        if !self.allow_destructive {
+            self.set_status("Changing replicas needs --allow-destructive".to_string());
+            return;
+        }
+        if let Some(index) = self.selected_index_rate() {
+            self.replica_prompt = Some(ReplicaPrompt::new(
+                index.name.clone(),
+                index.replicas.as_ref(),
+            ));
+        }
+    }
+
+    // Applies the prompted count in the background; an invalid one keeps the prompt open
+    pub fn submit_replicas(&mut self) {
+        let Some(ref mut prompt) = self.replica_prompt else {
+            return;
+        };
+        let replicas = match prompt.value() {
+            Ok(replicas) => replicas,
+            Err(e) => {
+                prompt.error = Some(e);
+                return;
+            }
+        };
+        let index = prompt.index.clone();
+        self.replica_prompt = None;
+        self.set_status(format!("Setting replicas of {} to {}...", index, replicas));
+
+        let client = self.es_client.clone();
+        let tx = self.replicas_tx.clone();
+        tokio::spawn(async move {
+            let result = client
+                .lock()
+                .await
+                .update_replicas(&index, replicas)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send((index, replicas, result)).await;
+        });
+    }
+
+    pub fn cancel_replica_prompt(&mut self) {
+        self.replica_prompt = None;
+    }
+
+    // Reports a finished replica update and refreshes so the table shows the new count
+    pub fn poll_replicas_update(&mut self) {
+        let Ok((index, replicas, result)) = self.replicas_rx.try_recv() else {
+            return;
+        };
+        match result {
+            Ok(acknowledged) => {
+                let pending = if acknowledged {
+                    ""
+                } else {
+                    " (not acknowledged yet)"
+                };
+                self.set_status(format!(
+                    "Set replicas of {} to {}{}",
+                    index, replicas, pending
+                ));
+                if !self.loading {
+                    self.start_fetch();
+                }
+            }
+            Err(e) => {
+                self.error = Some(format!("Setting replicas of {} failed: {}", index, e));
+            }
+        }
+    }
+
+    pub fn toggle_exclude_selected(&mut self) {
+        if let Some(selected) = self.selected_index {
+            if let Some(index) = self.selected_index_rate() {
+                let name = index.name.clone();
+                // A new change makes the cleared set stale
+                self.forget_cleared_exclusions();
+                if self.excluded_indices.contains(&name) {
+                    self.excluded_indices.remove(&name);
+                } else {
+                    self.excluded_indices.insert(name);
+                    // Move selection to next item if possible
+                    let new_count = self.row_count();
+                    if new_count == 0 {
+                        self.selected_index = None;
+                    } else if selected >= new_count {
+                        self.selected_index = Some(new_count.saturating_sub(1));
+                    }
+                }
+            }
+        }
+    }
+
+    // Pins or unpins the selected index; the selection follows it to its new row
+    pub fn toggle_pin_selected(&mut self) {
+        let Some(name) = self.selected_index_rate().map(|i| i.name.clone()) else {
+            return;
+        };
+        if !self.pinned_indices.remove(&name) {
+            self.pinned_indices.insert(name.clone());
+        }
+        self.resort();
+        if self.view != View::Compare {
+            self.selected_index = self.table_rows().iter().position(|row| {
+                row.kind != RowKind::ExcludedMember
+                    && row.as_index().is_some_and(|i| i.name == name)
+            });
+        }
+    }
+
+    pub fn is_pinned(&self, name: &str) -> bool {
+        self.pinned_indices.contains(name)
+    }
+
+    pub fn clear_exclusions(&mut self) {
+        if self.excluded_indices.is_empty() {
+            return;
+        }
+        self.cleared_exclusions = Some(std::mem::take(&mut self.excluded_indices));
+        self.exclusions_cleared_at = Some(Instant::now());
+    }
+
+    pub fn undo_clear_exclusions(&mut self) {
+        if let Some(cleared) = self.cleared_exclusions.take() {
+            self.excluded_indices = cleared;
+            self.exclusions_cleared_at = None;
+            // Reset selection since restored exclusions hide rows again
+            self.selected_index = None;
+        }
+    }
+
+    fn forget_cleared_exclusions(&mut self) {
+        self.cleared_exclusions = None;
+        self.exclusions_cleared_at = None;
+    }
+
+    /// Number of exclusions just cleared, while the footer offers to undo it
+    pub fn exclusions_undo_hint(&self) -> Option<usize> {
+        let cleared_at = self.exclusions_cleared_at?;
+        if cleared_at.elapsed() >= EXCLUSIONS_UNDO_HINT {
+            return None;
+        }
+        self.cleared_exclusions.as_ref().map(HashSet::len)
+    }
+
+    pub fn excluded_count(&self) -> usize {
+        self.excluded_indices.len()
+    }
+
+    pub fn toggle_help_popup(&mut self) {
+        self.show_help_popup = !self.show_help_popup;
+        if self.show_help_popup {
+            self.help_scroll = 0;
+        }
+    }
+
+    pub fn help_scroll_up(&mut self) {
+        self.help_scroll = self.help_scroll.saturating_sub(1);
+    }
+
+    pub fn help_scroll_down(&mut self) {
+        self.help_scroll = self.help_scroll.saturating_add(1);
+    }
+
+    pub fn column_colormap(&self, column: SortColumn) -> Colormap {
+        self.column_colormaps
+            .get(&column)
+            .copied()
+            .unwrap_or(self.colormap)
+    }
+
+    pub fn toggle_gradient_direction(&mut self) {
+        self.invert_gradient = !self.invert_gradient;
+    }
+
+    pub fn cycle_color_mode(&mut self) {
+        self.color_mode = self.color_mode.next();
+    }
+
+    pub fn toggle_size_basis(&mut self) {
+        self.show_total_size = !self.show_total_size;
+        self.resort();
+    }
+
+    pub fn toggle_raw_bytes(&mut self) {
+        self.show_raw_bytes = !self.show_raw_bytes;
+    }
+
+    /// Size as shown in the table and details, rounded or exact depending on `B`
+    pub fn format_size(&self, bytes: u64) -> String {
+        if self.show_raw_bytes {
+            format_bytes_exact(bytes)
+        } else {
+            format_bytes(bytes)
+        }
+    }
+
+    // Cycling only changes the colormap of the active sort column
+    pub fn next_colormap(&mut self) {
+        let column = self.sort.column;
+        let colormap = self.column_colormap(column).next();
+        self.column_colormaps.insert(column, colormap);
+    }
+
+    pub fn prev_colormap(&mut self) {
+        let column = self.sort.column;
+        let colormap = self.column_colormap(column).prev();
+        self.column_colormaps.insert(column, colormap);
+    }
+
+    pub fn handle_action(&mut self, action: Action) {
+        // Anything but a second `O` calls off a pending rollover
+        if self.details.rollover_armed && action != Action::RolloverDataStream {
+            self.details.rollover_armed = false;
+            self.details.status = None;
+        }
         match action {
             Action::Quit => self.quit(),
             Action::SelectUp => self.select_up(),
@@ -573,16 +1960,46 @@ impl App {
             Action::HelpScrollUp => self.help_scroll_up(),
             Action::HelpScrollDown => self.help_scroll_down(),
             Action::TogglePause => self.toggle_pause(),
+            Action::PauseChart => self.toggle_chart_pause(),
+            Action::ClearHistory => self.clear_history(),
             Action::ToggleGraph => self.toggle_graph(),
+            Action::ToggleMovingAverage => self.toggle_moving_average(),
+            Action::CycleChartMetric => self.cycle_chart_metric(),
             Action::ToggleHealth => self.toggle_health(),
+            Action::GrowChart => self.grow_chart(),
+            Action::ShrinkChart => self.shrink_chart(),
+            Action::WidenColumn => self.resize_sort_column(true),
+            Action::NarrowColumn => self.resize_sort_column(false),
+            Action::ToggleRowLayout => self.toggle_row_layout(),
+            Action::NextView => self.set_view(self.view.next()),
+            Action::PrevView => self.set_view(self.view.prev()),
+            Action::SelectView(view) => self.set_view(view),
+            Action::MarkSnapshot => self.mark_snapshot(),
+            Action::WatchSelected => self.watch_selected(),
+            Action::StopWatch => self.stop_watch(),
+            Action::WatchScrollUp => self.watch_scroll_up(),
+            Action::WatchScrollDown => self.watch_scroll_down(),
             Action::ToggleIndices => self.toggle_indices(),
             Action::ToggleSystemIndices => self.toggle_system_indices(),
+            Action::ToggleSystemInList => self.toggle_system_in_list(),
+            Action::ToggleSystemInRate => self.toggle_system_in_rate(),
             Action::ShowDetails => self.show_index_details(),
             Action::ToggleExclude => self.toggle_exclude_selected(),
+            Action::TogglePin => self.toggle_pin_selected(),
             Action::ClearExclusions => self.clear_exclusions(),
+            Action::UndoClearExclusions => self.undo_clear_exclusions(),
             Action::IncreaseRefreshRate => self.increase_refresh_rate(),
             Action::DecreaseRefreshRate => self.decrease_refresh_rate(),
+            Action::IncreaseRateSamples => self.increase_rate_samples(),
+            Action::DecreaseRateSamples => self.decrease_rate_samples(),
             Action::NextColormap => self.next_colormap(),
+            Action::ToggleGradientDirection => self.toggle_gradient_direction(),
+            Action::ToggleSizeBasis => self.toggle_size_basis(),
+            Action::ToggleRawBytes => self.toggle_raw_bytes(),
+            Action::CycleColorMode => self.cycle_color_mode(),
+            Action::ToggleRollup => self.toggle_rollup(),
+            Action::ToggleExcludedSection => self.toggle_excluded_section(),
+            Action::ToggleUnhealthyFirst => self.toggle_unhealthy_first(),
             Action::PrevColormap => self.prev_colormap(),
             Action::NextColumn => self.next_column(),
             Action::PrevColumn => self.prev_column(),
@@ -590,29 +2007,116 @@ impl App {
             Action::EnterFilterMode => self.enter_filter_mode(),
             Action::ExitFilterMode => self.exit_filter_mode(),
             Action::ClearFilter => self.clear_filter(),
+            Action::CopyFilter => self.copy_filter(),
+            Action::ShowFilterError => self.show_filter_error(),
             Action::CloseDetails => self.close_details_popup(),
+            Action::ToggleDetailsFullscreen => self.details.toggle_fullscreen(),
+            Action::ExportDetails => self.export_details(),
+            Action::RolloverDataStream => self.rollover_data_stream(),
+            Action::LoadShards => self.load_shards(),
+            Action::NextProblem => {
+                self.select_unhealthy(true);
+            }
+            Action::PrevProblem => {
+                self.select_unhealthy(false);
+            }
+            Action::NextUnhealthyDetails => self.step_unhealthy_details(true),
+            Action::PrevUnhealthyDetails => self.step_unhealthy_details(false),
             Action::DetailsScrollUp => self.details_scroll_up(),
             Action::DetailsScrollDown => self.details_scroll_down(),
             Action::DetailsScrollPageUp => self.details_scroll_page_up(10),
             Action::DetailsScrollPageDown => self.details_scroll_page_down(10),
+            Action::RetryNow => self.retry_now(),
+            Action::ShowIndexChart => self.show_index_chart(),
+            Action::CloseIndexChart => self.close_index_chart(),
+            Action::ShowHotThreads => self.show_hot_threads(),
+            Action::ExplainAllocation => self.explain_allocation(),
+            Action::ShowClusterInfo => self.show_cluster_info(),
+            Action::EditReplicas => self.edit_replicas(),
+            Action::SubmitReplicas => self.submit_replicas(),
+            Action::CancelReplicaPrompt => self.cancel_replica_prompt(),
+            Action::CloseTextPopup => self.text_popup.close(),
+            Action::TextPopupScrollUp => self.text_popup.scroll_up(),
+            Action::TextPopupScrollDown => self.text_popup.scroll_down(),
+            Action::TextPopupScrollPageUp => self.text_popup.scroll_page_up(10),
+            Action::TextPopupScrollPageDown => self.text_popup.scroll_page_down(10),
         }
     }
 }
 
+// Defaults with the configured widths applied, clamped to what `H`/`L` can reach
+fn initial_column_widths(configured: &[(SortColumn, u16)]) -> [u16; 9] {
+    let mut widths = DEFAULT_COLUMN_WIDTHS;
+    for &(column, width) in configured {
+        widths[column as usize] = width.clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH);
+    }
+    widths
+}
+
+// Append a sample to a bounded history, dropping the oldest point when full
+fn push_history(history: &mut VecDeque<u64>, value: u64) {
+    if history.len() >= MAX_HISTORY_POINTS {
+        history.pop_front();
+    }
+    history.push_back(value);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::EstiCliError;
+    use crate::models::{DataStreamDetails, IndexDetails, DEFAULT_STAT_GROUPS};
+    use crate::ui::types::SortOrder;
 
     fn setup_mock_app() -> App {
-        let mut app = App::new(
-            "http://localhost:9200".to_string(),
-            AuthConfig::None,
-            false,
-            None,
-            5,
-            Colormap::Turbo,
-            10,
-        )
+        let mut app = App::new(AppConfig {
+            base_url: "http://localhost:9200".to_string(),
+            auth: AuthConfig::None,
+            insecure: false,
+            ca_cert: None,
+            refresh_secs: 5,
+            connect_timeout_secs: 5,
+            timeout_secs: 30,
+            max_error_body: 4096,
+            colormap: Colormap::Turbo,
+            column_colormaps: HashMap::new(),
+            column_widths: Vec::new(),
+            rate_samples: 10,
+            theme: Theme::DARK,
+            avg_window: 5,
+            timezone: Timezone::Local,
+            rate_unit: RateUnit::Sec,
+            gradient_scale: GradientScale::Log,
+            spinner_style: SpinnerStyle::Braille,
+            status_indicator: StatusIndicator::Icon,
+            selection_style: SelectionStyle::Reversed,
+            zebra: false,
+            row_layout: RowLayout::Horizontal,
+            compact: false,
+            total_size: false,
+            watch: None,
+            alert: false,
+            alert_command: None,
+            alert_recovery: false,
+            webhook_url: None,
+            system_patterns: Vec::new(),
+            include_patterns: Vec::new(),
+            exclude_patterns: Vec::new(),
+            age_tiers: Vec::new(),
+            index_pattern: None,
+            rollup_separators: "-_.".to_string(),
+            stat_groups: DEFAULT_STAT_GROUPS.to_vec(),
+            details_concurrency: None,
+            lazy_shards: false,
+            dump_dir: None,
+            heap_warning: None,
+            allow_destructive: false,
+            snapshot_export: None,
+            snapshot_interval: Duration::from_secs(86400),
+            snapshot_append: false,
+            pause_on_blur: false,
+            name_transforms: Vec::new(),
+        })
         .unwrap();
 
         app.indices = vec![
@@ -621,21 +2125,27 @@ mod tests {
                 doc_count: 100,
                 rate_per_sec: 1.0,
                 size_bytes: 1024,
+                total_size_bytes: 1024,
                 health: "green".to_string(),
+                ..Default::default()
             },
             IndexRate {
                 name: "index-2".to_string(),
                 doc_count: 200,
                 rate_per_sec: 2.0,
                 size_bytes: 2048,
+                total_size_bytes: 2048,
                 health: "green".to_string(),
+                ..Default::default()
             },
             IndexRate {
                 name: "index-3".to_string(),
                 doc_count: 300,
                 rate_per_sec: 3.0,
                 size_bytes: 3072,
+                total_size_bytes: 3072,
                 health: "green".to_string(),
+                ..Default::default()
             },
         ];
         app
@@ -677,6 +2187,905 @@ mod tests {
         assert_eq!(app.selected_index, Some(0));
     }
 
+    #[test]
+    fn test_views_keep_own_selection() {
+        let mut app = setup_mock_app();
+        app.select_last();
+        assert_eq!(app.selected_index, Some(2));
+
+        // A view that was never visited starts without a selection
+        app.handle_action(Action::NextView);
+        assert_eq!(app.view, View::Indices);
+        assert_eq!(app.selected_index, None);
+        app.select_first();
+
+        // Switching back restores each view's selection
+        app.handle_action(Action::PrevView);
+        assert_eq!(app.view, View::Overview);
+        assert_eq!(app.selected_index, Some(2));
+        app.handle_action(Action::SelectView(View::Indices));
+        assert_eq!(app.selected_index, Some(0));
+    }
+
+    #[test]
+    fn test_nodes_view_fetches_missing_shards_right_away() {
+        let mut app = setup_mock_app();
+        app.last_refresh = Some(Instant::now());
+        assert!(!app.should_refresh());
+
+        // Shards are skipped outside the Nodes view, so opening it shouldn't wait an interval
+        app.handle_action(Action::SelectView(View::Nodes));
+        assert!(app.should_refresh());
+    }
+
+    // Stands in for a fetch task that panicked before sending its result
+    async fn kill_fetch_task(app: &mut App) {
+        let task = tokio::spawn(async { panic!("fetch task died") });
+        while !task.is_finished() {
+            tokio::task::yield_now().await;
+        }
+        app.loading = true;
+        app.fetch_task = Some(task);
+    }
+
+    #[tokio::test]
+    async fn test_recovers_from_panicked_fetch_task() {
+        let mut app = setup_mock_app();
+        app.last_refresh = Some(Instant::now());
+
+        kill_fetch_task(&mut app).await;
+        app.poll_fetch_result();
+        assert!(!app.loading);
+        assert!(app.fetch_task.is_none());
+        assert!(app.error.is_some());
+        assert!(app.reconnect_at.is_some());
+        assert_eq!(app.reconnect_backoff, RECONNECT_BACKOFF_MIN * 2);
+
+        // Once the backoff elapses a fresh fetch is due, even within the refresh interval
+        app.reconnect_at = Some(Instant::now());
+        assert!(app.should_refresh());
+
+        // The channel still delivers results
+        app.fetch_tx
+            .try_send((
+                Ok(Vec::new()),
+                Ok(ClusterHealth::default()),
+                None,
+                None,
+                Duration::ZERO,
+            ))
+            .unwrap();
+        app.poll_fetch_result();
+        assert!(app.error.is_none());
+        assert_eq!(app.reconnect_backoff, RECONNECT_BACKOFF_MIN);
+    }
+
+    #[test]
+    fn test_data_age_grows_while_fetches_fail() {
+        let mut app = setup_mock_app();
+        app.last_success = Some(Instant::now() - Duration::from_secs(30));
+
+        app.fetch_tx
+            .try_send((
+                Err(EstiCliError::Internal("connection refused".to_string())),
+                Ok(ClusterHealth::default()),
+                None,
+                None,
+                Duration::ZERO,
+            ))
+            .unwrap();
+        app.poll_fetch_result();
+        assert!(app.error.is_some());
+        assert!(app.data_age().unwrap() >= Duration::from_secs(30));
+
+        app.fetch_tx
+            .try_send((
+                Ok(Vec::new()),
+                Ok(ClusterHealth::default()),
+                None,
+                None,
+                Duration::ZERO,
+            ))
+            .unwrap();
+        app.poll_fetch_result();
+        assert!(app.data_age().unwrap() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_api_latency_rolling_average() {
+        let mut app = setup_mock_app();
+        assert_eq!(app.api_latency_display(), None);
+
+        for ms in [100, 10, 20, 30, 40, 50] {
+            app.fetch_tx
+                .try_send((
+                    Ok(Vec::new()),
+                    Ok(ClusterHealth::default()),
+                    None,
+                    None,
+                    Duration::from_millis(ms),
+                ))
+                .unwrap();
+            app.poll_fetch_result();
+        }
+
+        // The oldest sample has rolled off
+        assert_eq!(app.api_latency.len(), LATENCY_SAMPLES);
+        assert_eq!(app.api_latency_display().as_deref(), Some("30ms"));
+    }
+
+    #[test]
+    fn test_health_and_rates_fail_independently() {
+        let mut app = setup_mock_app();
+        app.cluster_health.cluster_name = "prod".to_string();
+        let index = IndexRate {
+            name: "logs".to_string(),
+            doc_count: 5,
+            health: "green".to_string(),
+            ..Default::default()
+        };
+        fn failure<T>() -> Result<T> {
+            Err(EstiCliError::Internal("boom".to_string()))
+        }
+
+        // Health fails: the rates still land, health turns unknown
+        app.fetch_tx
+            .try_send((
+                Ok(vec![index]),
+                failure(),
+                None,
+                None,
+                Duration::from_secs(30),
+            ))
+            .unwrap();
+        app.poll_fetch_result();
+        assert_eq!(app.indices.len(), 1);
+        assert_eq!(app.cluster_health.status, UNKNOWN_HEALTH);
+        assert_eq!(app.cluster_health.cluster_name, "prod");
+        assert!(app.health_error.is_some());
+        assert!(app.error.is_none());
+        // The failed call's duration is not a latency sample
+        assert!(app.api_latency.is_empty());
+
+        // Rates fail: the last good indices stay, health recovers
+        let health = ClusterHealth {
+            status: "green".to_string(),
+            ..Default::default()
+        };
+        app.fetch_tx
+            .try_send((failure(), Ok(health), None, None, Duration::ZERO))
+            .unwrap();
+        app.poll_fetch_result();
+        assert_eq!(app.indices.len(), 1);
+        assert!(app.error.is_some());
+        assert_eq!(app.cluster_health.status, "green");
+        assert!(app.health_error.is_none());
+    }
+
+    #[test]
+    fn test_chart_pause_keeps_table_live() {
+        let mut app = setup_mock_app();
+        let send = |app: &mut App, doc_count: u64| {
+            let index = IndexRate {
+                name: "logs".to_string(),
+                doc_count,
+                health: "green".to_string(),
+                ..Default::default()
+            };
+            app.fetch_tx
+                .try_send((
+                    Ok(vec![index]),
+                    Ok(ClusterHealth::default()),
+                    None,
+                    None,
+                    Duration::ZERO,
+                ))
+                .unwrap();
+            app.poll_fetch_result();
+        };
+
+        send(&mut app, 1);
+        app.handle_action(Action::PauseChart);
+        send(&mut app, 2);
+        assert_eq!(app.indices[0].doc_count, 2);
+        assert_eq!(app.doc_count_history, VecDeque::from([1]));
+
+        app.handle_action(Action::PauseChart);
+        send(&mut app, 3);
+        assert_eq!(app.doc_count_history, VecDeque::from([1, 3]));
+    }
+
+    #[test]
+    fn test_unassigned_history_skips_failed_health() {
+        let mut app = setup_mock_app();
+        let send = |app: &mut App, health: Result<ClusterHealth>| {
+            app.fetch_tx
+                .try_send((Ok(Vec::new()), health, None, None, Duration::ZERO))
+                .unwrap();
+            app.poll_fetch_result();
+        };
+
+        let health = |unassigned_shards| ClusterHealth {
+            unassigned_shards,
+            ..Default::default()
+        };
+        send(&mut app, Ok(health(4)));
+        send(&mut app, Err(EstiCliError::Internal("down".to_string())));
+        send(&mut app, Ok(health(1)));
+        assert_eq!(app.unassigned_history, VecDeque::from([4, 1]));
+
+        app.chart_metric = ChartMetric::UnassignedShards;
+        assert_eq!(app.chart_history_vec(), vec![4, 1]);
+    }
+
+    #[test]
+    fn test_index_chart_uses_selected_index_history() {
+        let mut app = setup_mock_app();
+        app.index_rate_history
+            .insert("index-2".to_string(), VecDeque::from([1.4, 2.6]));
+
+        app.selected_index = Some(1);
+        app.handle_action(Action::ShowIndexChart);
+        assert_eq!(app.index_chart.as_deref(), Some("index-2"));
+        assert_eq!(app.index_rate_history_vec("index-2"), vec![1, 3]);
+
+        app.handle_action(Action::CloseIndexChart);
+        assert!(app.index_chart.is_none());
+    }
+
+    #[test]
+    fn test_copy_and_paste_filter() {
+        let mut app = setup_mock_app();
+        app.handle_action(Action::CopyFilter);
+        assert_eq!(app.clipboard, None);
+        assert_eq!(app.status_message(), Some("No filter to copy"));
+
+        // Outside filter mode a paste replaces the filter
+        app.filter.input = "select(.health == \"red\")".into();
+        app.paste_filter("select(.name == \"index-2\")");
+        assert!(app.filter.active);
+        assert_eq!(app.filtered_indices().len(), 1);
+
+        app.handle_action(Action::CopyFilter);
+        assert_eq!(
+            app.clipboard.as_deref(),
+            Some("select(.name == \"index-2\")")
+        );
+    }
+
+    #[test]
+    fn test_filter_totals_sum_matched_indices() {
+        let mut app = setup_mock_app();
+        assert_eq!(app.filter_totals(), None);
+
+        app.filter.input = "select(.doc_count >= 200)".into();
+        app.filter.recompile();
+        assert_eq!(
+            app.filter_totals(),
+            Some(FilterTotals {
+                rate_per_sec: 5.0,
+                doc_count: 500,
+                size_bytes: 5120,
+            })
+        );
+    }
+
+    #[test]
+    fn test_totals_of_unfetched_groups_show_dash() {
+        let mut app = setup_mock_app();
+        assert_eq!(app.total_cluster_rate_human(), "6.0");
+
+        app.stat_groups = vec![StatGroup::Docs, StatGroup::Store];
+        assert_eq!(app.total_cluster_rate_human(), "-");
+        assert_eq!(app.total_cluster_bytes_per_sec_human(), "-");
+        assert_eq!(app.if_fetched(&[StatGroup::Docs], "600".to_string()), "600");
+    }
+
+    // Details popup showing `name`, backing `data_stream` when given
+    fn show_details_of(app: &mut App, name: &str, data_stream: Option<&str>) {
+        app.details.show_popup = true;
+        app.details.index = Some(name.to_string());
+        app.details.data = Some(IndexDetails {
+            name: name.to_string(),
+            data_stream: data_stream.map(|ds| DataStreamDetails {
+                name: ds.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_live_segments_need_the_segments_group() {
+        let mut app = setup_mock_app();
+        app.indices[0].segment_count = 42;
+        app.indices[0].segments_delta = -3;
+        show_details_of(&mut app, "index-1", None);
+        let segments_line = |app: &App| {
+            crate::ui::details_popup::details_lines(app, &app.details)
+                .iter()
+                .map(|line| line.to_string())
+                .find(|line| line.starts_with("Live Segments"))
+        };
+
+        assert_eq!(segments_line(&app).as_deref(), Some("Live Segments: -"));
+        app.stat_groups.push(StatGroup::Segments);
+        assert_eq!(
+            segments_line(&app).as_deref(),
+            Some("Live Segments: 42 (-3 since last refresh)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rollover_needs_allow_destructive_and_confirmation() {
+        let mut app = setup_mock_app();
+        show_details_of(&mut app, "users", None);
+        assert_eq!(app.details_data_stream(), None);
+        app.handle_action(Action::RolloverDataStream);
+        assert!(app.details.status.is_none());
+
+        show_details_of(&mut app, ".ds-logs-000001", Some("logs"));
+        assert_eq!(app.details_data_stream(), Some("logs"));
+        app.handle_action(Action::RolloverDataStream);
+        assert_eq!(
+            app.details.status,
+            Some(Err("Rollover needs --allow-destructive".to_string()))
+        );
+
+        // The first O only arms the rollover; any other key calls it off
+        app.allow_destructive = true;
+        app.handle_action(Action::RolloverDataStream);
+        assert!(app.details.rollover_armed);
+        app.handle_action(Action::DetailsScrollDown);
+        assert!(!app.details.rollover_armed);
+        assert!(app.details.status.is_none());
+
+        app.handle_action(Action::RolloverDataStream);
+        app.handle_action(Action::RolloverDataStream);
+        assert!(!app.details.rollover_armed);
+        assert_eq!(
+            app.details.status,
+            Some(Ok("Rolling over logs...".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rollover_refetches_the_rolled_over_index() {
+        let mut app = setup_mock_app();
+        // Keep the success path from starting a table refresh
+        app.loading = true;
+        app.selected_index = Some(1);
+        let rolled_over = |app: &mut App| {
+            app.rollover_tx
+                .try_send((
+                    ".ds-logs-000001".to_string(),
+                    "logs".to_string(),
+                    Ok(".ds-logs-000002".to_string()),
+                ))
+                .unwrap();
+            app.poll_rollover();
+        };
+
+        // Not the selected row, but the index the rollover was started from
+        show_details_of(&mut app, ".ds-logs-000001", Some("logs"));
+        rolled_over(&mut app);
+        assert_eq!(app.details.index.as_deref(), Some(".ds-logs-000001"));
+        assert!(app.details.loading);
+
+        // The popup moved on before the result came in
+        show_details_of(&mut app, "index-3", None);
+        app.details.loading = false;
+        rolled_over(&mut app);
+        assert_eq!(app.details.index.as_deref(), Some("index-3"));
+        assert!(!app.details.loading);
+    }
+
+    #[test]
+    fn test_rollover_outcome_status() {
+        let mut app = setup_mock_app();
+        show_details_of(&mut app, ".ds-logs-000001", Some("logs"));
+        // Keep the success path from starting background fetches
+        app.details.show_popup = false;
+        app.loading = true;
+
+        app.rollover_tx
+            .try_send((
+                ".ds-logs-000001".to_string(),
+                "logs".to_string(),
+                Ok(".ds-logs-000002".to_string()),
+            ))
+            .unwrap();
+        app.poll_rollover();
+        assert_eq!(
+            app.details.status,
+            Some(Ok(
+                "Rolled over logs, new write index .ds-logs-000002".to_string()
+            ))
+        );
+
+        app.rollover_tx
+            .try_send((
+                ".ds-logs-000001".to_string(),
+                "logs".to_string(),
+                Err("403 Forbidden".to_string()),
+            ))
+            .unwrap();
+        app.poll_rollover();
+        assert_eq!(
+            app.details.status,
+            Some(Err("Rollover of logs failed: 403 Forbidden".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_edit_replicas_needs_allow_destructive() {
+        let mut app = setup_mock_app();
+        app.selected_index = Some(0);
+        app.handle_action(Action::EditReplicas);
+        assert!(app.replica_prompt.is_none());
+        assert_eq!(
+            app.status_message(),
+            Some("Changing replicas needs --allow-destructive")
+        );
+
+        app.allow_destructive = true;
+        app.handle_action(Action::EditReplicas);
+        let prompt = app.replica_prompt.as_mut().unwrap();
+        prompt.input = "-1".into();
+
+        // Invalid input keeps the prompt open with the reason
+        app.handle_action(Action::SubmitReplicas);
+        let prompt = app.replica_prompt.as_ref().unwrap();
+        assert!(prompt.error.is_some());
+
+        app.handle_action(Action::CancelReplicaPrompt);
+        assert!(app.replica_prompt.is_none());
+    }
+
+    #[test]
+    fn test_replica_prompt_renders_multibyte_input() {
+        use crate::ui::replica_prompt::ReplicaPromptPopup;
+        use crossterm::event::{Event, KeyCode, KeyEvent};
+        use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+        use tui_input::backend::crossterm::EventHandler;
+
+        let mut app = setup_mock_app();
+        app.allow_destructive = true;
+        app.selected_index = Some(0);
+        app.handle_action(Action::EditReplicas);
+        let prompt = app.replica_prompt.as_mut().unwrap();
+        prompt.input = "1".into();
+        for c in ['é', '2'] {
+            prompt
+                .input
+                .handle_event(&Event::Key(KeyEvent::from(KeyCode::Char(c))));
+        }
+        prompt
+            .input
+            .handle_event(&Event::Key(KeyEvent::from(KeyCode::Left)));
+
+        // The cursor counts characters; splitting the value by it must not cut into `é`
+        let prompt = app.replica_prompt.as_ref().unwrap();
+        let area = Rect::new(0, 0, 80, 10);
+        let mut buf = Buffer::empty(area);
+        ReplicaPromptPopup::new(&app, prompt).render(area, &mut buf);
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("number_of_replicas: 1é▏2"));
+    }
+
+    #[test]
+    fn test_status_marker_icon_or_text() {
+        let mut app = setup_mock_app();
+        assert_eq!(app.status_marker(), "✓");
+
+        app.status_indicator = StatusIndicator::Text;
+        assert_eq!(app.status_marker(), "idle");
+        app.loading = true;
+        assert_eq!(app.status_marker(), "loading");
+    }
+
+    #[test]
+    fn test_empty_table_while_connecting() {
+        let mut app = setup_mock_app();
+        app.indices.clear();
+        assert_eq!(
+            app.empty_table_message().as_deref(),
+            Some("Connecting to http://localhost:9200…")
+        );
+
+        // A failed first fetch shows its error instead of an empty cluster
+        app.fetch_tx
+            .try_send((
+                Err(EstiCliError::Internal("refused".to_string())),
+                Ok(ClusterHealth::default()),
+                None,
+                None,
+                Duration::ZERO,
+            ))
+            .unwrap();
+        app.poll_fetch_result();
+        let message = app.empty_table_message().unwrap();
+        assert!(message.starts_with("Can't connect to http://localhost:9200"));
+        assert!(message.contains("refused"));
+
+        app.fetch_tx
+            .try_send((
+                Ok(Vec::new()),
+                Ok(ClusterHealth::default()),
+                None,
+                None,
+                Duration::ZERO,
+            ))
+            .unwrap();
+        app.poll_fetch_result();
+        assert_eq!(
+            app.empty_table_message().as_deref(),
+            Some("Cluster has no indices")
+        );
+    }
+
+    #[test]
+    fn test_empty_filtered_indices() {
+        let mut app = setup_mock_app();
+        app.last_success = Some(Instant::now());
+        app.selected_index = Some(1);
+        for name in ["index-1", "index-2", "index-3"] {
+            app.excluded_indices.insert(name.to_string());
+        }
+
+        assert!(app.filtered_indices().is_empty());
+        assert_eq!(
+            app.empty_table_message().as_deref(),
+            Some("No indices match the filter, exclusions or system-index settings")
+        );
+
+        // Navigation clears the stale selection and index actions do nothing
+        for action in [Action::SelectDown, Action::SelectFirst, Action::SelectLast] {
+            app.handle_action(action);
+            assert_eq!(app.selected_index, None);
+        }
+        app.handle_action(Action::ShowDetails);
+        app.handle_action(Action::WatchSelected);
+        app.handle_action(Action::ShowIndexChart);
+        assert!(!app.details.show_popup);
+        assert!(app.watch.is_none());
+        assert!(app.index_chart.is_none());
+
+        app.indices.clear();
+        assert_eq!(
+            app.empty_table_message().as_deref(),
+            Some("Cluster has no indices")
+        );
+
+        app.index_pattern = Some("logs-*".to_string());
+        assert_eq!(
+            app.empty_table_message().as_deref(),
+            Some("No indices match --index logs-*")
+        );
+    }
+
+    #[test]
+    fn test_size_basis_toggle_resorts() {
+        let mut app = setup_mock_app();
+        app.indices[0].total_size_bytes = 10_000;
+        app.sort.column = SortColumn::Size;
+        app.sort.order = SortOrder::Descending;
+
+        app.handle_action(Action::ToggleSizeBasis);
+        assert!(app.show_total_size);
+        assert_eq!(app.indices[0].name, "index-1");
+
+        app.handle_action(Action::ToggleSizeBasis);
+        assert_eq!(app.indices[0].name, "index-3");
+    }
+
+    #[test]
+    fn test_raw_bytes_toggle() {
+        let mut app = setup_mock_app();
+        assert_eq!(app.format_size(1_234_567), "1.2 MiB");
+
+        app.handle_action(Action::ToggleRawBytes);
+        assert_eq!(app.format_size(1_234_567), "1,234,567 B");
+        assert_eq!(app.format_size(999), "999 B");
+        assert_eq!(app.format_size(0), "0 B");
+
+        app.handle_action(Action::ToggleRawBytes);
+        assert!(!app.show_raw_bytes);
+    }
+
+    #[test]
+    fn test_unhealthy_first_survives_resort() {
+        let mut app = setup_mock_app();
+        app.indices[0].health = "yellow".to_string();
+        app.sort.column = SortColumn::Rate;
+        app.sort.order = SortOrder::Descending;
+
+        app.handle_action(Action::ToggleUnhealthyFirst);
+        let names =
+            |app: &App| -> Vec<String> { app.indices.iter().map(|i| i.name.clone()).collect() };
+        assert_eq!(names(&app), ["index-1", "index-3", "index-2"]);
+
+        // Changing the sort order keeps the unhealthy index pinned
+        app.handle_action(Action::ToggleSortOrder);
+        assert_eq!(names(&app), ["index-1", "index-2", "index-3"]);
+
+        app.handle_action(Action::ToggleUnhealthyFirst);
+        assert_eq!(names(&app), ["index-1", "index-2", "index-3"]);
+        app.handle_action(Action::ToggleSortOrder);
+        assert_eq!(names(&app), ["index-3", "index-2", "index-1"]);
+    }
+
+    #[test]
+    fn test_initial_column_widths_from_config() {
+        let widths = initial_column_widths(&[(SortColumn::Name, 70), (SortColumn::Size, 1)]);
+        assert_eq!(widths[SortColumn::Name as usize], 70);
+        assert_eq!(widths[SortColumn::Size as usize], MIN_COLUMN_WIDTH);
+        assert_eq!(widths[SortColumn::Rate as usize], DEFAULT_COLUMN_WIDTHS[2]);
+    }
+
+    #[test]
+    fn test_pin_keeps_index_on_top_and_selected() {
+        let mut app = setup_mock_app();
+        app.sort.column = SortColumn::Rate;
+        app.sort.order = SortOrder::Descending;
+        app.resort();
+        let names =
+            |app: &App| -> Vec<String> { app.indices.iter().map(|i| i.name.clone()).collect() };
+
+        app.selected_index = Some(2);
+        app.handle_action(Action::TogglePin);
+        assert_eq!(names(&app), ["index-1", "index-3", "index-2"]);
+        assert_eq!(app.selected_index, Some(0));
+
+        // Pinned indices survive a re-sort and still count in the totals
+        app.handle_action(Action::ToggleSortOrder);
+        assert_eq!(names(&app), ["index-1", "index-2", "index-3"]);
+        assert_eq!(app.filtered_indices().len(), 3);
+
+        app.handle_action(Action::TogglePin);
+        assert!(app.pinned_indices.is_empty());
+        assert_eq!(names(&app), ["index-1", "index-2", "index-3"]);
+        assert_eq!(app.selected_index, Some(0));
+    }
+
+    #[test]
+    fn test_heap_pressure_reports_fullest_node_over_threshold() {
+        let mut app = setup_mock_app();
+        let heap = |node: &str, percent| NodeHeap {
+            node: node.to_string(),
+            heap_used_percent: percent,
+        };
+        app.node_heap = vec![heap("node-1", 88), heap("node-2", 93), heap("node-3", 40)];
+        assert_eq!(app.heap_pressure(), None);
+
+        app.heap_warning = Some(85);
+        assert_eq!(app.heap_pressure().map(|n| n.node.as_str()), Some("node-2"));
+
+        app.heap_warning = Some(95);
+        assert_eq!(app.heap_pressure(), None);
+    }
+
+    #[test]
+    fn test_rollup_enter_expands_group() {
+        let mut app = setup_mock_app();
+        app.handle_action(Action::ToggleRollup);
+        assert_eq!(app.selected_index, Some(0));
+        assert_eq!(app.table_rows().len(), 1);
+        assert!(app.selected_index_rate().is_none());
+
+        app.handle_action(Action::ShowDetails);
+        assert!(!app.details.show_popup);
+        assert_eq!(app.table_rows().len(), 4);
+
+        app.handle_action(Action::SelectDown);
+        assert_eq!(
+            app.selected_index_rate().map(|i| i.name.as_str()),
+            Some("index-1")
+        );
+    }
+
+    #[test]
+    fn test_excluded_section_folds_and_reincludes() {
+        let mut app = setup_mock_app();
+        app.excluded_indices.insert("index-1".to_string());
+        app.handle_action(Action::ToggleExcludedSection);
+        assert_eq!(app.excluded_filtered_indices().len(), 1);
+        assert_eq!(app.filtered_indices().len(), 2);
+
+        // Folded divider after the two included rows
+        let rows = app.table_rows();
+        assert_eq!(rows.len(), 3);
+        assert!(matches!(rows[2].kind, RowKind::Excluded { members: 1, .. }));
+        drop(rows);
+
+        app.selected_index = Some(2);
+        assert!(app.selected_index_rate().is_none());
+        app.handle_action(Action::ShowDetails);
+        assert!(!app.details.show_popup);
+        assert_eq!(app.table_rows().len(), 4);
+
+        app.handle_action(Action::SelectDown);
+        app.handle_action(Action::ToggleExclude);
+        assert!(app.excluded_indices.is_empty());
+        assert_eq!(app.table_rows().len(), 3);
+    }
+
+    #[test]
+    fn test_resize_sort_column_is_clamped() {
+        let mut app = setup_mock_app();
+        app.sort.column = SortColumn::Name;
+        for _ in 0..10 {
+            app.handle_action(Action::WidenColumn);
+        }
+        assert_eq!(app.column_widths[0], MAX_COLUMN_WIDTH);
+
+        app.sort.column = SortColumn::Size;
+        for _ in 0..10 {
+            app.handle_action(Action::NarrowColumn);
+        }
+        assert_eq!(app.column_widths[3], MIN_COLUMN_WIDTH);
+        assert_eq!(app.column_widths[1], DEFAULT_COLUMN_WIDTHS[1]);
+    }
+
+    #[test]
+    fn test_clear_history_empties_chart_and_rates() {
+        let mut app = setup_mock_app();
+        let mut indices = app.indices.clone();
+        app.update_indices_with_rates(&mut indices);
+        push_history(&mut app.rate_history, 10);
+        assert!(!app.history_just_cleared());
+
+        app.handle_action(Action::ClearHistory);
+        assert!(app.rate_history.is_empty());
+        assert!(app.index_rate_history_vec("index-1").is_empty());
+        assert!(app.history_just_cleared());
+    }
+
+    #[tokio::test]
+    async fn test_details_step_through_unhealthy_indices() {
+        let mut app = setup_mock_app();
+        app.indices[0].health = "yellow".to_string();
+        app.indices[2].health = "red".to_string();
+        app.selected_index = Some(0);
+        app.show_index_details();
+        assert_eq!(app.unhealthy_position(), (Some(0), 2));
+
+        // The green index in between is skipped, and stepping wraps around
+        app.handle_action(Action::NextUnhealthyDetails);
+        assert_eq!(app.selected_index, Some(2));
+        assert!(app.details.show_popup && app.details.loading);
+        app.handle_action(Action::NextUnhealthyDetails);
+        assert_eq!(app.selected_index, Some(0));
+        app.handle_action(Action::PrevUnhealthyDetails);
+        assert_eq!(app.selected_index, Some(2));
+    }
+
+    #[test]
+    fn test_display_name_chains_transforms() {
+        let mut app = setup_mock_app();
+        assert!(matches!(
+            app.display_name("index-1"),
+            Cow::Borrowed("index-1")
+        ));
+
+        let transform = |pattern: &str, replace: &str| NameTransform {
+            pattern: regex::Regex::new(pattern).unwrap(),
+            replace: replace.to_string(),
+        };
+        app.name_transforms = vec![
+            transform(r"^\.ds-", ""),
+            transform(r"-\d{4}\.\d{2}\.\d{2}-0*(\d+)$", " #$1"),
+        ];
+        assert_eq!(app.display_name(".ds-logs-2024.01.01-000042"), "logs #42");
+
+        // The table shows the label; the selection still resolves to the real index
+        app.selected_index = Some(0);
+        app.name_transforms = vec![transform("index-", "i")];
+        assert_eq!(app.display_name("index-1"), "i1");
+        assert_eq!(
+            app.selected_index_rate().map(|i| i.name.as_str()),
+            Some("index-1")
+        );
+    }
+
+    #[test]
+    fn test_rate_samples_adjust_and_truncate() {
+        let mut app = setup_mock_app();
+        app.index_rate_history
+            .insert("index-1".to_string(), VecDeque::from([1.0, 2.0, 3.0, 4.0]));
+        app.rate_samples = 4;
+
+        app.handle_action(Action::DecreaseRateSamples);
+        app.handle_action(Action::DecreaseRateSamples);
+        assert_eq!(app.rate_samples, 2);
+        assert_eq!(app.index_rate_history_vec("index-1"), vec![3, 4]);
+
+        for _ in 0..5 {
+            app.handle_action(Action::DecreaseRateSamples);
+        }
+        assert_eq!(app.rate_samples, 1);
+        for _ in 0..100 {
+            app.handle_action(Action::IncreaseRateSamples);
+        }
+        assert_eq!(app.rate_samples, MAX_RATE_SAMPLES);
+    }
+
+    #[test]
+    fn test_jump_between_problem_indices() {
+        let mut app = setup_mock_app();
+        app.handle_action(Action::NextProblem);
+        assert_eq!(app.selected_index, None);
+
+        app.indices[1].health = "yellow".to_string();
+        app.indices[2].health = "red".to_string();
+        app.handle_action(Action::NextProblem);
+        assert_eq!(app.selected_index, Some(1));
+        app.handle_action(Action::NextProblem);
+        assert_eq!(app.selected_index, Some(2));
+        app.handle_action(Action::NextProblem);
+        assert_eq!(app.selected_index, Some(1));
+        app.handle_action(Action::PrevProblem);
+        assert_eq!(app.selected_index, Some(2));
+        assert!(!app.details.show_popup);
+    }
+
+    #[test]
+    fn test_pause_on_blur_keeps_manual_pause() {
+        let mut app = setup_mock_app();
+        app.focus_lost();
+        assert!(!app.paused);
+
+        app.pause_on_blur = true;
+        app.focus_lost();
+        assert!(app.paused && app.blur_paused);
+        app.focus_gained();
+        assert!(!app.paused);
+
+        // Paused by hand before losing focus: regaining it doesn't resume
+        app.handle_action(Action::TogglePause);
+        app.focus_lost();
+        app.focus_gained();
+        assert!(app.paused);
+
+        // Paused by hand while unfocused: the user's pause wins too
+        app.handle_action(Action::TogglePause);
+        app.focus_lost();
+        app.handle_action(Action::TogglePause);
+        app.handle_action(Action::TogglePause);
+        app.focus_gained();
+        assert!(app.paused);
+    }
+
+    #[tokio::test]
+    async fn test_retry_now_resets_backoff_and_fetches() {
+        let mut app = setup_mock_app();
+        kill_fetch_task(&mut app).await;
+        app.poll_fetch_result();
+        assert!(app.reconnect_at.is_some());
+
+        app.handle_action(Action::RetryNow);
+        assert!(app.error.is_none());
+        assert!(app.reconnect_at.is_none());
+        assert_eq!(app.reconnect_backoff, RECONNECT_BACKOFF_MIN);
+        assert!(app.loading);
+    }
+
+    #[test]
+    fn test_colormap_cycles_per_sort_column() {
+        let mut app = setup_mock_app();
+        app.sort.column = SortColumn::Size;
+        app.next_colormap();
+
+        assert_eq!(
+            app.column_colormap(SortColumn::Size),
+            Colormap::Turbo.next()
+        );
+        assert_eq!(app.column_colormap(SortColumn::Rate), Colormap::Turbo);
+
+        app.prev_colormap();
+        assert_eq!(app.column_colormap(SortColumn::Size), Colormap::Turbo);
+    }
+
     #[test]
     fn test_pagination() {
         let mut app = setup_mock_app();
@@ -691,6 +3100,28 @@ mod tests {
         assert_eq!(app.selected_index, Some(0));
     }
 
+    #[test]
+    fn test_undo_clear_exclusions() {
+        let mut app = setup_mock_app();
+        app.excluded_indices.insert("index-1".to_string());
+        app.excluded_indices.insert("index-2".to_string());
+
+        app.handle_action(Action::ClearExclusions);
+        assert!(app.excluded_indices.is_empty());
+        assert_eq!(app.exclusions_undo_hint(), Some(2));
+
+        app.handle_action(Action::UndoClearExclusions);
+        assert_eq!(app.excluded_count(), 2);
+        assert_eq!(app.exclusions_undo_hint(), None);
+
+        // A later exclusion change drops the undo
+        app.handle_action(Action::ClearExclusions);
+        app.selected_index = Some(0);
+        app.toggle_exclude_selected();
+        app.handle_action(Action::UndoClearExclusions);
+        assert_eq!(app.excluded_count(), 1);
+    }
+
     #[test]
     fn test_exclusion_impact_on_selection() {
         let mut app = setup_mock_app();
@@ -718,7 +3149,9 @@ mod tests {
             doc_count: 50,
             rate_per_sec: 10.0,
             size_bytes: 512,
+            total_size_bytes: 512,
             health: "green".to_string(),
+            ..Default::default()
         });
 
         // Current rates: index-1(1.0), index-2(2.0), index-3(3.0) = 6.0
@@ -726,12 +3159,81 @@ mod tests {
         assert_eq!(app.total_cluster_rate(), 6.0);
 
         // Show system indices
-        app.show_system_indices = true;
+        app.toggle_system_indices();
         assert_eq!(app.total_cluster_rate(), 16.0);
 
         // Hide again and exclude index-1
-        app.show_system_indices = false;
+        app.toggle_system_indices();
         app.excluded_indices.insert("index-1".to_string());
         assert_eq!(app.total_cluster_rate(), 5.0); // 2.0 + 3.0
     }
+
+    #[test]
+    fn test_system_indices_in_list_and_rate_independently() {
+        let mut app = setup_mock_app();
+        app.indices.push(IndexRate {
+            name: ".system-index".to_string(),
+            doc_count: 50,
+            rate_per_sec: 10.0,
+            size_bytes: 512,
+            total_size_bytes: 512,
+            health: "green".to_string(),
+            ..Default::default()
+        });
+
+        // Listed, but kept out of the headline rate
+        app.toggle_system_in_list();
+        assert_eq!(app.filtered_indices().len(), 4);
+        assert_eq!(app.total_cluster_rate(), 6.0);
+
+        // And the other way round
+        app.toggle_system_in_list();
+        app.toggle_system_in_rate();
+        assert_eq!(app.filtered_indices().len(), 3);
+        assert_eq!(app.total_cluster_rate(), 16.0);
+
+        // The combined toggle lines both up again
+        app.toggle_system_indices();
+        assert!(app.show_system_in_list && app.count_system_in_rate);
+    }
+
+    #[test]
+    fn test_custom_system_patterns_replace_dot_prefix() {
+        let mut app = setup_mock_app();
+        app.indices.push(IndexRate {
+            name: ".kibana".to_string(),
+            doc_count: 50,
+            rate_per_sec: 10.0,
+            size_bytes: 512,
+            total_size_bytes: 512,
+            health: "green".to_string(),
+            ..Default::default()
+        });
+        app.system_patterns = vec!["index-*".to_string(), "*-3".to_string()];
+
+        // Only .kibana is left, since the dot prefix no longer applies
+        let names: Vec<&str> = app
+            .filtered_indices()
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect();
+        assert_eq!(names, [".kibana"]);
+        assert_eq!(app.total_cluster_rate(), 10.0);
+    }
+
+    #[test]
+    fn test_include_exclude_globs_compose_with_exclusions() {
+        let mut app = setup_mock_app();
+        app.include_patterns = vec!["index-*".to_string()];
+        app.exclude_patterns = vec!["*-3".to_string()];
+        app.excluded_indices.insert("index-1".to_string());
+
+        let names: Vec<&str> = app
+            .filtered_indices()
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect();
+        assert_eq!(names, ["index-2"]);
+        assert_eq!(app.total_cluster_rate(), 2.0);
+    }
 }
@@ -0,0 +1,121 @@
+use std::future::Future;
+
+use super::generational::GenerationalChannel;
+
+pub type PopupResult = Result<String, String>;
+
+/// A scrollable popup showing text fetched in the background, such as hot threads.
+pub struct TextPopupState {
+    pub show_popup: bool,
+    pub title: &'static str,
+    pub text: Option<String>,
+    pub loading: bool,
+    pub error: Option<String>,
+    pub scroll: usize,
+    results: GenerationalChannel<PopupResult>,
+}
+
+impl TextPopupState {
+    pub fn new() -> Self {
+        Self {
+            show_popup: false,
+            title: "",
+            text: None,
+            loading: false,
+            error: None,
+            scroll: 0,
+            results: GenerationalChannel::new(),
+        }
+    }
+
+    // Opens the popup and resolves `fetch` in the background
+    pub fn fetch<F>(&mut self, title: &'static str, fetch: F)
+    where
+        F: Future<Output = crate::error::Result<String>> + Send + 'static,
+    {
+        self.show_popup = true;
+        self.title = title;
+        self.loading = true;
+        self.error = None;
+        self.text = None;
+        self.scroll = 0;
+        self.results.invalidate();
+        self.results
+            .spawn(async move { fetch.await.map_err(|e| e.to_string()) });
+    }
+
+    // Opens the popup on text that is already at hand
+    pub fn show(&mut self, title: &'static str, text: String) {
+        self.show_popup = true;
+        self.title = title;
+        self.loading = false;
+        self.error = None;
+        self.text = Some(text);
+        self.scroll = 0;
+        self.results.invalidate();
+    }
+
+    pub fn close(&mut self) {
+        self.show_popup = false;
+        self.text = None;
+        self.error = None;
+        self.loading = false;
+        self.scroll = 0;
+        self.results.invalidate();
+    }
+
+    pub fn poll(&mut self) {
+        let Some(result) = self.results.poll() else {
+            return;
+        };
+        self.loading = false;
+        match result {
+            Ok(text) => {
+                self.text = Some(text);
+                self.error = None;
+            }
+            Err(e) => {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
+
+    pub fn scroll_page_up(&mut self, page_size: usize) {
+        self.scroll = self.scroll.saturating_sub(page_size);
+    }
+
+    pub fn scroll_page_down(&mut self, page_size: usize) {
+        self.scroll = self.scroll.saturating_add(page_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_ignores_closed_fetch() {
+        let mut popup = TextPopupState::new();
+        popup.results.invalidate();
+        popup.show_popup = true;
+        popup.loading = true;
+
+        // Closing bumps the generation, so the late result is dropped
+        popup.close();
+        popup.results.complete(1, Ok("late".to_string()));
+        popup.poll();
+        assert!(popup.text.is_none());
+
+        popup.results.complete(2, Ok("current".to_string()));
+        popup.poll();
+        assert_eq!(popup.text.as_deref(), Some("current"));
+    }
+}
@@ -0,0 +1,72 @@
+use tui_input::Input;
+
+use crate::models::Replicas;
+
+/// Prompt for a new `index.number_of_replicas` of one index (`E`, with `--allow-destructive`).
+pub struct ReplicaPrompt {
+    pub index: String,
+    pub input: Input,
+    /// `index.auto_expand_replicas` range, which overrides a fixed count while set
+    pub auto_expand: Option<String>,
+    /// Why the last submitted value was rejected
+    pub error: Option<String>,
+}
+
+impl ReplicaPrompt {
+    // Starts from the current count so small adjustments are a keystroke or two
+    pub fn new(index: String, current: Option<&Replicas>) -> Self {
+        let (value, auto_expand) = match current {
+            Some(Replicas::Count(count)) => (count.to_string(), None),
+            Some(Replicas::AutoExpand(range)) => (String::new(), Some(range.clone())),
+            None => (String::new(), None),
+        };
+        Self {
+            index,
+            input: Input::new(value),
+            auto_expand,
+            error: None,
+        }
+    }
+
+    pub fn value(&self) -> Result<u32, String> {
+        parse_replicas(self.input.value())
+    }
+}
+
+// A replica count is a non-negative whole number
+fn parse_replicas(text: &str) -> Result<u32, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Enter a replica count".to_string());
+    }
+    text.parse()
+        .map_err(|_| format!("\"{}\" is not a non-negative whole number", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_replicas() {
+        assert_eq!(parse_replicas(" 2 "), Ok(2));
+        assert_eq!(parse_replicas("0"), Ok(0));
+        assert!(parse_replicas("").is_err());
+        assert!(parse_replicas("-1").is_err());
+        assert!(parse_replicas("1.5").is_err());
+        assert!(parse_replicas("two").is_err());
+    }
+
+    #[test]
+    fn test_prompt_starts_from_current_count() {
+        let prompt = ReplicaPrompt::new("logs".to_string(), Some(&Replicas::Count(1)));
+        assert_eq!(prompt.value(), Ok(1));
+
+        let prompt = ReplicaPrompt::new(
+            "logs".to_string(),
+            Some(&Replicas::AutoExpand("0-all".to_string())),
+        );
+        assert_eq!(prompt.auto_expand.as_deref(), Some("0-all"));
+        assert!(prompt.value().is_err());
+    }
+}
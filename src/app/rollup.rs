@@ -0,0 +1,262 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use crate::app::sort::{pin_unhealthy, SortState};
+use crate::models::IndexRate;
+use crate::utils::health_severity;
+
+/// What a table row stands for once rollup is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowKind {
+    /// An index that doesn't share its prefix with any other
+    Index,
+    /// Synthetic sum over the indices sharing a prefix
+    Group { members: usize, expanded: bool },
+    /// An index listed under its expanded group
+    Member,
+    /// Divider above the excluded indices, summing them like a group
+    Excluded { members: usize, expanded: bool },
+    /// An excluded index, listed under the divider while it's unfolded
+    ExcludedMember,
+}
+
+pub struct TableRow<'a> {
+    pub index: Cow<'a, IndexRate>,
+    pub kind: RowKind,
+}
+
+impl<'a> TableRow<'a> {
+    pub fn index(index: &'a IndexRate) -> Self {
+        Self {
+            index: Cow::Borrowed(index),
+            kind: RowKind::Index,
+        }
+    }
+
+    // The real index behind the row; None for synthetic group rows
+    pub fn as_index(&self) -> Option<&'a IndexRate> {
+        match self.index {
+            Cow::Borrowed(index) => Some(index),
+            Cow::Owned(_) => None,
+        }
+    }
+}
+
+/// Divider and rows for the excluded section below the table; empty without excluded indices.
+pub fn excluded_section<'a>(excluded: &[&'a IndexRate], expanded: bool) -> Vec<TableRow<'a>> {
+    if excluded.is_empty() {
+        return Vec::new();
+    }
+    let mut rows = vec![TableRow {
+        index: Cow::Owned(aggregate("Excluded", excluded)),
+        kind: RowKind::Excluded {
+            members: excluded.len(),
+            expanded,
+        },
+    }];
+    if expanded {
+        rows.extend(excluded.iter().map(|&index| TableRow {
+            index: Cow::Borrowed(index),
+            kind: RowKind::ExcludedMember,
+        }));
+    }
+    rows
+}
+
+/// Group name for an index: everything up to the first separator that is followed by a
+/// digit, plus `*`. `logs-2024.01.01` and `logs-2024.01.02` both become `logs-*`.
+pub fn group_key(name: &str, separators: &str) -> Option<String> {
+    let bytes = name.as_bytes();
+    name.char_indices()
+        .filter(|&(i, c)| i > 0 && separators.contains(c))
+        .find(|&(i, c)| {
+            bytes
+                .get(i + c.len_utf8())
+                .is_some_and(|b| b.is_ascii_digit())
+        })
+        .map(|(i, c)| format!("{}*", &name[..i + c.len_utf8()]))
+}
+
+/// Collapses indices sharing a prefix into one summed row per group, sorted like plain rows.
+/// Members of expanded groups follow their group row, in their existing order. Groups with a
+/// pinned member go first.
+pub fn rollup<'a>(
+    indices: &[&'a IndexRate],
+    separators: &str,
+    expanded: &HashSet<String>,
+    sort: &SortState,
+    total_size: bool,
+    unhealthy_first: bool,
+    pinned: &HashSet<String>,
+) -> Vec<TableRow<'a>> {
+    let mut groups: HashMap<String, Vec<&'a IndexRate>> = HashMap::new();
+    for &index in indices {
+        let key = group_key(&index.name, separators).unwrap_or_else(|| index.name.clone());
+        groups.entry(key).or_default().push(index);
+    }
+
+    let mut aggregates: Vec<IndexRate> = groups
+        .iter()
+        .map(|(key, members)| aggregate(key, members))
+        .collect();
+    sort.sort(&mut aggregates, total_size);
+    if unhealthy_first {
+        pin_unhealthy(&mut aggregates);
+    }
+    // Groups holding a pinned index are pinned with it
+    aggregates.sort_by_key(|aggregate| {
+        !groups[&aggregate.name]
+            .iter()
+            .any(|index| pinned.contains(&index.name))
+    });
+
+    let mut rows = Vec::new();
+    for aggregate in aggregates {
+        let members = &groups[&aggregate.name];
+        if let [index] = members.as_slice() {
+            rows.push(TableRow::index(index));
+            continue;
+        }
+
+        let is_expanded = expanded.contains(&aggregate.name);
+        rows.push(TableRow {
+            index: Cow::Owned(aggregate),
+            kind: RowKind::Group {
+                members: members.len(),
+                expanded: is_expanded,
+            },
+        });
+        if is_expanded {
+            rows.extend(members.iter().map(|&index| TableRow {
+                index: Cow::Borrowed(index),
+                kind: RowKind::Member,
+            }));
+        }
+    }
+    rows
+}
+
+// Sums the counters of a group; health is the worst member's, creation the newest
+fn aggregate(name: &str, members: &[&IndexRate]) -> IndexRate {
+    IndexRate {
+        name: name.to_string(),
+        doc_count: members.iter().map(|i| i.doc_count).sum(),
+        rate_per_sec: members.iter().map(|i| i.rate_per_sec).sum(),
+        size_bytes: members.iter().map(|i| i.size_bytes).sum(),
+        total_size_bytes: members.iter().map(|i| i.total_size_bytes).sum(),
+        health: members
+            .iter()
+            .max_by_key(|i| health_severity(&i.health))
+            .map(|i| i.health.clone())
+            .unwrap_or_default(),
+        index_failed: members.iter().map(|i| i.index_failed).sum(),
+        failed_delta: members.iter().map(|i| i.failed_delta).sum(),
+        closed: members.iter().all(|i| i.closed),
+        unassigned_shards: members.iter().map(|i| i.unassigned_shards).sum(),
+        // Shown only when every member agrees
+        replicas: members
+            .iter()
+            .map(|i| i.replicas.clone())
+            .reduce(|a, b| if a == b { a } else { None })
+            .flatten(),
+        index_latency_ms: weighted_latency(members, |i| i.index_latency_ms, |i| i.rate_per_sec),
+        // No query rate is tracked, so every member with queries counts the same
+        query_latency_ms: weighted_latency(members, |i| i.query_latency_ms, |_| 1.0),
+        merges_current: members.iter().map(|i| i.merges_current).sum(),
+        merge_time_rate: members.iter().map(|i| i.merge_time_rate).sum(),
+        segment_count: members.iter().map(|i| i.segment_count).sum(),
+        segments_delta: members.iter().map(|i| i.segments_delta).sum(),
+        creation_date: members.iter().filter_map(|i| i.creation_date).max(),
+    }
+}
+
+// Mean latency of the members that have one, weighted so busy indices dominate
+fn weighted_latency(
+    members: &[&IndexRate],
+    latency: impl Fn(&IndexRate) -> Option<f64>,
+    weight: impl Fn(&IndexRate) -> f64,
+) -> Option<f64> {
+    let (weighted, total) = members
+        .iter()
+        .filter(|i| weight(i) > 0.0)
+        .filter_map(|i| Some((latency(i)? * weight(i), weight(i))))
+        .fold((0.0, 0.0), |(w, t), (lw, lt)| (w + lw, t + lt));
+    (total > 0.0).then(|| weighted / total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::types::{SortColumn, SortOrder};
+
+    #[test]
+    fn test_group_key() {
+        assert_eq!(
+            group_key("logs-2024.01.01", "-_.").as_deref(),
+            Some("logs-*")
+        );
+        assert_eq!(
+            group_key(".ds-metrics-2024.01.01-000001", "-_.").as_deref(),
+            Some(".ds-metrics-*")
+        );
+        assert_eq!(group_key("app-v2", "-_.").as_deref(), None);
+        assert_eq!(group_key("logs-2024", "_").as_deref(), None);
+    }
+
+    #[test]
+    fn test_rollup_sums_groups_and_expands() {
+        let indices = [
+            IndexRate {
+                doc_count: 10,
+                rate_per_sec: 1.0,
+                ..IndexRate::named("logs-2024.01.01")
+            },
+            IndexRate {
+                doc_count: 10,
+                rate_per_sec: 2.0,
+                health: "yellow".to_string(),
+                ..IndexRate::named("logs-2024.01.02")
+            },
+            IndexRate {
+                doc_count: 10,
+                rate_per_sec: 5.0,
+                ..IndexRate::named("users")
+            },
+        ];
+        let refs: Vec<&IndexRate> = indices.iter().collect();
+        let sort = SortState {
+            column: SortColumn::Rate,
+            order: SortOrder::Descending,
+        };
+
+        let none = HashSet::new();
+        let rows = rollup(&refs, "-_.", &none, &sort, false, false, &none);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].index.name, "users");
+        assert_eq!(rows[0].kind, RowKind::Index);
+        assert_eq!(rows[1].index.name, "logs-*");
+        assert_eq!(rows[1].index.rate_per_sec, 3.0);
+        assert_eq!(rows[1].index.doc_count, 20);
+        assert_eq!(rows[1].index.health, "yellow");
+        assert!(rows[1].as_index().is_none());
+
+        let expanded = HashSet::from(["logs-*".to_string()]);
+        let rows = rollup(&refs, "-_.", &expanded, &sort, false, false, &none);
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[1].kind,
+            RowKind::Group {
+                members: 2,
+                expanded: true
+            }
+        );
+        assert_eq!(rows[2].kind, RowKind::Member);
+        assert_eq!(rows[2].index.name, "logs-2024.01.01");
+
+        // A pinned member lifts its whole group above the faster index
+        let pinned = HashSet::from(["logs-2024.01.02".to_string()]);
+        let rows = rollup(&refs, "-_.", &none, &sort, false, false, &pinned);
+        assert_eq!(rows[0].index.name, "logs-*");
+        assert_eq!(rows[1].index.name, "users");
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::models::IndexRate;
+
+/// Per-index doc counts and sizes stamped at a point in time, used to measure
+/// cumulative change over an arbitrary window rather than the per-tick rate.
+pub struct Snapshot {
+    pub taken_at: Instant,
+    // Index name -> (doc count, size in bytes)
+    counts: HashMap<String, (u64, u64)>,
+}
+
+impl Snapshot {
+    pub fn capture(indices: &[IndexRate]) -> Self {
+        Self {
+            taken_at: Instant::now(),
+            counts: indices
+                .iter()
+                .map(|i| (i.name.clone(), (i.doc_count, i.size_bytes)))
+                .collect(),
+        }
+    }
+
+    // Change in (docs, bytes) since the snapshot, or None if the index didn't exist then
+    pub fn delta(&self, index: &IndexRate) -> Option<(i64, i64)> {
+        let &(docs, bytes) = self.counts.get(&index.name)?;
+        Some((
+            index.doc_count as i64 - docs as i64,
+            index.size_bytes as i64 - bytes as i64,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_delta() {
+        let snapshot = Snapshot::capture(&[IndexRate {
+            doc_count: 100,
+            size_bytes: 4096,
+            ..IndexRate::named("logs")
+        }]);
+
+        let grown = IndexRate {
+            doc_count: 150,
+            size_bytes: 1024,
+            ..IndexRate::named("logs")
+        };
+        assert_eq!(snapshot.delta(&grown), Some((50, -3072)));
+        assert_eq!(snapshot.delta(&IndexRate::named("new")), None);
+    }
+}
@@ -1,5 +1,9 @@
-use crate::models::IndexRate;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::models::{IndexRate, Replicas};
 use crate::ui::types::{SortColumn, SortOrder};
+use crate::utils::health_severity;
 
 #[derive(Default)]
 pub struct SortState {
@@ -20,17 +24,29 @@ impl SortState {
         self.order = self.order.toggle();
     }
 
-    pub fn sort(&self, indices: &mut [IndexRate]) {
+    // `total_size` picks the size the Size column sorts on: primaries only or with replicas
+    pub fn sort(&self, indices: &mut [IndexRate], total_size: bool) {
         indices.sort_by(|index_a, index_b| {
             let cmp = match self.column {
                 SortColumn::Name => index_a.name.cmp(&index_b.name),
                 SortColumn::DocCount => index_a.doc_count.cmp(&index_b.doc_count),
-                SortColumn::Rate => index_a
-                    .rate_per_sec
-                    .partial_cmp(&index_b.rate_per_sec)
-                    .unwrap_or(std::cmp::Ordering::Equal),
-                SortColumn::Size => index_a.size_bytes.cmp(&index_b.size_bytes),
-                SortColumn::Health => index_a.health.cmp(&index_b.health),
+                SortColumn::Rate => cmp_rate(index_a.rate_per_sec, index_b.rate_per_sec),
+                SortColumn::Size => index_a.size(total_size).cmp(&index_b.size(total_size)),
+                // By severity (green < yellow < red); closed and unknown sort below green
+                SortColumn::Health => {
+                    health_severity(&index_a.health).cmp(&health_severity(&index_b.health))
+                }
+                SortColumn::Unassigned => index_a.unassigned_shards.cmp(&index_b.unassigned_shards),
+                SortColumn::Replicas => replicas_key(index_a).cmp(&replicas_key(index_b)),
+                // Indices without ops in the window sort below every measured latency
+                SortColumn::IndexLatency => cmp_rate(
+                    index_a.index_latency_ms.unwrap_or(f64::NAN),
+                    index_b.index_latency_ms.unwrap_or(f64::NAN),
+                ),
+                SortColumn::QueryLatency => cmp_rate(
+                    index_a.query_latency_ms.unwrap_or(f64::NAN),
+                    index_b.query_latency_ms.unwrap_or(f64::NAN),
+                ),
             };
 
             match self.order {
@@ -41,6 +57,31 @@ impl SortState {
     }
 }
 
+// Stable partition moving unhealthy indices above healthy ones, keeping each group's order
+pub fn pin_unhealthy(indices: &mut [IndexRate]) {
+    indices.sort_by_key(|index| !index.is_unhealthy());
+}
+
+// Stable partition moving pinned indices above the rest, keeping each group's order
+pub fn pin_named(indices: &mut [IndexRate], pinned: &HashSet<String>) {
+    indices.sort_by_key(|index| !pinned.contains(&index.name));
+}
+
+// Unknown replica settings sort below every known one
+fn replicas_key(index: &IndexRate) -> Option<(u32, bool)> {
+    index.replicas.as_ref().map(Replicas::sort_key)
+}
+
+// Total order for rates: NaN sorts below every number so it can't destabilize the sort
+fn cmp_rate(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,11 +91,44 @@ mod tests {
             name: name.to_string(),
             doc_count: docs,
             rate_per_sec: rate,
-            size_bytes: 0,
             health: "green".to_string(),
+            ..Default::default()
         }
     }
 
+    #[test]
+    fn test_pin_unhealthy_keeps_sorted_order_within_groups() {
+        let mut indices = vec![
+            mock_index("a", 0, 0.0),
+            mock_index("b", 0, 0.0),
+            mock_index("c", 0, 0.0),
+            mock_index("d", 0, 0.0),
+        ];
+        indices[1].health = "red".to_string();
+        indices[3].health = "yellow".to_string();
+        indices[2].health = "red".to_string();
+        indices[2].closed = true;
+
+        pin_unhealthy(&mut indices);
+        let names: Vec<&str> = indices.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["b", "d", "a", "c"]);
+    }
+
+    #[test]
+    fn test_pin_named_keeps_sorted_order_within_groups() {
+        let mut indices = vec![
+            mock_index("a", 0, 0.0),
+            mock_index("b", 0, 0.0),
+            mock_index("c", 0, 0.0),
+            mock_index("d", 0, 0.0),
+        ];
+        let pinned = HashSet::from(["c".to_string(), "b".to_string(), "gone".to_string()]);
+
+        pin_named(&mut indices, &pinned);
+        let names: Vec<&str> = indices.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["b", "c", "a", "d"]);
+    }
+
     #[test]
     fn test_sort_by_name() {
         let mut indices = vec![mock_index("z", 0, 0.0), mock_index("a", 0, 0.0)];
@@ -62,7 +136,7 @@ mod tests {
             column: SortColumn::Name,
             order: SortOrder::Ascending,
         };
-        sort.sort(&mut indices);
+        sort.sort(&mut indices, false);
         assert_eq!(indices[0].name, "a");
         assert_eq!(indices[1].name, "z");
     }
@@ -74,8 +148,67 @@ mod tests {
             column: SortColumn::Rate,
             order: SortOrder::Descending,
         };
-        sort.sort(&mut indices);
+        sort.sort(&mut indices, false);
         assert_eq!(indices[0].name, "b");
         assert_eq!(indices[1].name, "a");
     }
+
+    #[test]
+    fn test_sort_by_health_severity_descending() {
+        let mut indices = vec![
+            mock_index("green", 0, 0.0),
+            mock_index("red", 0, 0.0),
+            mock_index("closed", 0, 0.0),
+            mock_index("yellow", 0, 0.0),
+        ];
+        for index in indices.iter_mut() {
+            index.health = index.name.clone();
+        }
+        let sort = SortState {
+            column: SortColumn::Health,
+            order: SortOrder::Descending,
+        };
+        sort.sort(&mut indices, false);
+        let names: Vec<&str> = indices.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["red", "yellow", "green", "closed"]);
+    }
+
+    #[test]
+    fn test_sort_by_latency_unmeasured_lowest() {
+        let mut indices = vec![
+            mock_index("idle", 0, 0.0),
+            mock_index("fast", 0, 0.0),
+            mock_index("slow", 0, 0.0),
+        ];
+        indices[1].index_latency_ms = Some(0.5);
+        indices[2].index_latency_ms = Some(12.0);
+        let sort = SortState {
+            column: SortColumn::IndexLatency,
+            order: SortOrder::Descending,
+        };
+        sort.sort(&mut indices, false);
+        let names: Vec<_> = indices.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["slow", "fast", "idle"]);
+    }
+
+    #[test]
+    fn test_sort_by_rate_with_nan() {
+        let mut indices = vec![
+            mock_index("a", 10, 1.0),
+            mock_index("nan", 10, f64::NAN),
+            mock_index("b", 10, 5.0),
+        ];
+        let mut sort = SortState {
+            column: SortColumn::Rate,
+            order: SortOrder::Descending,
+        };
+        sort.sort(&mut indices, false);
+        let names: Vec<_> = indices.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["b", "a", "nan"]);
+
+        sort.order = SortOrder::Ascending;
+        sort.sort(&mut indices, false);
+        let names: Vec<_> = indices.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["nan", "a", "b"]);
+    }
 }
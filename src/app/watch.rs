@@ -0,0 +1,38 @@
+use std::collections::VecDeque;
+
+use crate::app::details::DetailsState;
+use crate::ui::types::ChartMetric;
+
+/// Focused monitoring of a single index: its own history series plus details
+/// (shards, ILM) refreshed on the normal interval.
+pub struct WatchState {
+    pub index: String,
+    pub rate_history: VecDeque<u64>,
+    pub bytes_rate_history: VecDeque<u64>,
+    pub doc_count_history: VecDeque<u64>,
+    pub unassigned_history: VecDeque<u64>,
+    pub details: DetailsState,
+}
+
+impl WatchState {
+    pub fn new(index: String) -> Self {
+        Self {
+            index,
+            rate_history: VecDeque::new(),
+            bytes_rate_history: VecDeque::new(),
+            doc_count_history: VecDeque::new(),
+            unassigned_history: VecDeque::new(),
+            details: DetailsState::new(),
+        }
+    }
+
+    pub fn history_vec(&self, metric: ChartMetric) -> Vec<u64> {
+        let history = match metric {
+            ChartMetric::IndexingRate => &self.rate_history,
+            ChartMetric::BytesRate => &self.bytes_rate_history,
+            ChartMetric::DocCount => &self.doc_count_history,
+            ChartMetric::UnassignedShards => &self.unassigned_history,
+        };
+        history.iter().copied().collect()
+    }
+}
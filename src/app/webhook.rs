@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::sync::{mpsc, Mutex};
+use url::Url;
+
+use crate::elasticsearch::EsClient;
+
+/// JSON body posted to `--webhook-url` when cluster health worsens.
+#[derive(Debug, Serialize)]
+pub struct HealthChangePayload {
+    pub cluster_name: String,
+    pub old_status: String,
+    pub new_status: String,
+    pub unassigned_shards: u32,
+}
+
+/// Posts the payload in a background task, reporting failures on `error_tx`.
+pub fn notify(
+    es_client: Arc<Mutex<EsClient>>,
+    url: Url,
+    payload: HealthChangePayload,
+    error_tx: mpsc::Sender<String>,
+) {
+    tokio::spawn(async move {
+        // Reuse the connection pool, but not the Elasticsearch credentials
+        let client = es_client.lock().await.client.clone();
+        let result = client
+            .post(url)
+            .json(&payload)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+
+        if let Err(e) = result {
+            let _ = error_tx.send(format!("Webhook failed: {}", e)).await;
+        }
+    });
+}
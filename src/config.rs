@@ -0,0 +1,259 @@
+use regex::Regex;
+use serde::{de, Deserialize, Deserializer};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{EstiCliError, Result};
+use crate::ui::types::{Colormap, SelectionStyle, SortColumn};
+
+/// Settings read from the TOML config file.
+///
+/// Looked up at `--config <FILE>`, falling back to `$XDG_CONFIG_HOME/esticli/config.toml`
+/// (or `~/.config/esticli/config.toml`). A missing default file is not an error.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Gradient colormap per sortable column, overriding `--colormap`
+    pub colormaps: ColumnColormaps,
+    /// Highlight of the selected row: reversed, underline, bar or a background color
+    #[serde(deserialize_with = "from_str_opt")]
+    pub selection_style: Option<SelectionStyle>,
+    /// Alternate a subtle background on every other table row
+    pub zebra: bool,
+    /// Regex replacements shortening index names in the table, applied in order
+    pub name_transforms: Vec<NameTransform>,
+    /// Starting relative widths of the table columns, as `H`/`L` would set them
+    pub column_widths: ColumnWidths,
+}
+
+/// Display-only rewrite of index names, e.g. `.ds-logs-2024.01.01-000042` to `logs #42`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NameTransform {
+    #[serde(deserialize_with = "regex_from_str")]
+    pub pattern: Regex,
+    /// Replacement text; `$1` or `${name}` refer to capture groups
+    pub replace: String,
+}
+
+impl NameTransform {
+    pub fn apply<'a>(&self, name: &'a str) -> std::borrow::Cow<'a, str> {
+        self.pattern.replace(name, self.replace.as_str())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnColormaps {
+    #[serde(deserialize_with = "from_str_opt")]
+    pub doc_count: Option<Colormap>,
+    #[serde(deserialize_with = "from_str_opt")]
+    pub rate: Option<Colormap>,
+    #[serde(deserialize_with = "from_str_opt")]
+    pub size: Option<Colormap>,
+    /// Color stops (e.g. `"#1a1a2e"`) for the `custom` colormap
+    pub custom: Option<Vec<String>>,
+}
+
+impl ColumnColormaps {
+    // The configured (column, colormap) pairs
+    pub fn entries(&self) -> Vec<(SortColumn, Colormap)> {
+        [
+            (SortColumn::DocCount, self.doc_count),
+            (SortColumn::Rate, self.rate),
+            (SortColumn::Size, self.size),
+        ]
+        .into_iter()
+        .filter_map(|(column, colormap)| colormap.map(|c| (column, c)))
+        .collect()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnWidths {
+    pub name: Option<u16>,
+    pub doc_count: Option<u16>,
+    pub rate: Option<u16>,
+    pub size: Option<u16>,
+    pub health: Option<u16>,
+    pub unassigned: Option<u16>,
+    pub replicas: Option<u16>,
+    pub index_latency: Option<u16>,
+    pub query_latency: Option<u16>,
+}
+
+impl ColumnWidths {
+    // The configured (column, width) pairs
+    pub fn entries(&self) -> Vec<(SortColumn, u16)> {
+        [
+            (SortColumn::Name, self.name),
+            (SortColumn::DocCount, self.doc_count),
+            (SortColumn::Rate, self.rate),
+            (SortColumn::Size, self.size),
+            (SortColumn::Health, self.health),
+            (SortColumn::Unassigned, self.unassigned),
+            (SortColumn::Replicas, self.replicas),
+            (SortColumn::IndexLatency, self.index_latency),
+            (SortColumn::QueryLatency, self.query_latency),
+        ]
+        .into_iter()
+        .filter_map(|(column, width)| width.map(|w| (column, w)))
+        .collect()
+    }
+}
+
+impl ConfigFile {
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match default_path().filter(|p| p.exists()) {
+                Some(path) => path,
+                None => return Ok(Self::default()),
+            },
+        };
+
+        let text = std::fs::read_to_string(&path)
+            .map_err(|e| EstiCliError::Config(format!("{}: {}", path.display(), e)))?;
+        Self::parse(&text).map_err(|e| EstiCliError::Config(format!("{}: {}", path.display(), e)))
+    }
+
+    fn parse(text: &str) -> std::result::Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+}
+
+fn default_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("esticli").join("config.toml"))
+}
+
+// Parse optional values with their `FromStr` impl, so the file accepts the CLI spellings
+fn from_str_opt<'de, D, T>(deserializer: D) -> std::result::Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = String>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse().map_err(de::Error::custom))
+        .transpose()
+}
+
+fn regex_from_str<'de, D>(deserializer: D) -> std::result::Result<Regex, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Regex::new(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_column_colormaps() {
+        let config = ConfigFile::parse(
+            r#"
+            [colormaps]
+            rate = "inferno"
+            size = "Cool"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.colormaps.entries(),
+            [
+                (SortColumn::Rate, Colormap::Inferno),
+                (SortColumn::Size, Colormap::Cool)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_custom_stops() {
+        let config = ConfigFile::parse(
+            r##"
+            [colormaps]
+            rate = "custom"
+            custom = ["#1a1a2e", "#e94560"]
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.colormaps.entries(),
+            [(SortColumn::Rate, Colormap::Custom)]
+        );
+        assert_eq!(config.colormaps.custom.unwrap(), ["#1a1a2e", "#e94560"]);
+    }
+
+    #[test]
+    fn test_parse_selection_style() {
+        let parse = |value: &str| {
+            ConfigFile::parse(&format!("selection_style = \"{}\"", value))
+                .map(|config| config.selection_style)
+        };
+
+        assert_eq!(parse("bar").unwrap(), Some(SelectionStyle::Bar));
+        assert_eq!(
+            parse("#303040").unwrap(),
+            Some(SelectionStyle::Background(ratatui::style::Color::Rgb(
+                0x30, 0x30, 0x40
+            )))
+        );
+        assert!(parse("blink").is_err());
+        assert_eq!(ConfigFile::parse("").unwrap().selection_style, None);
+    }
+
+    #[test]
+    fn test_parse_column_widths() {
+        let config = ConfigFile::parse(
+            r#"
+            [column_widths]
+            name = 70
+            index_latency = 15
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.column_widths.entries(),
+            [(SortColumn::Name, 70), (SortColumn::IndexLatency, 15)]
+        );
+        assert!(ConfigFile::parse("[column_widths]\nstatus = 10").is_err());
+    }
+
+    #[test]
+    fn test_parse_zebra() {
+        assert!(ConfigFile::parse("zebra = true").unwrap().zebra);
+        assert!(!ConfigFile::parse("").unwrap().zebra);
+        assert!(ConfigFile::parse("zebra = \"yes\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_name_transforms() {
+        let config = ConfigFile::parse(
+            r#"
+            [[name_transforms]]
+            pattern = '^\.ds-(.+)-\d{4}\.\d{2}\.\d{2}-0*(\d+)$'
+            replace = '$1 #$2'
+            "#,
+        )
+        .unwrap();
+
+        let transform = &config.name_transforms[0];
+        assert_eq!(transform.apply(".ds-logs-2024.01.01-000042"), "logs #42");
+        assert_eq!(transform.apply("metrics"), "metrics");
+        assert!(ConfigFile::parse("[[name_transforms]]\npattern = '('\nreplace = ''").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_colormap() {
+        let error = ConfigFile::parse("[colormaps]\nrate = \"sepia\"").unwrap_err();
+        assert!(error.to_string().contains("Unknown colormap 'sepia'"));
+        assert!(ConfigFile::parse("[colormaps]\nhealth = \"cool\"").is_err());
+    }
+}
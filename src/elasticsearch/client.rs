@@ -1,5 +1,16 @@
+use super::types::RootInfo;
 use crate::error::{EstiCliError, Result};
-use crate::models::{ClusterHealth, IndexDetails, IndexRate, IndexSnapshot};
+use crate::models::{
+    ClusterHealth, IndexDetails, IndexRate, IndexSnapshot, NodeHeap, ShardDistribution, ShardInfo,
+    StatGroup, DEFAULT_STAT_GROUPS,
+};
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use std::io::BufReader;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio_util::io::{StreamReader, SyncIoBridge};
 use url::Url;
 
 #[derive(Clone)]
@@ -13,10 +24,27 @@ pub struct EsClient {
     pub(crate) client: reqwest::Client,
     pub(crate) base_url: Url,
     pub(crate) auth: AuthConfig,
-    pub(crate) previous_snapshot: Option<(
-        std::time::Instant,
-        std::collections::HashMap<String, IndexSnapshot>,
-    )>,
+    // Error bodies longer than this many bytes are cut short
+    max_error_body: usize,
+    // `--index` target: stats requests only cover matching indices when set
+    pub(crate) index_pattern: Option<String>,
+    // `--stats` groups requested by the main refresh
+    pub(crate) stat_groups: Vec<StatGroup>,
+    // Most index details sub-requests in flight at once; all of them when unset
+    pub(crate) details_concurrency: Option<NonZeroUsize>,
+    // `--dump-responses` target: raw JSON bodies are written here before parsing
+    dump_dir: Option<PathBuf>,
+    // Keeps dump file names unique when responses arrive within the same millisecond
+    dump_seq: AtomicU64,
+    // Root endpoint answer, fetched on first use and kept for the session
+    pub(crate) cluster_info: Option<RootInfo>,
+    // Behind its own lock so the rates request can run alongside the others
+    pub(crate) previous_snapshot: std::sync::Mutex<
+        Option<(
+            std::time::Instant,
+            std::collections::HashMap<String, IndexSnapshot>,
+        )>,
+    >,
 }
 
 impl EsClient {
@@ -25,11 +53,15 @@ impl EsClient {
         auth: AuthConfig,
         insecure: bool,
         ca_cert: Option<std::path::PathBuf>,
+        connect_timeout: std::time::Duration,
+        timeout: std::time::Duration,
+        max_error_body: usize,
     ) -> Result<Self> {
         let mut builder = reqwest::Client::builder()
             .danger_accept_invalid_certs(insecure)
             .gzip(true)
-            .timeout(std::time::Duration::from_secs(30));
+            .connect_timeout(connect_timeout)
+            .timeout(timeout);
 
         if let Some(ca_path) = ca_cert {
             let ca_data = std::fs::read(&ca_path).map_err(|e| {
@@ -48,10 +80,43 @@ impl EsClient {
             client,
             base_url: url,
             auth,
-            previous_snapshot: None,
+            max_error_body,
+            index_pattern: None,
+            stat_groups: DEFAULT_STAT_GROUPS.to_vec(),
+            details_concurrency: None,
+            dump_dir: None,
+            dump_seq: AtomicU64::new(0),
+            cluster_info: None,
+            previous_snapshot: std::sync::Mutex::new(None),
         })
     }
 
+    // Scope index stats to a pattern (e.g. `logs-*`) instead of the whole cluster
+    pub fn with_index_pattern(mut self, pattern: Option<String>) -> Self {
+        self.index_pattern = pattern;
+        self
+    }
+
+    // Only request these `_stats` groups on each refresh
+    pub fn with_stat_groups(mut self, groups: Vec<StatGroup>) -> Self {
+        self.stat_groups = groups;
+        self
+    }
+
+    // Bound how many index details requests hit the cluster at once
+    pub fn with_details_concurrency(mut self, limit: Option<NonZeroUsize>) -> Self {
+        self.details_concurrency = limit;
+        self
+    }
+
+    // Write every raw JSON response body to `dir`, for attaching to bug reports. Only
+    // response bodies are written, never request headers, so credentials sent in the
+    // `Authorization` header don't end up on disk.
+    pub fn with_dump_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.dump_dir = dir;
+        self
+    }
+
     pub(crate) fn auth_request(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
         match &self.auth {
             AuthConfig::None => request,
@@ -62,24 +127,72 @@ impl EsClient {
         }
     }
 
-    // Helper to send a request and parse the response as JSON with error handling
-    pub(crate) async fn send_json<T>(&self, request: reqwest::RequestBuilder) -> Result<T>
-    where
-        T: serde::de::DeserializeOwned,
-    {
+    // Helper to send a request, turning non-2xx responses into API errors
+    async fn send_checked(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
         let response = self.auth_request(request).send().await?;
 
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
+            let body = truncate_error_body(body, self.max_error_body);
             return Err(EstiCliError::Api { status, body });
         }
 
+        Ok(response)
+    }
+
+    // Helper to send a request and parse the response as JSON with error handling
+    pub(crate) async fn send_json<T>(&self, request: reqwest::RequestBuilder) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self.send_checked(request).await?;
+        let url = response.url().clone();
         let body = response.bytes().await?;
+        self.dump_response(&url, &body).await;
         serde_json::from_slice(&body).map_err(EstiCliError::from)
     }
 
-    pub async fn fetch_index_rates(&mut self) -> Result<Vec<IndexRate>> {
+    // Best effort: a failed dump must not fail the request it belongs to
+    async fn dump_response(&self, url: &Url, body: &[u8]) {
+        let Some(ref dir) = self.dump_dir else {
+            return;
+        };
+        let seq = self.dump_seq.fetch_add(1, Ordering::Relaxed);
+        let _ = tokio::fs::write(dir.join(dump_file_name(Utc::now(), seq, url)), body).await;
+    }
+
+    // Sibling of `send_json` for endpoints that answer in plain text (e.g. `_nodes/hot_threads`)
+    pub(crate) async fn send_text(&self, request: reqwest::RequestBuilder) -> Result<String> {
+        Ok(self.send_checked(request).await?.text().await?)
+    }
+
+    // Like `send_json`, but deserializes while the body streams in instead of buffering it.
+    // Meant for cluster-wide responses (e.g. `_stats`) that can reach tens of MB; parsing
+    // runs on a blocking thread so it doesn't stall the runtime.
+    pub(crate) async fn send_json_streaming<T>(&self, request: reqwest::RequestBuilder) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+    {
+        // Dumping needs the whole body anyway
+        if self.dump_dir.is_some() {
+            return self.send_json(request).await;
+        }
+
+        let stream = self
+            .send_checked(request)
+            .await?
+            .bytes_stream()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e));
+        let reader = SyncIoBridge::new(StreamReader::new(stream));
+
+        tokio::task::spawn_blocking(move || serde_json::from_reader(BufReader::new(reader)))
+            .await
+            .map_err(|e| EstiCliError::Internal(format!("Response parsing task failed: {}", e)))?
+            .map_err(EstiCliError::from)
+    }
+
+    pub async fn fetch_index_rates(&self) -> Result<Vec<IndexRate>> {
         super::stats::fetch_index_rates(self).await
     }
 
@@ -89,12 +202,120 @@ impl EsClient {
         doc_count: u64,
         rate_per_sec: f64,
         size_bytes: u64,
+        include_shards: bool,
     ) -> Result<IndexDetails> {
-        super::details::fetch_index_details(self, index_name, doc_count, rate_per_sec, size_bytes)
-            .await
+        super::details::fetch_index_details(
+            self,
+            index_name,
+            doc_count,
+            rate_per_sec,
+            size_bytes,
+            include_shards,
+        )
+        .await
     }
 
-    pub async fn fetch_cluster_health(&mut self) -> Result<ClusterHealth> {
+    pub async fn fetch_shard_allocation(
+        &self,
+        index_name: &str,
+    ) -> Result<(Vec<ShardInfo>, serde_json::Value)> {
+        super::details::fetch_shard_allocation(self, index_name).await
+    }
+
+    pub async fn fetch_cluster_health(&self) -> Result<ClusterHealth> {
         super::stats::fetch_cluster_health(self).await
     }
+
+    pub async fn fetch_shard_distribution(&self) -> Result<ShardDistribution> {
+        super::stats::fetch_shard_distribution(self).await
+    }
+
+    pub async fn fetch_node_heap(&self) -> Result<Vec<NodeHeap>> {
+        super::stats::fetch_node_heap(self).await
+    }
+
+    pub async fn fetch_hot_threads(&self) -> Result<String> {
+        super::diagnostics::fetch_hot_threads(self).await
+    }
+
+    pub async fn fetch_cluster_info(&mut self) -> Result<String> {
+        super::diagnostics::fetch_cluster_info(self).await
+    }
+
+    pub async fn fetch_allocation_explain(&self, index_name: Option<&str>) -> Result<String> {
+        super::diagnostics::fetch_allocation_explain(self, index_name).await
+    }
+
+    pub async fn update_replicas(&self, index_name: &str, replicas: u32) -> Result<bool> {
+        super::write::update_replicas(self, index_name, replicas).await
+    }
+
+    pub async fn rollover(&self, data_stream: &str) -> Result<String> {
+        super::write::rollover(self, data_stream).await
+    }
+}
+
+// `20240131T120000.123Z-0007-_cat_shards_logs.json`: sortable by time, then by path
+fn dump_file_name(at: DateTime<Utc>, seq: u64, url: &Url) -> String {
+    let path: String = url
+        .path()
+        .trim_start_matches('/')
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!(
+        "{}-{:04}-{}.json",
+        at.format("%Y%m%dT%H%M%S%.3fZ"),
+        seq,
+        path
+    )
+}
+
+// Cut `body` to at most `limit` bytes (on a char boundary), marking that it was cut
+fn truncate_error_body(mut body: String, limit: usize) -> String {
+    if body.len() <= limit {
+        return body;
+    }
+    let mut end = limit;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    body.truncate(end);
+    body.push_str("… (truncated)");
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_error_body() {
+        let large = "x".repeat(1024 * 1024);
+        let truncated = truncate_error_body(large, 4096);
+        assert_eq!(truncated.len(), 4096 + "… (truncated)".len());
+        assert!(truncated.ends_with("… (truncated)"));
+
+        // Short bodies are untouched, multi-byte chars are never split
+        assert_eq!(truncate_error_body("short".to_string(), 4096), "short");
+        assert_eq!(truncate_error_body("ééé".to_string(), 3), "é… (truncated)");
+    }
+
+    #[test]
+    fn test_dump_file_name() {
+        use chrono::TimeZone;
+
+        let at = Utc.with_ymd_and_hms(2024, 1, 31, 12, 0, 0).unwrap();
+        let url = Url::parse("http://localhost:9200/_cat/shards/logs-*?format=json").unwrap();
+        assert_eq!(
+            dump_file_name(at, 7, &url),
+            "20240131T120000.000Z-0007-_cat_shards_logs-_.json"
+        );
+    }
 }
@@ -1,10 +1,28 @@
 use super::client::EsClient;
 use super::types::{
-    CatIndexEntry, CatShardEntry, DataStreamsResponse, IlmExplainResponse, IndexSettingsResponse,
-    IndexTemplateResponse, SegmentsStatsResponse,
+    CacheCounters, CacheStatsResponse, CatIndexEntry, CatShardEntry, DataStreamsResponse,
+    IlmExplainResponse, IlmPolicyResponse, IndexSettingsResponse, IndexTemplateResponse,
+    SegmentsStatsResponse, TranslogStatsResponse,
 };
 use crate::error::Result;
-use crate::models::{DataStreamDetails, IndexDetails, ShardInfo};
+use crate::models::{
+    CacheStats, DataStreamDetails, IndexDetails, Replicas, RolloverConditions, ShardInfo,
+};
+use crate::utils::glob_matches;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use tokio::sync::Semaphore;
+
+// Waits for a permit before sending, so at most as many requests as permits are in flight
+async fn limited<T>(permits: &Semaphore, request: impl Future<Output = T>) -> T {
+    // The semaphore is never closed, so acquiring only waits
+    let _permit = permits.acquire().await;
+    request.await
+}
 
 pub async fn fetch_index_details(
     client: &EsClient,
@@ -12,6 +30,7 @@ pub async fn fetch_index_details(
     doc_count: u64,
     rate_per_sec: f64,
     size_bytes: u64,
+    include_shards: bool,
 ) -> Result<IndexDetails> {
     // Prepare all requests
     let settings_req = client
@@ -27,27 +46,77 @@ pub async fn fetch_index_details(
             .base_url
             .join(&format!("{}/_stats/segments", index_name))?,
     );
-    let shards_req = client.client.get(client.base_url.join(&format!(
-        "_cat/shards/{}?format=json&h=index,shard,prirep,state,docs,store,node",
-        index_name
-    ))?);
+    // `_cat/shards` is slow on indices with many shards, so it can be left for later
+    let shards_req = if include_shards {
+        Some(shards_request(client, index_name)?)
+    } else {
+        None
+    };
     let templates_req = client.client.get(client.base_url.join("_index_template")?);
     let cat_req = client.client.get(client.base_url.join(&format!(
         "_cat/indices/{}?format=json&h=health,status,index",
         index_name
     ))?);
     let ds_req = client.client.get(client.base_url.join("_data_stream")?);
+    let translog_req = client.client.get(
+        client
+            .base_url
+            .join(&format!("{}/_stats/translog", index_name))?,
+    );
+    let caches_req = client.client.get(
+        client
+            .base_url
+            .join(&format!("{}/_stats/query_cache,request_cache", index_name))?,
+    );
 
-    // Execute requests in parallel
-    let (settings_res, ilm_res, segments_res, shards_res, templates_res, cat_res, ds_res) = tokio::join!(
-        client.send_json::<IndexSettingsResponse>(settings_req),
-        client.send_json::<IlmExplainResponse>(ilm_req),
-        client.send_json::<SegmentsStatsResponse>(segments_req),
-        client.send_json::<Vec<CatShardEntry>>(shards_req),
-        client.send_json::<IndexTemplateResponse>(templates_req),
-        client.send_json::<Vec<CatIndexEntry>>(cat_req),
-        client.send_json::<DataStreamsResponse>(ds_req),
+    // Execute requests in parallel, up to the configured limit, keeping the raw bodies for export
+    let permits = Semaphore::new(
+        client
+            .details_concurrency
+            .map_or(Semaphore::MAX_PERMITS, NonZeroUsize::get),
     );
+    let (
+        settings_raw,
+        ilm_raw,
+        segments_raw,
+        shards_raw,
+        templates_raw,
+        cat_raw,
+        ds_raw,
+        translog_raw,
+        caches_raw,
+    ) = tokio::join!(
+        limited(&permits, client.send_json::<Value>(settings_req)),
+        limited(&permits, client.send_json::<Value>(ilm_req)),
+        limited(&permits, client.send_json_streaming::<Value>(segments_req)),
+        async {
+            match shards_req {
+                Some(req) => Some(limited(&permits, client.send_json::<Value>(req)).await),
+                None => None,
+            }
+        },
+        limited(&permits, client.send_json::<Value>(templates_req)),
+        limited(&permits, client.send_json::<Value>(cat_req)),
+        limited(&permits, client.send_json::<Value>(ds_req)),
+        limited(&permits, client.send_json::<Value>(translog_req)),
+        limited(&permits, client.send_json::<Value>(caches_req)),
+    );
+    let mut raw_responses = BTreeMap::new();
+    let settings_res =
+        record::<IndexSettingsResponse>(&mut raw_responses, "settings", settings_raw);
+    let ilm_res = record::<IlmExplainResponse>(&mut raw_responses, "ilm_explain", ilm_raw);
+    let segments_res =
+        record::<SegmentsStatsResponse>(&mut raw_responses, "segments", segments_raw);
+    let shards_res = shards_raw.map(|raw| {
+        record::<Vec<CatShardEntry>>(&mut raw_responses, "cat_shards", raw).unwrap_or_default()
+    });
+    let templates_res =
+        record::<IndexTemplateResponse>(&mut raw_responses, "index_templates", templates_raw);
+    let cat_res = record::<Vec<CatIndexEntry>>(&mut raw_responses, "cat_indices", cat_raw);
+    let ds_res = record::<DataStreamsResponse>(&mut raw_responses, "data_streams", ds_raw);
+    let translog_res =
+        record::<TranslogStatsResponse>(&mut raw_responses, "translog", translog_raw);
+    let caches_res = record::<CacheStatsResponse>(&mut raw_responses, "caches", caches_raw);
 
     // Process settings (required for most other things)
     let settings = settings_res.unwrap_or_default();
@@ -55,7 +124,6 @@ pub async fn fetch_index_details(
 
     // Process ILM
     let (ilm_policy, ilm_phase) = ilm_res
-        .ok()
         .and_then(|ilm| {
             ilm.indices
                 .get(index_name)
@@ -72,7 +140,6 @@ pub async fn fetch_index_details(
 
     // Process segments
     let total_segments = segments_res
-        .ok()
         .and_then(|s| {
             s.indices
                 .get(index_name)
@@ -80,19 +147,34 @@ pub async fn fetch_index_details(
         })
         .unwrap_or(0);
 
-    // Process shards
-    let shard_allocation = shards_res
-        .unwrap_or_default()
-        .into_iter()
-        .map(|s| ShardInfo {
-            shard_id: s.shard.parse().unwrap_or(0),
-            primary: s.prirep == "p",
-            state: s.state,
-            node: s.node.unwrap_or_else(|| "unassigned".to_string()),
-            docs: s.docs.and_then(|d| d.parse().ok()),
-            size: s.store,
+    // Process translog
+    let (translog_size_bytes, translog_ops) = translog_res
+        .and_then(|t| {
+            t.indices.get(index_name).map(|stats| {
+                let translog = &stats.primaries.translog;
+                (translog.size_in_bytes, translog.operations)
+            })
+        })
+        .unwrap_or((0, 0));
+
+    // Process caches
+    let (query_cache, request_cache) = caches_res
+        .and_then(|c| c.indices.get(index_name).map(|stats| stats.total.clone()))
+        .map(|totals| {
+            let to_stats = |c: CacheCounters| CacheStats {
+                memory_bytes: c.memory_size_in_bytes,
+                hits: c.hit_count,
+                misses: c.miss_count,
+            };
+            (
+                Some(to_stats(totals.query_cache)),
+                Some(to_stats(totals.request_cache)),
+            )
         })
-        .collect();
+        .unwrap_or((None, None));
+
+    // Process shards
+    let shard_allocation = shards_res.map(shard_infos);
 
     // Process templates
     let templates = templates_res
@@ -104,7 +186,7 @@ pub async fn fetch_index_details(
                     t.index_template
                         .index_patterns
                         .iter()
-                        .any(|pattern| pattern_matches(pattern, index_name))
+                        .any(|pattern| glob_matches(pattern, index_name))
                 })
                 .map(|t| t.name)
                 .collect()
@@ -113,7 +195,6 @@ pub async fn fetch_index_details(
 
     // Process health/status
     let (health, status) = cat_res
-        .ok()
         .and_then(|entries| {
             entries
                 .first()
@@ -122,7 +203,7 @@ pub async fn fetch_index_details(
         .unwrap_or((None, None));
 
     // Process data stream
-    let data_stream = ds_res.ok().and_then(|ds_response| {
+    let mut data_stream = ds_res.and_then(|ds_response| {
         ds_response.data_streams.iter().find_map(|ds| {
             ds.indices
                 .iter()
@@ -141,29 +222,36 @@ pub async fn fetch_index_details(
                             .lifecycle
                             .as_ref()
                             .and_then(|l| l.data_retention.clone()),
+                        rollover: None,
                     }
                 })
         })
     });
 
+    // Rollover conditions only matter for the write index
+    if let (Some(ds), Some(policy)) = (data_stream.as_mut(), ilm_policy.as_deref()) {
+        if ds.is_write_index {
+            ds.rollover = fetch_rollover_conditions(client, policy, &mut raw_responses).await;
+        }
+    }
+
     // Parse specific settings fields
     let creation_date = index_settings
         .and_then(|s| s.settings.index.creation_date.as_ref())
         .and_then(|ts| ts.parse::<i64>().ok())
-        .and_then(|ts| {
-            chrono::DateTime::from_timestamp_millis(ts)
-                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-        });
+        .and_then(chrono::DateTime::from_timestamp_millis);
 
     let primary_shards = index_settings
         .and_then(|s| s.settings.index.number_of_shards.as_ref())
         .and_then(|n| n.parse().ok())
         .unwrap_or(0);
 
-    let replica_shards = index_settings
-        .and_then(|s| s.settings.index.number_of_replicas.as_ref())
-        .and_then(|n| n.parse().ok())
-        .unwrap_or(0);
+    let replicas = index_settings.and_then(|s| {
+        Replicas::from_settings(
+            s.settings.index.number_of_replicas.as_deref(),
+            s.settings.index.auto_expand_replicas.as_deref(),
+        )
+    });
 
     let is_frozen = index_settings
         .and_then(|s| s.settings.index.frozen.as_ref())
@@ -184,12 +272,14 @@ pub async fn fetch_index_details(
         provided_name,
         creation_date,
         primary_shards,
-        replica_shards,
+        replicas,
         is_frozen,
         is_partial,
         ilm_policy,
         ilm_phase,
         total_segments,
+        translog_size_bytes,
+        translog_ops,
         shard_allocation,
         templates,
         uuid,
@@ -199,22 +289,109 @@ pub async fn fetch_index_details(
         rate_per_sec,
         size_bytes,
         data_stream,
+        query_cache,
+        request_cache,
+        raw_responses,
     })
 }
 
-// Simple glob pattern matching for index templates
-fn pattern_matches(pattern: &str, index_name: &str) -> bool {
-    if pattern == "*" {
-        return true;
-    }
+fn shards_request(client: &EsClient, index_name: &str) -> Result<reqwest::RequestBuilder> {
+    Ok(client.client.get(client.base_url.join(&format!(
+        "_cat/shards/{}?format=json&h=index,shard,prirep,state,docs,store,node",
+        index_name
+    ))?))
+}
 
-    if let Some(prefix) = pattern.strip_suffix('*') {
-        return index_name.starts_with(prefix);
-    }
+fn shard_infos(entries: Vec<CatShardEntry>) -> Vec<ShardInfo> {
+    entries
+        .into_iter()
+        .map(|s| ShardInfo {
+            shard_id: s.shard.parse().unwrap_or(0),
+            primary: s.prirep == "p",
+            state: s.state,
+            node: s.node.unwrap_or_else(|| "unassigned".to_string()),
+            docs: s.docs.and_then(|d| d.parse().ok()),
+            size: s.store,
+        })
+        .collect()
+}
 
-    if let Some(suffix) = pattern.strip_prefix('*') {
-        return index_name.ends_with(suffix);
-    }
+// Shard allocation on its own, for details fetched without it; also returns the raw response
+pub async fn fetch_shard_allocation(
+    client: &EsClient,
+    index_name: &str,
+) -> Result<(Vec<ShardInfo>, Value)> {
+    let raw: Value = client
+        .send_json(shards_request(client, index_name)?)
+        .await?;
+    let entries = Vec::<CatShardEntry>::deserialize(&raw)?;
+    Ok((shard_infos(entries), raw))
+}
+
+// Keep a raw response under `key` and parse it into its typed form
+fn record<T: DeserializeOwned>(
+    raw_responses: &mut BTreeMap<String, Value>,
+    key: &str,
+    response: Result<Value>,
+) -> Option<T> {
+    let value = response.ok()?;
+    // Parsed from a reference, so keeping the raw value needs no deep copy
+    let parsed = T::deserialize(&value).ok();
+    raw_responses.insert(key.to_string(), value);
+    parsed
+}
+
+// The hot phase rollover action of an ILM policy, if it has one
+async fn fetch_rollover_conditions(
+    client: &EsClient,
+    policy: &str,
+    raw_responses: &mut BTreeMap<String, Value>,
+) -> Option<RolloverConditions> {
+    let req = client.client.get(
+        client
+            .base_url
+            .join(&format!("_ilm/policy/{}", policy))
+            .ok()?,
+    );
+    let response = client.send_json::<Value>(req).await;
+    let mut policies = record::<IlmPolicyResponse>(raw_responses, "ilm_policy", response)?;
+    let rollover = policies
+        .remove(policy)?
+        .policy
+        .phases
+        .hot?
+        .actions
+        .rollover?;
+
+    Some(RolloverConditions {
+        max_docs: rollover.max_docs,
+        max_age: rollover.max_age,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
-    pattern == index_name
+    #[tokio::test]
+    async fn test_limited_bounds_requests_in_flight() {
+        let permits = Semaphore::new(2);
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let request = || async {
+            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        };
+
+        tokio::join!(
+            limited(&permits, request()),
+            limited(&permits, request()),
+            limited(&permits, request()),
+            limited(&permits, request()),
+        );
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
 }
@@ -0,0 +1,245 @@
+use super::client::EsClient;
+use super::types::{AllocationExplainResponse, CatShardEntry, RootInfo};
+use crate::error::Result;
+use serde_json::json;
+use std::fmt::Write;
+
+// Plain-text dump of the busiest threads on every node
+pub async fn fetch_hot_threads(client: &EsClient) -> Result<String> {
+    let url = client.base_url.join("_nodes/hot_threads")?;
+    let request = client.client.get(url);
+
+    client.send_text(request).await
+}
+
+// Name, version and build of the cluster behind the root endpoint. Fetched once per
+// session: none of it changes short of an upgrade, which restarts the node anyway.
+pub async fn fetch_cluster_info(client: &mut EsClient) -> Result<String> {
+    let info = match client.cluster_info {
+        Some(ref info) => info.clone(),
+        None => {
+            let request = client.client.get(client.base_url.clone());
+            let info: RootInfo = client.send_json(request).await?;
+            client.cluster_info.insert(info).clone()
+        }
+    };
+    Ok(format_cluster_info(&info))
+}
+
+fn format_cluster_info(info: &RootInfo) -> String {
+    let mut out = String::new();
+    let version = &info.version;
+
+    let _ = writeln!(out, "Cluster: {}", info.cluster_name);
+    if !info.cluster_uuid.is_empty() {
+        let _ = writeln!(out, "Cluster UUID: {}", info.cluster_uuid);
+    }
+    let _ = writeln!(out, "Answered by node: {}", info.name);
+
+    let _ = writeln!(out);
+    match version.distribution {
+        Some(ref distribution) => {
+            let _ = writeln!(out, "Version: {} ({})", version.number, distribution);
+        }
+        None => {
+            let _ = writeln!(out, "Version: {}", version.number);
+        }
+    }
+    let build: Vec<&str> = [&version.build_flavor, &version.build_type]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    if !build.is_empty() {
+        let _ = writeln!(out, "Build: {}", build.join(" / "));
+    }
+    if let Some(ref hash) = version.build_hash {
+        let _ = writeln!(out, "Build hash: {}", hash);
+    }
+    if let Some(ref date) = version.build_date {
+        let _ = writeln!(out, "Build date: {}", date);
+    }
+    if let Some(ref lucene) = version.lucene_version {
+        let _ = writeln!(out, "Lucene: {}", lucene);
+    }
+    if let Some(ref wire) = version.minimum_wire_compatibility_version {
+        let _ = writeln!(out, "Min wire compatibility: {}", wire);
+    }
+    if let Some(ref index) = version.minimum_index_compatibility_version {
+        let _ = writeln!(out, "Min index compatibility: {}", index);
+    }
+
+    out
+}
+
+// Explains the first unassigned shard of `index_name`, or of the cluster when the index
+// has none (or none is given), rendered as readable text
+pub async fn fetch_allocation_explain(
+    client: &EsClient,
+    index_name: Option<&str>,
+) -> Result<String> {
+    let mut request = client
+        .client
+        .post(client.base_url.join("_cluster/allocation/explain")?);
+
+    if let Some(index_name) = index_name {
+        let shards_req = client.client.get(client.base_url.join(&format!(
+            "_cat/shards/{}?format=json&h=index,shard,prirep,state",
+            index_name
+        ))?);
+        let shards: Vec<CatShardEntry> = client.send_json(shards_req).await?;
+
+        if let Some(shard) = shards.iter().find(|s| s.state == "UNASSIGNED") {
+            request = request.json(&json!({
+                "index": index_name,
+                "shard": shard.shard.parse::<u32>().unwrap_or(0),
+                "primary": shard.prirep == "p",
+            }));
+        }
+    }
+
+    let explain: AllocationExplainResponse = client.send_json(request).await?;
+    Ok(format_allocation_explain(&explain))
+}
+
+fn format_allocation_explain(explain: &AllocationExplainResponse) -> String {
+    let mut out = String::new();
+    let role = if explain.primary {
+        "primary"
+    } else {
+        "replica"
+    };
+
+    let _ = writeln!(
+        out,
+        "Shard: {}[{}] ({}), {}",
+        explain.index, explain.shard, role, explain.current_state
+    );
+    if let Some(ref node) = explain.current_node {
+        let _ = writeln!(out, "Current node: {}", node.name);
+    }
+
+    if let Some(ref info) = explain.unassigned_info {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "Unassigned reason: {}", info.reason);
+        if let Some(ref at) = info.at {
+            let _ = writeln!(out, "Unassigned since: {}", at);
+        }
+        if let Some(ref status) = info.last_allocation_status {
+            let _ = writeln!(out, "Last allocation status: {}", status);
+        }
+        if let Some(ref details) = info.details {
+            let _ = writeln!(out, "Details: {}", details);
+        }
+    }
+
+    let _ = writeln!(out);
+    if let Some(ref can_allocate) = explain.can_allocate {
+        let _ = writeln!(out, "Can allocate: {}", can_allocate);
+    }
+    if let Some(ref can_remain) = explain.can_remain_on_current_node {
+        let _ = writeln!(out, "Can remain on current node: {}", can_remain);
+    }
+    if let Some(ref can_rebalance) = explain.can_rebalance_cluster {
+        let _ = writeln!(out, "Can rebalance: {}", can_rebalance);
+    }
+    for explanation in [
+        &explain.allocate_explanation,
+        &explain.rebalance_explanation,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        let _ = writeln!(out, "{}", explanation);
+    }
+
+    if !explain.node_allocation_decisions.is_empty() {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "Per-node decisions:");
+    }
+    for node in &explain.node_allocation_decisions {
+        let _ = writeln!(out, "  {}: {}", node.node_name, node.node_decision);
+        // Only the deciders that said no (or throttled) explain the verdict
+        for decider in node.deciders.iter().filter(|d| d.decision != "YES") {
+            let _ = writeln!(
+                out,
+                "    [{}] {}: {}",
+                decider.decision, decider.decider, decider.explanation
+            );
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_allocation_explain_lists_blocking_deciders() {
+        let explain: AllocationExplainResponse = serde_json::from_value(json!({
+            "index": "logs",
+            "shard": 0,
+            "primary": false,
+            "current_state": "unassigned",
+            "unassigned_info": { "reason": "NODE_LEFT", "last_allocation_status": "no_attempt" },
+            "can_allocate": "no",
+            "allocate_explanation": "cannot allocate because allocation is not permitted to any of the nodes",
+            "node_allocation_decisions": [{
+                "node_name": "node-1",
+                "node_decision": "no",
+                "deciders": [
+                    { "decider": "same_shard", "decision": "NO", "explanation": "a copy of this shard is already allocated to this node" },
+                    { "decider": "disk_threshold", "decision": "YES", "explanation": "enough disk" }
+                ]
+            }]
+        }))
+        .unwrap();
+
+        let text = format_allocation_explain(&explain);
+        assert!(text.starts_with("Shard: logs[0] (replica), unassigned\n"));
+        assert!(text.contains("Unassigned reason: NODE_LEFT\n"));
+        assert!(text.contains("Can allocate: no\n"));
+        assert!(text.contains("  node-1: no\n    [NO] same_shard: a copy"));
+        assert!(!text.contains("disk_threshold"));
+    }
+
+    #[test]
+    fn test_format_cluster_info() {
+        let info: RootInfo = serde_json::from_value(json!({
+            "name": "es-data-1",
+            "cluster_name": "prod",
+            "cluster_uuid": "Ab3dE",
+            "version": {
+                "number": "8.13.4",
+                "build_flavor": "default",
+                "build_type": "docker",
+                "build_hash": "da95df118650b55a500dcc181889ac35c6d8da7c",
+                "lucene_version": "9.10.0",
+                "minimum_wire_compatibility_version": "7.17.0"
+            },
+            "tagline": "You Know, for Search"
+        }))
+        .unwrap();
+
+        let text = format_cluster_info(&info);
+        assert!(
+            text.starts_with("Cluster: prod\nCluster UUID: Ab3dE\nAnswered by node: es-data-1\n")
+        );
+        assert!(text.contains("Version: 8.13.4\nBuild: default / docker\n"));
+        assert!(text.contains("Lucene: 9.10.0\n"));
+        assert!(!text.contains("Build date"));
+
+        // OpenSearch names its distribution and has no build flavor
+        let info: RootInfo = serde_json::from_value(json!({
+            "name": "os-1",
+            "cluster_name": "search",
+            "version": { "distribution": "opensearch", "number": "2.13.0", "build_type": "tar" }
+        }))
+        .unwrap();
+        let text = format_cluster_info(&info);
+        assert!(text.contains("Version: 2.13.0 (opensearch)\nBuild: tar\n"));
+        assert!(!text.contains("Cluster UUID"));
+    }
+}
@@ -1,6 +1,8 @@
 pub mod client;
 pub mod details;
+pub mod diagnostics;
 pub mod stats;
 pub mod types;
+pub mod write;
 
 pub use client::{AuthConfig, EsClient};
@@ -1,15 +1,52 @@
 use super::client::EsClient;
-use super::types::{ClusterHealthResponse, StatsResponse};
+use super::types::{
+    CatIndexEntry, CatShardEntry, ClusterHealthResponse, FlatSettingsEntry, NodesStatsResponse,
+    StatsResponse,
+};
 use crate::error::Result;
-use crate::models::{ClusterHealth, IndexRate, IndexSnapshot};
+use crate::models::{
+    ClusterHealth, IndexRate, IndexSnapshot, NodeHeap, NodeShards, Replicas, ShardDistribution,
+    StatGroup,
+};
+use crate::utils::finite_or_zero;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::time::Instant;
 
-pub async fn fetch_index_rates(client: &mut EsClient) -> Result<Vec<IndexRate>> {
-    let url = client.base_url.join("_stats/indexing,docs,store")?;
+pub async fn fetch_index_rates(client: &EsClient) -> Result<Vec<IndexRate>> {
+    let pattern = client.index_pattern.as_deref();
+    let url = client
+        .base_url
+        .join(&stats_path(pattern, &client.stat_groups))?;
     let request = client.client.get(url);
+    let cat_req = client.client.get(client.base_url.join(&cat_path(
+        "indices",
+        pattern,
+        "format=json&h=index,health,status,creation.date",
+    ))?);
+    let settings_req = client
+        .client
+        .get(client.base_url.join(&replica_settings_path(pattern))?);
 
-    let stats: StatsResponse = client.send_json(request).await?;
+    let (stats, cat, settings) = tokio::join!(
+        client.send_json_streaming::<StatsResponse>(request),
+        client.send_json_streaming::<Vec<CatIndexEntry>>(cat_req),
+        client.send_json_streaming::<HashMap<String, FlatSettingsEntry>>(settings_req),
+    );
+    let stats = stats?;
+    // Creation dates and closed indices are extras on top of `_stats`, so a failed
+    // `_cat/indices` is not fatal
+    let cat = cat.unwrap_or_default();
+    let creation_dates: HashMap<String, DateTime<Utc>> = cat
+        .iter()
+        .filter_map(|entry| {
+            let millis = entry.creation_date.as_deref()?.parse().ok()?;
+            Some((
+                entry._index.clone(),
+                DateTime::from_timestamp_millis(millis)?,
+            ))
+        })
+        .collect();
 
     let now = Instant::now();
 
@@ -23,7 +60,16 @@ pub async fn fetch_index_rates(client: &mut EsClient) -> Result<Vec<IndexRate>>
                 IndexSnapshot {
                     doc_count: entry.primaries.docs.count,
                     index_total: entry.primaries.indexing.index_total,
+                    index_time_in_millis: entry.primaries.indexing.index_time_in_millis,
+                    // Queries are served by replicas too, so count every shard copy
+                    query_total: entry.total.search.query_total,
+                    query_time_in_millis: entry.total.search.query_time_in_millis,
+                    merges_current: entry.total.merges.current,
+                    merge_time_in_millis: entry.total.merges.total_time_in_millis,
+                    segment_count: entry.primaries.segments.count,
+                    index_failed: entry.primaries.indexing.index_failed,
                     size_bytes: entry.primaries.store.size_in_bytes,
+                    total_size_bytes: entry.total.store.size_in_bytes,
                     health: entry.health.clone(),
                 },
             )
@@ -31,49 +77,166 @@ pub async fn fetch_index_rates(client: &mut EsClient) -> Result<Vec<IndexRate>>
         .collect();
 
     // Calculate rates based on the previous snapshot
-    let rates: Vec<IndexRate> = if let Some((prev_time, prev_snapshot)) = &client.previous_snapshot
-    {
-        let elapsed = now.duration_since(*prev_time).as_secs_f64();
-
-        current_snapshot
-            .iter()
-            .map(|(name, current)| {
-                let rate = prev_snapshot
-                    .get(name)
-                    .filter(|prev| elapsed > 0.0 && current.index_total >= prev.index_total)
-                    .map(|prev| (current.index_total - prev.index_total) as f64 / elapsed)
-                    .unwrap_or(0.0);
-
-                IndexRate {
+    let mut previous_snapshot = client
+        .previous_snapshot
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let mut rates: Vec<IndexRate> =
+        if let Some((prev_time, prev_snapshot)) = previous_snapshot.as_ref() {
+            let elapsed = now.duration_since(*prev_time).as_secs_f64();
+
+            current_snapshot
+                .iter()
+                .map(|(name, current)| {
+                    let prev = prev_snapshot.get(name);
+                    let rate = prev
+                        .filter(|prev| elapsed > 0.0 && current.index_total >= prev.index_total)
+                        .map(|prev| (current.index_total - prev.index_total) as f64 / elapsed)
+                        .map(finite_or_zero)
+                        .unwrap_or(0.0);
+                    // Counters reset when shards move, which must not read as negative failures
+                    let failed_delta = prev
+                        .map(|prev| current.index_failed.saturating_sub(prev.index_failed))
+                        .unwrap_or(0);
+                    let index_latency_ms = prev.and_then(|prev| {
+                        latency(
+                            (prev.index_total, prev.index_time_in_millis),
+                            (current.index_total, current.index_time_in_millis),
+                        )
+                    });
+                    let merge_time_rate = prev.map_or(0.0, |prev| {
+                        merge_time_rate(
+                            prev.merge_time_in_millis,
+                            current.merge_time_in_millis,
+                            elapsed,
+                        )
+                    });
+                    let query_latency_ms = prev.and_then(|prev| {
+                        latency(
+                            (prev.query_total, prev.query_time_in_millis),
+                            (current.query_total, current.query_time_in_millis),
+                        )
+                    });
+
+                    IndexRate {
+                        name: name.clone(),
+                        doc_count: current.doc_count,
+                        rate_per_sec: rate,
+                        size_bytes: current.size_bytes,
+                        total_size_bytes: current.total_size_bytes,
+                        health: current.health.clone(),
+                        index_failed: current.index_failed,
+                        failed_delta,
+                        closed: false,
+                        unassigned_shards: 0,
+                        replicas: None,
+                        index_latency_ms,
+                        query_latency_ms,
+                        merges_current: current.merges_current,
+                        merge_time_rate,
+                        segment_count: current.segment_count,
+                        segments_delta: prev.map_or(0, |prev| {
+                            current.segment_count as i64 - prev.segment_count as i64
+                        }),
+                        creation_date: creation_dates.get(name).copied(),
+                    }
+                })
+                .collect()
+        } else {
+            // First fetch, no rate data yet
+            current_snapshot
+                .iter()
+                .map(|(name, current)| IndexRate {
                     name: name.clone(),
                     doc_count: current.doc_count,
-                    rate_per_sec: rate,
+                    rate_per_sec: 0.0,
                     size_bytes: current.size_bytes,
+                    total_size_bytes: current.total_size_bytes,
                     health: current.health.clone(),
-                }
-            })
-            .collect()
-    } else {
-        // First fetch, no rate data yet
-        current_snapshot
-            .iter()
-            .map(|(name, current)| IndexRate {
-                name: name.clone(),
-                doc_count: current.doc_count,
-                rate_per_sec: 0.0,
-                size_bytes: current.size_bytes,
-                health: current.health.clone(),
-            })
-            .collect()
-    };
+                    index_failed: current.index_failed,
+                    failed_delta: 0,
+                    closed: false,
+                    unassigned_shards: 0,
+                    replicas: None,
+                    index_latency_ms: None,
+                    query_latency_ms: None,
+                    merges_current: 0,
+                    merge_time_rate: 0.0,
+                    segment_count: current.segment_count,
+                    segments_delta: 0,
+                    creation_date: creation_dates.get(name).copied(),
+                })
+                .collect()
+        };
+
+    // `_stats` leaves closed indices out entirely
+    rates.extend(closed_indices(&cat, &current_snapshot, &creation_dates));
+
+    // Replica settings are display-only as well, so missing ones just show as unknown
+    if let Ok(settings) = settings {
+        for rate in &mut rates {
+            rate.replicas = settings.get(&rate.name).and_then(replicas);
+        }
+    }
 
     // Store current snapshot for the next calculation
-    client.previous_snapshot = Some((now, current_snapshot));
+    *previous_snapshot = Some((now, current_snapshot));
 
     Ok(rates)
 }
 
-pub async fn fetch_cluster_health(client: &mut EsClient) -> Result<ClusterHealth> {
+// Time spent per op over the window, from `(ops, time_in_millis)` counter pairs;
+// counters reset when shards move
+fn latency(prev: (u64, u64), current: (u64, u64)) -> Option<f64> {
+    let ops = current.0.checked_sub(prev.0)?;
+    let millis = current.1.checked_sub(prev.1)?;
+    (ops > 0).then(|| millis as f64 / ops as f64)
+}
+
+// Milliseconds spent merging per second, summed over concurrent merges; 0 without a
+// window or when the counter went backwards
+fn merge_time_rate(prev_millis: u64, current_millis: u64, elapsed: f64) -> f64 {
+    if elapsed <= 0.0 {
+        return 0.0;
+    }
+    current_millis
+        .checked_sub(prev_millis)
+        .map_or(0.0, |millis| finite_or_zero(millis as f64 / elapsed))
+}
+
+// Placeholder rows for closed indices, which have no docs, rate or size to report
+fn closed_indices(
+    cat: &[CatIndexEntry],
+    open: &HashMap<String, IndexSnapshot>,
+    creation_dates: &HashMap<String, DateTime<Utc>>,
+) -> Vec<IndexRate> {
+    cat.iter()
+        .filter(|entry| entry.status.as_deref() == Some("close"))
+        .filter(|entry| !open.contains_key(&entry._index))
+        .map(|entry| IndexRate {
+            name: entry._index.clone(),
+            doc_count: 0,
+            rate_per_sec: 0.0,
+            size_bytes: 0,
+            total_size_bytes: 0,
+            health: entry.health.clone().unwrap_or_default(),
+            index_failed: 0,
+            failed_delta: 0,
+            closed: true,
+            unassigned_shards: 0,
+            replicas: None,
+            index_latency_ms: None,
+            query_latency_ms: None,
+            merges_current: 0,
+            merge_time_rate: 0.0,
+            segment_count: 0,
+            segments_delta: 0,
+            creation_date: creation_dates.get(&entry._index).copied(),
+        })
+        .collect()
+}
+
+pub async fn fetch_cluster_health(client: &EsClient) -> Result<ClusterHealth> {
     let url = client.base_url.join("_cluster/health")?;
     let request = client.client.get(url);
 
@@ -81,7 +244,12 @@ pub async fn fetch_cluster_health(client: &mut EsClient) -> Result<ClusterHealth
 
     Ok(ClusterHealth {
         cluster_name: health.cluster_name,
-        status: health.status,
+        // Missing from a partial response; shown as unknown rather than blank
+        status: if health.status.is_empty() {
+            "unknown".to_string()
+        } else {
+            health.status
+        },
         number_of_nodes: health.number_of_nodes,
         number_of_data_nodes: health.number_of_data_nodes,
         active_primary_shards: health.active_primary_shards,
@@ -93,3 +261,277 @@ pub async fn fetch_cluster_health(client: &mut EsClient) -> Result<ClusterHealth
         number_of_pending_tasks: health.number_of_pending_tasks,
     })
 }
+
+pub async fn fetch_shard_distribution(client: &EsClient) -> Result<ShardDistribution> {
+    let url = client.base_url.join(&cat_path(
+        "shards",
+        client.index_pattern.as_deref(),
+        "format=json&h=index,shard,prirep,state,node",
+    ))?;
+    let request = client.client.get(url);
+
+    let entries: Vec<CatShardEntry> = client.send_json_streaming(request).await?;
+
+    Ok(shard_distribution(&entries))
+}
+
+// `_stats` for the whole cluster, or only for indices matching `--index`. Missing
+// concrete indices are skipped so a pattern without matches yields no rows, not a 404.
+fn stats_path(pattern: Option<&str>, groups: &[StatGroup]) -> String {
+    let groups = groups
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    match pattern {
+        Some(pattern) => format!("{}/_stats/{}?ignore_unavailable=true", pattern, groups),
+        None => format!("_stats/{}", groups),
+    }
+}
+
+pub async fn fetch_node_heap(client: &EsClient) -> Result<Vec<NodeHeap>> {
+    let url = client
+        .base_url
+        .join("_nodes/stats/jvm?filter_path=nodes.*.name,nodes.*.jvm.mem.heap_used_percent")?;
+    let response: NodesStatsResponse = client.send_json(client.client.get(url)).await?;
+    Ok(node_heaps(response))
+}
+
+// Heap usage per node, sorted by node name
+fn node_heaps(response: NodesStatsResponse) -> Vec<NodeHeap> {
+    let mut heaps: Vec<NodeHeap> = response
+        .nodes
+        .into_values()
+        .map(|node| NodeHeap {
+            node: node.name,
+            heap_used_percent: node.jvm.mem.heap_used_percent,
+        })
+        .collect();
+    heaps.sort_by(|a, b| a.node.cmp(&b.node));
+    heaps
+}
+
+// Just the replica settings of every index (or those matching `--index`)
+fn replica_settings_path(pattern: Option<&str>) -> String {
+    let path = "_settings/index.number_of_replicas,index.auto_expand_replicas?flat_settings=true";
+    match pattern {
+        Some(pattern) => format!("{}/{}&ignore_unavailable=true", pattern, path),
+        None => path.to_string(),
+    }
+}
+
+fn replicas(entry: &FlatSettingsEntry) -> Option<Replicas> {
+    Replicas::from_settings(
+        entry.get("index.number_of_replicas"),
+        entry.get("index.auto_expand_replicas"),
+    )
+}
+
+// `_cat/<api>`, narrowed to `--index` when set
+fn cat_path(api: &str, pattern: Option<&str>, query: &str) -> String {
+    match pattern {
+        Some(pattern) => format!("_cat/{}/{}?{}", api, pattern, query),
+        None => format!("_cat/{}?{}", api, query),
+    }
+}
+
+// Aggregate per-shard rows into per-node counts
+fn shard_distribution(entries: &[CatShardEntry]) -> ShardDistribution {
+    let mut nodes: HashMap<&str, NodeShards> = HashMap::new();
+    let mut unassigned = 0;
+    let mut unassigned_by_index: HashMap<String, usize> = HashMap::new();
+
+    for entry in entries {
+        let Some(node) = entry
+            .node
+            .as_deref()
+            .filter(|_| entry.state != "UNASSIGNED")
+        else {
+            unassigned += 1;
+            *unassigned_by_index.entry(entry._index.clone()).or_default() += 1;
+            continue;
+        };
+        let counts = nodes.entry(node).or_insert_with(|| NodeShards {
+            node: node.to_string(),
+            ..Default::default()
+        });
+        counts.shards += 1;
+        if entry.prirep == "p" {
+            counts.primaries += 1;
+        }
+    }
+
+    let mut nodes: Vec<NodeShards> = nodes.into_values().collect();
+    nodes.sort_by(|a, b| a.node.cmp(&b.node));
+
+    ShardDistribution {
+        nodes,
+        unassigned,
+        unassigned_by_index,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_over_window() {
+        assert_eq!(latency((100, 50), (300, 450)), Some(2.0));
+        // No ops in the window, or counters that went backwards
+        assert_eq!(latency((100, 50), (100, 50)), None);
+        assert_eq!(latency((100, 50), (10, 60)), None);
+        assert_eq!(latency((100, 50), (200, 5)), None);
+    }
+
+    #[test]
+    fn test_merge_time_rate() {
+        assert_eq!(merge_time_rate(1_000, 4_000, 2.0), 1_500.0);
+        assert_eq!(merge_time_rate(1_000, 1_000, 2.0), 0.0);
+        assert_eq!(merge_time_rate(1_000, 4_000, 0.0), 0.0);
+        // Counters reset when shards move
+        assert_eq!(merge_time_rate(4_000, 1_000, 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_replicas_from_flat_settings() {
+        let settings: HashMap<String, FlatSettingsEntry> =
+            serde_json::from_value(serde_json::json!({
+                "fixed": {"settings": {"index.number_of_replicas": "2"}},
+                "auto": {"settings": {
+                    "index.number_of_replicas": "1",
+                    "index.auto_expand_replicas": "0-all"
+                }},
+                "off": {"settings": {
+                    "index.number_of_replicas": "1",
+                    "index.auto_expand_replicas": "false"
+                }},
+                "odd": {"settings": {"index.number_of_replicas": "many"}}
+            }))
+            .unwrap();
+
+        assert_eq!(replicas(&settings["fixed"]), Some(Replicas::Count(2)));
+        assert_eq!(
+            replicas(&settings["auto"]),
+            Some(Replicas::AutoExpand("0-all".to_string()))
+        );
+        assert_eq!(replicas(&settings["off"]), Some(Replicas::Count(1)));
+        assert_eq!(replicas(&settings["odd"]), None);
+        assert_eq!(
+            Replicas::AutoExpand("1-5".to_string()).sort_key(),
+            (1, true)
+        );
+    }
+
+    #[test]
+    fn test_node_heaps_from_filtered_stats() {
+        let response: NodesStatsResponse = serde_json::from_value(serde_json::json!({
+            "nodes": {
+                "b1": {"name": "node-2", "jvm": {"mem": {"heap_used_percent": 91}}},
+                "a1": {"name": "node-1", "jvm": {"mem": {"heap_used_percent": 40}}}
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            node_heaps(response),
+            vec![
+                NodeHeap {
+                    node: "node-1".to_string(),
+                    heap_used_percent: 40
+                },
+                NodeHeap {
+                    node: "node-2".to_string(),
+                    heap_used_percent: 91
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_index_pattern_scopes_paths() {
+        use crate::models::DEFAULT_STAT_GROUPS;
+
+        assert_eq!(
+            stats_path(None, &DEFAULT_STAT_GROUPS),
+            "_stats/indexing,docs,store,search,merge"
+        );
+        assert_eq!(
+            stats_path(Some("logs-*"), &[StatGroup::Docs, StatGroup::Segments]),
+            "logs-*/_stats/docs,segments?ignore_unavailable=true"
+        );
+        assert_eq!(
+            replica_settings_path(Some("logs-*")),
+            "logs-*/_settings/index.number_of_replicas,index.auto_expand_replicas?flat_settings=true&ignore_unavailable=true"
+        );
+        assert_eq!(
+            cat_path("shards", None, "format=json"),
+            "_cat/shards?format=json"
+        );
+        assert_eq!(
+            cat_path("indices", Some("logs-*"), "format=json"),
+            "_cat/indices/logs-*?format=json"
+        );
+    }
+
+    fn entry(prirep: &str, state: &str, node: Option<&str>) -> CatShardEntry {
+        CatShardEntry {
+            _index: "logs".to_string(),
+            shard: "0".to_string(),
+            prirep: prirep.to_string(),
+            state: state.to_string(),
+            docs: None,
+            store: None,
+            node: node.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_shard_distribution_per_node() {
+        let distribution = shard_distribution(&[
+            entry("p", "STARTED", Some("node-2")),
+            entry("r", "STARTED", Some("node-1")),
+            entry("p", "STARTED", Some("node-1")),
+            entry("r", "UNASSIGNED", None),
+        ]);
+
+        assert_eq!(distribution.unassigned, 1);
+        assert_eq!(distribution.unassigned_by_index.get("logs"), Some(&1));
+        assert_eq!(
+            distribution.nodes,
+            vec![
+                NodeShards {
+                    node: "node-1".to_string(),
+                    shards: 2,
+                    primaries: 1
+                },
+                NodeShards {
+                    node: "node-2".to_string(),
+                    shards: 1,
+                    primaries: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_closed_indices_fill_in_for_stats() {
+        let cat_entry = |index: &str, status: &str| CatIndexEntry {
+            health: Some("green".to_string()),
+            status: Some(status.to_string()),
+            _index: index.to_string(),
+            creation_date: None,
+        };
+        let cat = [
+            cat_entry("logs-open", "open"),
+            cat_entry("logs-old", "close"),
+        ];
+        let open = HashMap::from([("logs-open".to_string(), IndexSnapshot::default())]);
+
+        let closed = closed_indices(&cat, &open, &HashMap::new());
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].name, "logs-old");
+        assert!(closed[0].closed);
+        assert_eq!(closed[0].doc_count, 0);
+    }
+}
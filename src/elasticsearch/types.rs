@@ -10,14 +10,33 @@ pub struct StatsResponse {
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct IndexStatsEntry {
     pub primaries: PrimaryStats,
+    #[serde(default)]
+    pub total: TotalStats,
     pub health: String,
 }
 
+// Groups left out of `--stats` are missing from the response and read as zero
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct PrimaryStats {
+    #[serde(default)]
     pub docs: DocsStats,
+    #[serde(default)]
     pub indexing: IndexingStats,
+    #[serde(default)]
     pub store: StoreStats,
+    #[serde(default)]
+    pub segments: SegmentsCount,
+}
+
+// Primaries plus replicas; only the store size, search and merge stats are read
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct TotalStats {
+    #[serde(default)]
+    pub store: StoreStats,
+    #[serde(default)]
+    pub search: SearchStats,
+    #[serde(default)]
+    pub merges: MergeStats,
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
@@ -28,6 +47,26 @@ pub struct DocsStats {
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct IndexingStats {
     pub index_total: u64,
+    #[serde(default)]
+    pub index_time_in_millis: u64,
+    #[serde(default)]
+    pub index_failed: u64,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct SearchStats {
+    #[serde(default)]
+    pub query_total: u64,
+    #[serde(default)]
+    pub query_time_in_millis: u64,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct MergeStats {
+    #[serde(default)]
+    pub current: u64,
+    #[serde(default)]
+    pub total_time_in_millis: u64,
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
@@ -48,6 +87,19 @@ pub struct IndexSettingsEntry {
     pub settings: IndexSettings,
 }
 
+// One index of `_settings?flat_settings=true`, keyed like `index.number_of_replicas`
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct FlatSettingsEntry {
+    #[serde(default)]
+    pub settings: HashMap<String, serde_json::Value>,
+}
+
+impl FlatSettingsEntry {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.settings.get(key)?.as_str()
+    }
+}
+
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct IndexSettings {
     pub index: IndexSettingsIndex,
@@ -58,6 +110,9 @@ pub struct IndexSettingsIndex {
     pub creation_date: Option<String>,
     pub number_of_shards: Option<String>,
     pub number_of_replicas: Option<String>,
+    /// A range like `0-all`, or `false` when replicas don't auto-expand
+    #[serde(default)]
+    pub auto_expand_replicas: Option<String>,
     pub uuid: Option<String>,
     #[serde(default)]
     pub frozen: Option<String>,
@@ -111,6 +166,58 @@ pub struct SegmentsCount {
     pub count: u64,
 }
 
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct TranslogStatsResponse {
+    pub indices: HashMap<String, TranslogIndexStats>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct TranslogIndexStats {
+    pub primaries: TranslogPrimaryStats,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct TranslogPrimaryStats {
+    pub translog: TranslogCounters,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct TranslogCounters {
+    #[serde(default)]
+    pub size_in_bytes: u64,
+    #[serde(default)]
+    pub operations: u64,
+}
+
+// `_stats/query_cache,request_cache`, summed over all shard copies
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct CacheStatsResponse {
+    pub indices: HashMap<String, CacheIndexStats>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct CacheIndexStats {
+    pub total: CacheTotals,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct CacheTotals {
+    #[serde(default)]
+    pub query_cache: CacheCounters,
+    #[serde(default)]
+    pub request_cache: CacheCounters,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct CacheCounters {
+    #[serde(default)]
+    pub memory_size_in_bytes: u64,
+    #[serde(default)]
+    pub hit_count: u64,
+    #[serde(default)]
+    pub miss_count: u64,
+}
+
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct CatShardEntry {
     #[serde(rename = "index")]
@@ -141,10 +248,15 @@ pub struct IndexTemplateDetails {
 
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct CatIndexEntry {
+    #[serde(default)]
     pub health: Option<String>,
+    #[serde(default)]
     pub status: Option<String>,
     #[serde(rename = "index")]
     pub _index: String,
+    // Epoch milliseconds, only present when requested with `h=creation.date`
+    #[serde(rename = "creation.date", default)]
+    pub creation_date: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
@@ -184,7 +296,90 @@ pub struct DataStreamLifecycle {
     #[serde(default)]
     pub data_retention: Option<String>,
 }
+
+// Response of `_ilm/policy/<name>`, keyed by policy name
+pub type IlmPolicyResponse = HashMap<String, IlmPolicyEntry>;
+
 #[derive(Debug, Deserialize, Default, Clone)]
+pub struct IlmPolicyEntry {
+    pub policy: IlmPolicy,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct IlmPolicy {
+    #[serde(default)]
+    pub phases: IlmPhases,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct IlmPhases {
+    #[serde(default)]
+    pub hot: Option<IlmHotPhase>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct IlmHotPhase {
+    #[serde(default)]
+    pub actions: IlmHotActions,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct IlmHotActions {
+    #[serde(default)]
+    pub rollover: Option<IlmRolloverAction>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct IlmRolloverAction {
+    #[serde(default)]
+    pub max_age: Option<String>,
+    #[serde(default)]
+    pub max_docs: Option<u64>,
+}
+
+// Answer of settings updates and other write APIs
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct AcknowledgedResponse {
+    #[serde(default)]
+    pub acknowledged: bool,
+}
+
+// `<data_stream>/_rollover`
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct RolloverResponse {
+    #[serde(default)]
+    pub new_index: String,
+}
+
+// `_nodes/stats/jvm`, trimmed by `filter_path` to node names and heap usage
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct NodesStatsResponse {
+    #[serde(default)]
+    pub nodes: HashMap<String, NodeStatsEntry>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct NodeStatsEntry {
+    pub name: String,
+    #[serde(default)]
+    pub jvm: NodeJvmStats,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct NodeJvmStats {
+    #[serde(default)]
+    pub mem: NodeJvmMem,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct NodeJvmMem {
+    #[serde(default)]
+    pub heap_used_percent: u64,
+}
+
+// Every field defaults, so a partial (e.g. timed-out) health response still parses
+#[derive(Debug, Deserialize, Default, Clone)]
+#[serde(default)]
 pub struct ClusterHealthResponse {
     pub cluster_name: String,
     pub status: String,
@@ -199,6 +394,76 @@ pub struct ClusterHealthResponse {
     pub number_of_pending_tasks: u32,
 }
 
+// `_cluster/allocation/explain`; unassigned and assigned shards fill different fields
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct AllocationExplainResponse {
+    pub index: String,
+    pub shard: u32,
+    pub primary: bool,
+    pub current_state: String,
+    pub current_node: Option<AllocationNode>,
+    pub unassigned_info: Option<UnassignedInfo>,
+    pub can_allocate: Option<String>,
+    pub allocate_explanation: Option<String>,
+    pub can_remain_on_current_node: Option<String>,
+    pub can_rebalance_cluster: Option<String>,
+    pub rebalance_explanation: Option<String>,
+    #[serde(default)]
+    pub node_allocation_decisions: Vec<NodeAllocationDecision>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct AllocationNode {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct UnassignedInfo {
+    pub reason: String,
+    pub at: Option<String>,
+    pub last_allocation_status: Option<String>,
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct NodeAllocationDecision {
+    pub node_name: String,
+    pub node_decision: String,
+    #[serde(default)]
+    pub deciders: Vec<DeciderVerdict>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct DeciderVerdict {
+    pub decider: String,
+    pub decision: String,
+    pub explanation: String,
+}
+
+// Response of the root endpoint (`GET /`)
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct RootInfo {
+    pub name: String,
+    pub cluster_name: String,
+    #[serde(default)]
+    pub cluster_uuid: String,
+    pub version: VersionInfo,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct VersionInfo {
+    pub number: String,
+    // Set by OpenSearch ("opensearch"), absent on Elasticsearch
+    pub distribution: Option<String>,
+    pub build_flavor: Option<String>,
+    pub build_type: Option<String>,
+    pub build_hash: Option<String>,
+    pub build_date: Option<String>,
+    pub lucene_version: Option<String>,
+    pub minimum_wire_compatibility_version: Option<String>,
+    pub minimum_index_compatibility_version: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +490,19 @@ mod tests {
         assert_eq!(entry.node, Some("node-1".to_string()));
     }
 
+    #[test]
+    fn test_deserialize_partial_cluster_health() {
+        let json_data = json!({
+            "cluster_name": "prod",
+            "number_of_nodes": 3
+        });
+        let health: ClusterHealthResponse = serde_json::from_value(json_data).unwrap();
+        assert_eq!(health.cluster_name, "prod");
+        assert_eq!(health.number_of_nodes, 3);
+        assert_eq!(health.status, "");
+        assert_eq!(health.active_shards_percent_as_number, 0.0);
+    }
+
     #[test]
     fn test_deserialize_cat_index_entry() {
         let json_data = json!({
@@ -0,0 +1,27 @@
+// Write operations, only reachable with `--allow-destructive`
+
+use super::client::EsClient;
+use super::types::{AcknowledgedResponse, RolloverResponse};
+use crate::error::Result;
+use serde_json::json;
+
+// Sets `index.number_of_replicas`; returns whether the cluster acknowledged it in time
+pub async fn update_replicas(client: &EsClient, index_name: &str, replicas: u32) -> Result<bool> {
+    let url = client.base_url.join(&format!("{}/_settings", index_name))?;
+    let request = client
+        .client
+        .put(url)
+        .json(&json!({ "index.number_of_replicas": replicas }));
+
+    let response: AcknowledgedResponse = client.send_json(request).await?;
+    Ok(response.acknowledged)
+}
+
+// Rolls the data stream over unconditionally; returns the new write index
+pub async fn rollover(client: &EsClient, data_stream: &str) -> Result<String> {
+    let url = client
+        .base_url
+        .join(&format!("{}/_rollover", data_stream))?;
+    let response: RolloverResponse = client.send_json(client.client.post(url)).await?;
+    Ok(response.new_index)
+}
@@ -5,7 +5,15 @@ use thiserror::Error;
 pub enum EstiCliError {
     /// Error indicating a failure to connect to Elasticsearch.
     #[error("Elasticsearch connection failed: {0}")]
-    Connection(#[from] reqwest::Error),
+    Connection(reqwest::Error),
+
+    /// The host could not be reached within the connect timeout.
+    #[error("Can't reach Elasticsearch host (connect timeout): {0}")]
+    ConnectTimeout(reqwest::Error),
+
+    /// The connection was made but the response took longer than the request timeout.
+    #[error("Elasticsearch is slow to respond (request timeout): {0}")]
+    Timeout(reqwest::Error),
 
     /// Error indicating a non-2xx API response from Elasticsearch.
     #[error("API error (Status {status}): {body}")]
@@ -20,8 +28,23 @@ pub enum EstiCliError {
     #[error("URL parsing error: {0}")]
     Url(#[from] url::ParseError),
 
+    #[error("Config file error: {0}")]
+    Config(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
 
+impl From<reqwest::Error> for EstiCliError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_connect() && error.is_timeout() {
+            EstiCliError::ConnectTimeout(error)
+        } else if error.is_timeout() {
+            EstiCliError::Timeout(error)
+        } else {
+            EstiCliError::Connection(error)
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, EstiCliError>;
@@ -0,0 +1,258 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use crate::models::{IndexRate, StatGroup};
+
+/// Machine-readable formats for the index list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// One JSON array of all indices
+    Json,
+    /// One JSON object per line and index
+    Ndjson,
+    /// Header row plus one row per index, columns as in `CSV_COLUMNS`
+    Csv,
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Ndjson => write!(f, "ndjson"),
+            OutputFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" => Ok(OutputFormat::Ndjson),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!(
+                "Unknown output format '{}'. Available: json, ndjson, csv",
+                s
+            )),
+        }
+    }
+}
+
+/// The exported column set, shared by every format so they stay in step.
+pub const CSV_COLUMNS: [&str; 9] = [
+    "timestamp",
+    "name",
+    "doc_count",
+    "rate_per_sec",
+    "size_bytes",
+    "total_size_bytes",
+    "health",
+    "index_failed",
+    "creation_date",
+];
+
+// One exported index; field order matches `CSV_COLUMNS`. Stats of groups left out of
+// `--stats` are `null` in JSON and `-` in CSV rather than a misleading zero.
+#[derive(Serialize)]
+struct ExportRow<'a> {
+    timestamp: &'a str,
+    name: &'a str,
+    doc_count: Option<u64>,
+    rate_per_sec: Option<f64>,
+    size_bytes: Option<u64>,
+    total_size_bytes: Option<u64>,
+    health: &'a str,
+    index_failed: Option<u64>,
+    creation_date: Option<String>,
+}
+
+impl<'a> ExportRow<'a> {
+    fn new(index: &'a IndexRate, timestamp: &'a str, groups: &[StatGroup]) -> Self {
+        let fetched = |group| groups.contains(&group);
+        Self {
+            timestamp,
+            name: &index.name,
+            doc_count: fetched(StatGroup::Docs).then_some(index.doc_count),
+            rate_per_sec: fetched(StatGroup::Indexing).then_some(index.rate_per_sec),
+            size_bytes: fetched(StatGroup::Store).then_some(index.size_bytes),
+            total_size_bytes: fetched(StatGroup::Store).then_some(index.total_size_bytes),
+            health: &index.health,
+            index_failed: fetched(StatGroup::Indexing).then_some(index.index_failed),
+            creation_date: index.creation_date.map(iso8601),
+        }
+    }
+
+    fn csv_fields(&self) -> [String; 9] {
+        [
+            self.timestamp.to_string(),
+            self.name.to_string(),
+            stat_field(self.doc_count),
+            stat_field(self.rate_per_sec),
+            stat_field(self.size_bytes),
+            stat_field(self.total_size_bytes),
+            self.health.to_string(),
+            stat_field(self.index_failed),
+            self.creation_date.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+// CSV text of a stat, `-` when its group wasn't fetched
+fn stat_field<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".to_string(), |value| value.to_string())
+}
+
+// ISO-8601 in UTC with a `Z` suffix, e.g. `2024-01-31T12:00:00Z`
+fn iso8601(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Writes `indices` sampled at `at` to `out` in `format`, with the stats of `groups`.
+pub fn write_indices<W: Write>(
+    mut out: W,
+    format: OutputFormat,
+    indices: &[&IndexRate],
+    groups: &[StatGroup],
+    at: DateTime<Utc>,
+) -> io::Result<()> {
+    let timestamp = iso8601(at);
+    let rows = indices
+        .iter()
+        .map(|index| ExportRow::new(index, &timestamp, groups));
+
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_writer(&mut out, &rows.collect::<Vec<_>>())?;
+            writeln!(out)?;
+        }
+        OutputFormat::Ndjson => {
+            for row in rows {
+                serde_json::to_writer(&mut out, &row)?;
+                writeln!(out)?;
+            }
+        }
+        OutputFormat::Csv => return write_csv(out, indices, groups, at, true),
+    }
+    out.flush()
+}
+
+/// Writes `indices` as CSV, leaving out the header row when appending to an earlier export.
+pub fn write_csv<W: Write>(
+    mut out: W,
+    indices: &[&IndexRate],
+    groups: &[StatGroup],
+    at: DateTime<Utc>,
+    header: bool,
+) -> io::Result<()> {
+    let timestamp = iso8601(at);
+    if header {
+        writeln!(out, "{}", CSV_COLUMNS.join(","))?;
+    }
+    for index in indices {
+        let fields = ExportRow::new(index, &timestamp, groups)
+            .csv_fields()
+            .map(|field| csv_field(&field));
+        writeln!(out, "{}", fields.join(","))?;
+    }
+    out.flush()
+}
+
+// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DEFAULT_STAT_GROUPS;
+    use chrono::TimeZone;
+
+    fn render(format: OutputFormat, indices: &[IndexRate]) -> String {
+        render_groups(format, indices, &DEFAULT_STAT_GROUPS)
+    }
+
+    fn render_groups(format: OutputFormat, indices: &[IndexRate], groups: &[StatGroup]) -> String {
+        let refs: Vec<&IndexRate> = indices.iter().collect();
+        let at = Utc.with_ymd_and_hms(2024, 2, 1, 12, 0, 0).unwrap();
+        let mut out = Vec::new();
+        write_indices(&mut out, format, &refs, groups, at).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_csv_matches_columns_and_quotes() {
+        let index = IndexRate {
+            doc_count: 10,
+            rate_per_sec: 2.5,
+            size_bytes: 100,
+            total_size_bytes: 200,
+            creation_date: Some(Utc.with_ymd_and_hms(2024, 1, 31, 8, 0, 0).unwrap()),
+            ..IndexRate::named("logs,2024")
+        };
+        let csv = render(OutputFormat::Csv, &[index]);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_COLUMNS.join(",").as_str()));
+        assert_eq!(
+            lines.next(),
+            Some("2024-02-01T12:00:00Z,\"logs,2024\",10,2.5,100,200,green,0,2024-01-31T08:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_ndjson_one_line_per_index() {
+        let ndjson = render(
+            OutputFormat::Ndjson,
+            &[IndexRate::named("a"), IndexRate::named("b")],
+        );
+        let lines: Vec<serde_json::Value> = ndjson
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["name"], "b");
+        assert_eq!(lines[0]["timestamp"], "2024-02-01T12:00:00Z");
+
+        // JSON carries one key per column
+        let json: serde_json::Value =
+            serde_json::from_str(&render(OutputFormat::Json, &[IndexRate::named("a")])).unwrap();
+        let keys: Vec<&str> = json[0]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        assert_eq!(keys.len(), CSV_COLUMNS.len());
+    }
+
+    #[test]
+    fn test_unfetched_groups_export_as_missing() {
+        let groups = [StatGroup::Docs];
+        let indices = [IndexRate {
+            doc_count: 10,
+            rate_per_sec: 2.5,
+            size_bytes: 100,
+            ..IndexRate::named("logs")
+        }];
+        let csv = render_groups(OutputFormat::Csv, &indices, &groups);
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("2024-02-01T12:00:00Z,logs,10,-,-,-,green,-,")
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_groups(OutputFormat::Json, &indices, &groups)).unwrap();
+        assert_eq!(json[0]["doc_count"], 10);
+        assert!(json[0]["rate_per_sec"].is_null());
+        assert!(json[0]["size_bytes"].is_null());
+    }
+}
@@ -1,23 +1,38 @@
 mod app;
+mod config;
 mod elasticsearch;
 mod error;
+mod export;
 mod models;
 mod ui;
 mod utils;
 
+use std::io::Write;
 use std::path::PathBuf;
 use std::time::Duration;
 
 use anyhow::Result;
 use clap::Parser;
-use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::clipboard::CopyToClipboard;
+use crossterm::event::{
+    self, DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange,
+    Event, KeyCode, KeyEventKind, KeyModifiers,
+};
+use crossterm::execute;
 use ratatui::DefaultTerminal;
 use tui_input::backend::crossterm::EventHandler;
+use url::Url;
 
 use app::actions::Action;
-use app::App;
+use app::{App, AppConfig};
+use config::ConfigFile;
 use elasticsearch::AuthConfig;
-use ui::types::Colormap;
+use export::OutputFormat;
+use models::{StatGroup, DEFAULT_STAT_GROUPS};
+use ui::types::{
+    Colormap, GradientScale, RateUnit, RowLayout, SpinnerStyle, StatusIndicator, ThemeMode,
+    Timezone, View,
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "esticli")]
@@ -47,23 +62,242 @@ struct Args {
     #[arg(long, value_name = "FILE")]
     ca_cert: Option<PathBuf>,
 
+    // TOML config file (default: ~/.config/esticli/config.toml, if present)
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    // Seconds to wait for the TCP/TLS connection to Elasticsearch
+    #[arg(long, value_name = "SECS", default_value = "5")]
+    connect_timeout: u64,
+
+    // Seconds to wait for a whole request, including reading the response
+    #[arg(long, value_name = "SECS", default_value = "30")]
+    timeout: u64,
+
+    // Bytes of an Elasticsearch error response to keep; longer bodies are truncated
+    #[arg(long, value_name = "BYTES", default_value = "4096")]
+    max_error_body: usize,
+
     // Refresh interval in seconds
     #[arg(long, default_value = "5")]
     refresh: u64,
 
     // Colormap for gradient visualization
-    // Options: turbo, spectral, inferno, magma, plasma, viridis, rainbow, cividis, warm, cool
+    // Options: turbo, spectral, inferno, magma, plasma, plasma-reverse, viridis, rainbow, cividis, warm, cool,
+    // greyscale, custom (stops from the config file)
     #[arg(long, default_value = "warm")]
     colormap: Colormap,
 
     // Number of samples to average for rate calculation
     #[arg(long, default_value = "10")]
     rate_samples: usize,
+
+    // Color theme: auto (detect terminal background), dark, light
+    #[arg(long, default_value = "auto")]
+    theme: ThemeMode,
+
+    // Number of points in the chart's moving-average overlay
+    #[arg(long, default_value = "5")]
+    avg_window: usize,
+
+    // Timezone for displayed timestamps: local, utc, or an IANA name (e.g. Europe/Lisbon)
+    #[arg(long, default_value = "local")]
+    timezone: Timezone,
+
+    // Show rates per second (sec) or per minute (min), for low-volume clusters
+    #[arg(long, value_name = "UNIT", default_value = "sec")]
+    rate_unit: RateUnit,
+
+    // How values map onto the row color gradient: log, linear, or sqrt
+    #[arg(long, value_name = "SCALE", default_value = "log")]
+    gradient_scale: GradientScale,
+
+    // Loading spinner and health icon style: braille, ascii, dots (use ascii on limited terminals)
+    #[arg(long, default_value = "braille")]
+    spinner: SpinnerStyle,
+
+    // Fetch status in the indices title: icon (spinner/idle glyph) or text ("loading"/"idle")
+    #[arg(long, value_name = "KIND", default_value = "icon")]
+    status_indicator: StatusIndicator,
+
+    // Arrangement of the graph and health widgets: horizontal (side by side) or vertical (stacked)
+    #[arg(long, default_value = "horizontal")]
+    layout: RowLayout,
+
+    // Single-line header and footer with the graph hidden, leaving more room for the table
+    #[arg(long)]
+    compact: bool,
+
+    // Show sizes including replicas instead of primaries only (toggle with S)
+    #[arg(long)]
+    total_size: bool,
+
+    // Start focused on a single index (rate/docs history, shards, ILM)
+    #[arg(long, value_name = "INDEX")]
+    watch: Option<String>,
+
+    // Ring the terminal bell and flash the header when cluster health worsens
+    #[arg(long)]
+    alert: bool,
+
+    // Shell command run on an alert, with the new health status as its argument
+    #[arg(long, value_name = "CMD", requires = "alert")]
+    alert_command: Option<String>,
+
+    // Also alert when cluster health improves
+    #[arg(long, requires = "alert")]
+    alert_recovery: bool,
+
+    // URL that receives a JSON POST when cluster health worsens (e.g. a Slack webhook)
+    #[arg(long, value_name = "URL")]
+    webhook_url: Option<Url>,
+
+    // Glob for indices treated as system indices, repeatable (default: ".*")
+    #[arg(long = "system-pattern", value_name = "GLOB")]
+    system_patterns: Vec<String>,
+
+    // Only show indices matching this glob, repeatable (e.g. "logs-*")
+    #[arg(long = "include", value_name = "GLOB")]
+    include_patterns: Vec<String>,
+
+    // Hide indices matching this glob, repeatable
+    #[arg(long = "exclude", value_name = "GLOB")]
+    exclude_patterns: Vec<String>,
+
+    // Index ages separating the color tiers in age color mode (toggle with o)
+    #[arg(
+        long,
+        value_name = "DURATIONS",
+        value_delimiter = ',',
+        default_value = "1d,7d",
+        value_parser = parse_age_tier
+    )]
+    age_tiers: Vec<chrono::Duration>,
+
+    // Only fetch stats for indices matching this pattern, e.g. "logs-*" (comma-separated for several)
+    #[arg(long = "index", value_name = "PATTERN", value_parser = parse_index_pattern)]
+    index_pattern: Option<String>,
+
+    // `_stats` groups fetched on each refresh; columns needing the others show "-"
+    #[arg(
+        long = "stats",
+        value_name = "GROUPS",
+        value_delimiter = ',',
+        default_values_t = DEFAULT_STAT_GROUPS
+    )]
+    stat_groups: Vec<StatGroup>,
+
+    // Characters after which a digit starts the date/generation suffix grouped by rollup (u)
+    #[arg(long, value_name = "CHARS", default_value = "-_.")]
+    rollup_separators: String,
+
+    // Most index details requests sent at once (default: all), to limit load on small clusters
+    #[arg(long, value_name = "N")]
+    details_concurrency: Option<std::num::NonZeroUsize>,
+
+    // Leave shard allocation out of index details until s is pressed, for indices with many shards
+    #[arg(long)]
+    lazy_shards: bool,
+
+    // Write each raw JSON response to a timestamped file in this directory, for bug reports.
+    // Only response bodies are written; request headers (e.g. Authorization) never are.
+    #[arg(long, value_name = "DIR")]
+    dump_responses: Option<PathBuf>,
+
+    // Poll node JVM heap and warn in the header when a node reaches PERCENT (default 85)
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        num_args = 0..=1,
+        default_missing_value = "85",
+        value_parser = clap::value_parser!(u64).range(1..=100)
+    )]
+    heap_warning: Option<u64>,
+
+    // Allow changes to the cluster (E: replicas, O: data stream rollover); read-only otherwise
+    #[arg(long)]
+    allow_destructive: bool,
+
+    // Write a CSV of every index (same columns as --output csv) to FILE every --snapshot-interval
+    #[arg(long, value_name = "FILE")]
+    snapshot_export: Option<PathBuf>,
+
+    // Seconds between --snapshot-export writes, independent of the refresh interval
+    #[arg(long, value_name = "SECS", default_value_t = 86400, requires = "snapshot_export",
+          value_parser = clap::value_parser!(u64).range(1..))]
+    snapshot_interval: u64,
+
+    // Append each snapshot to the --snapshot-export file instead of overwriting it
+    #[arg(long, requires = "snapshot_export")]
+    snapshot_append: bool,
+
+    // Write the key bindings and jq filter reference to esticli-help.md and exit
+    #[arg(long)]
+    dump_help: bool,
+
+    // Print the index list once in this format (json, ndjson, csv) instead of starting the TUI
+    #[arg(long, value_name = "FORMAT")]
+    output: Option<OutputFormat>,
+
+    // Shorthand for --output json
+    #[arg(long, conflicts_with = "output")]
+    json: bool,
+
+    // Stop refreshing while the terminal is in the background, resuming when it regains focus
+    #[arg(long)]
+    pause_on_blur: bool,
+}
+
+// Reject characters Elasticsearch never allows in index names or that would break the URL path
+fn parse_index_pattern(value: &str) -> std::result::Result<String, String> {
+    const INVALID: &[char] = &['\\', '/', '?', '"', '<', '>', '|', ' ', '#'];
+    if value.is_empty() || value.split(',').any(str::is_empty) {
+        return Err("index pattern must not be empty".to_string());
+    }
+    if let Some(c) = value.chars().find(|c| INVALID.contains(c)) {
+        return Err(format!("index pattern must not contain '{}'", c));
+    }
+    Ok(value.to_string())
 }
 
+fn parse_age_tier(value: &str) -> std::result::Result<chrono::Duration, String> {
+    utils::parse_es_duration(value)
+        .ok_or_else(|| format!("invalid duration '{}', expected e.g. 12h or 7d", value))
+}
+
+// Written to the working directory by `--dump-help`
+const HELP_FILE: &str = "esticli-help.md";
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
+    if args.dump_help {
+        std::fs::write(HELP_FILE, ui::help_popup::help_markdown())
+            .map_err(|e| anyhow::anyhow!("Can't write {}: {}", HELP_FILE, e))?;
+        println!("Wrote {}", HELP_FILE);
+        return Ok(());
+    }
+    let config = ConfigFile::load(args.config.as_deref())?;
+    if let Some(ref stops) = config.colormaps.custom {
+        ui::types::set_custom_gradient(stops).map_err(anyhow::Error::msg)?;
+    }
+    let column_colormaps = config.colormaps.entries();
+    if std::iter::once(args.colormap)
+        .chain(column_colormaps.iter().map(|(_, colormap)| *colormap))
+        .any(|colormap| !colormap.is_available())
+    {
+        anyhow::bail!("The 'custom' colormap needs `custom = [...]` color stops under [colormaps] in the config file");
+    }
+
+    if let Some(ref dir) = args.dump_responses {
+        std::fs::create_dir_all(dir).map_err(|e| {
+            anyhow::anyhow!(
+                "Can't create --dump-responses directory {}: {}",
+                dir.display(),
+                e
+            )
+        })?;
+    }
 
     let auth = if let Some(api_key) = args.api_key {
         AuthConfig::ApiKey(api_key)
@@ -73,23 +307,107 @@ async fn main() -> Result<()> {
         AuthConfig::None
     };
 
-    let mut app = App::new(
-        args.url,
+    let output = args.output.or(args.json.then_some(OutputFormat::Json));
+
+    // Detect the theme before the terminal switches to the alternate screen; one-shot
+    // output never draws, so it skips the terminal query
+    let theme = match output {
+        Some(_) => ui::theme::Theme::DARK,
+        None => ui::theme::resolve(args.theme),
+    };
+
+    let mut app = App::new(AppConfig {
+        base_url: args.url,
         auth,
-        args.insecure,
-        args.ca_cert,
-        args.refresh,
-        args.colormap,
-        args.rate_samples,
-    )?;
+        insecure: args.insecure,
+        ca_cert: args.ca_cert,
+        refresh_secs: args.refresh,
+        connect_timeout_secs: args.connect_timeout,
+        timeout_secs: args.timeout,
+        max_error_body: args.max_error_body,
+        colormap: args.colormap,
+        column_colormaps: column_colormaps.into_iter().collect(),
+        column_widths: config.column_widths.entries(),
+        // One-shot output only has a single rate sample per index
+        rate_samples: if output.is_some() {
+            1
+        } else {
+            args.rate_samples
+        },
+        theme,
+        avg_window: args.avg_window,
+        timezone: args.timezone,
+        rate_unit: args.rate_unit,
+        gradient_scale: args.gradient_scale,
+        spinner_style: args.spinner,
+        status_indicator: args.status_indicator,
+        selection_style: config.selection_style.unwrap_or_default(),
+        zebra: config.zebra,
+        row_layout: args.layout,
+        compact: args.compact,
+        total_size: args.total_size,
+        watch: args.watch,
+        alert: args.alert,
+        alert_command: args.alert_command,
+        alert_recovery: args.alert_recovery,
+        webhook_url: args.webhook_url,
+        system_patterns: args.system_patterns,
+        include_patterns: args.include_patterns,
+        exclude_patterns: args.exclude_patterns,
+        age_tiers: args.age_tiers,
+        index_pattern: args.index_pattern,
+        stat_groups: args.stat_groups,
+        rollup_separators: args.rollup_separators,
+        details_concurrency: args.details_concurrency,
+        lazy_shards: args.lazy_shards,
+        dump_dir: args.dump_responses,
+        heap_warning: args.heap_warning,
+        allow_destructive: args.allow_destructive,
+        snapshot_export: args.snapshot_export,
+        snapshot_interval: Duration::from_secs(args.snapshot_interval),
+        snapshot_append: args.snapshot_append,
+        pause_on_blur: args.pause_on_blur,
+        name_transforms: config.name_transforms,
+    })?;
+
+    if let Some(format) = output {
+        return print_once(&mut app, format, Duration::from_secs(args.refresh)).await;
+    }
 
     let terminal = ratatui::init();
+    // Pasted text arrives as one event instead of keystrokes, to fill the filter
+    let _ = execute!(std::io::stdout(), EnableBracketedPaste);
+    if app.pause_on_blur {
+        let _ = execute!(std::io::stdout(), EnableFocusChange);
+    }
     let result = run(terminal, &mut app).await;
+    if app.pause_on_blur {
+        let _ = execute!(std::io::stdout(), DisableFocusChange);
+    }
+    let _ = execute!(std::io::stdout(), DisableBracketedPaste);
     ratatui::restore();
 
     result
 }
 
+// Fetches twice, `interval` apart, so rates reflect real traffic, then prints the filtered indices
+async fn print_once(app: &mut App, format: OutputFormat, interval: Duration) -> Result<()> {
+    app.fetch_and_wait().await.map_err(anyhow::Error::msg)?;
+    tokio::time::sleep(interval).await;
+    app.fetch_and_wait().await.map_err(anyhow::Error::msg)?;
+
+    let indices = app.filtered_indices();
+    let stdout = std::io::stdout().lock();
+    export::write_indices(
+        stdout,
+        format,
+        &indices,
+        &app.stat_groups,
+        chrono::Utc::now(),
+    )?;
+    Ok(())
+}
+
 async fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
     // Initial data fetch
     app.start_fetch();
@@ -100,6 +418,26 @@ async fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
 
         // Poll for details results (non-blocking)
         app.poll_details_result();
+        app.poll_webhook_errors();
+
+        // Periodic CSV snapshot, on its own timer
+        app.run_auto_export();
+
+        // Compile the filter once typing pauses
+        app.filter.recompile_if_idle();
+
+        if app.alert.take_bell() {
+            let mut stdout = std::io::stdout();
+            stdout.write_all(b"\x07")?;
+            stdout.flush()?;
+        }
+
+        // OSC 52 hands the copy to the terminal, which may silently ignore it
+        if let Some(text) = app.clipboard.take() {
+            if let Err(e) = execute!(std::io::stdout(), CopyToClipboard::to_clipboard_from(text)) {
+                app.set_status(format!("Clipboard unavailable: {}", e));
+            }
+        }
 
         // Advance spinner animation
         app.tick_spinner();
@@ -108,10 +446,12 @@ async fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
 
         // Poll for keyboard events with a short timeout
         if event::poll(Duration::from_millis(50))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     if let Some(action) = map_key_to_action(app, key) {
                         app.handle_action(action);
+                    } else if let Some(ref mut prompt) = app.replica_prompt {
+                        prompt.input.handle_event(&Event::Key(key));
                     } else if app.filter.active {
                         // Filter mode special handling for text input
                         match key.code {
@@ -120,11 +460,15 @@ async fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
                             }
                             _ => {
                                 app.filter.input.handle_event(&Event::Key(key));
-                                app.filter.recompile();
+                                app.filter.mark_edited();
                             }
                         }
                     }
                 }
+                Event::Paste(text) if !overlay_open(app) => app.paste_filter(&text),
+                Event::FocusLost => app.focus_lost(),
+                Event::FocusGained => app.focus_gained(),
+                _ => {}
             }
         }
 
@@ -137,6 +481,16 @@ async fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
     Ok(())
 }
 
+// Popups and the watch screen take keys themselves, so a paste there is dropped
+fn overlay_open(app: &App) -> bool {
+    app.show_help_popup
+        || app.details.show_popup
+        || app.index_chart.is_some()
+        || app.text_popup.show_popup
+        || app.watch.is_some()
+        || app.replica_prompt.is_some()
+}
+
 fn map_key_to_action(app: &App, key: event::KeyEvent) -> Option<Action> {
     if app.show_help_popup {
         return match key.code {
@@ -151,7 +505,18 @@ fn map_key_to_action(app: &App, key: event::KeyEvent) -> Option<Action> {
 
     if app.details.show_popup {
         return match key.code {
+            // Esc steps back from full-screen to the overlay before closing
+            KeyCode::Esc if app.details.fullscreen => Some(Action::ToggleDetailsFullscreen),
             KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => Some(Action::CloseDetails),
+            KeyCode::Char('f') => Some(Action::ToggleDetailsFullscreen),
+            KeyCode::Char('e') => Some(Action::ExportDetails),
+            // Only offered for indices backing a data stream
+            KeyCode::Char('O') if app.details_data_stream().is_some() => {
+                Some(Action::RolloverDataStream)
+            }
+            KeyCode::Char('s') => Some(Action::LoadShards),
+            KeyCode::Char('n') => Some(Action::NextUnhealthyDetails),
+            KeyCode::Char('p') => Some(Action::PrevUnhealthyDetails),
             KeyCode::Up | KeyCode::Char('k') => Some(Action::DetailsScrollUp),
             KeyCode::Down | KeyCode::Char('j') => Some(Action::DetailsScrollDown),
             KeyCode::PageUp => Some(Action::DetailsScrollPageUp),
@@ -160,11 +525,68 @@ fn map_key_to_action(app: &App, key: event::KeyEvent) -> Option<Action> {
         };
     }
 
+    if app.index_chart.is_some() {
+        return match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') | KeyCode::Char('t') => {
+                Some(Action::CloseIndexChart)
+            }
+            KeyCode::Char('a') => Some(Action::ToggleMovingAverage),
+            _ => None,
+        };
+    }
+
+    if app.text_popup.show_popup {
+        return match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => Some(Action::CloseTextPopup),
+            KeyCode::Up | KeyCode::Char('k') => Some(Action::TextPopupScrollUp),
+            KeyCode::Down | KeyCode::Char('j') => Some(Action::TextPopupScrollDown),
+            KeyCode::PageUp => Some(Action::TextPopupScrollPageUp),
+            KeyCode::PageDown => Some(Action::TextPopupScrollPageDown),
+            _ => None,
+        };
+    }
+
+    if app.watch.is_some() {
+        return match key.code {
+            KeyCode::Esc => Some(Action::StopWatch),
+            KeyCode::Char('q') => Some(Action::Quit),
+            KeyCode::Char('?') => Some(Action::ToggleHelp),
+            KeyCode::Char(' ') => Some(Action::TogglePause),
+            KeyCode::Char('a') => Some(Action::ToggleMovingAverage),
+            KeyCode::Char('m') => Some(Action::CycleChartMetric),
+            KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Action::ClearHistory)
+            }
+            KeyCode::Char('s') => Some(Action::LoadShards),
+            KeyCode::Up | KeyCode::Char('k') => Some(Action::WatchScrollUp),
+            KeyCode::Down | KeyCode::Char('j') => Some(Action::WatchScrollDown),
+            _ => None,
+        };
+    }
+
+    if app.replica_prompt.is_some() {
+        return match key.code {
+            KeyCode::Enter => Some(Action::SubmitReplicas),
+            KeyCode::Esc => Some(Action::CancelReplicaPrompt),
+            // Other keys edit the count in the run loop
+            _ => None,
+        };
+    }
+
     if app.filter.active {
         return match key.code {
             KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 Some(Action::ClearFilter)
             }
+            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Action::CopyFilter)
+            }
+            // Only while the filter is invalid; otherwise Ctrl+e stays end-of-line for the input
+            KeyCode::Char('e')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && app.filter.error.is_some() =>
+            {
+                Some(Action::ShowFilterError)
+            }
             // Other keys handled by input component in run loop
             _ => None,
         };
@@ -173,22 +595,67 @@ fn map_key_to_action(app: &App, key: event::KeyEvent) -> Option<Action> {
     match key.code {
         KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
         KeyCode::Char('?') => Some(Action::ToggleHelp),
+        KeyCode::Tab => Some(Action::NextView),
+        KeyCode::BackTab => Some(Action::PrevView),
+        // Plain digits toggle panels, so views are numbered on F-keys and Alt+digit
+        KeyCode::F(n) => View::from_number(n as usize).map(Action::SelectView),
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::ALT) && c.is_ascii_digit() => {
+            View::from_number(c.to_digit(10)? as usize).map(Action::SelectView)
+        }
         KeyCode::Char(' ') => Some(Action::TogglePause),
+        KeyCode::Char('p') => Some(Action::PauseChart),
+        KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(Action::ClearHistory)
+        }
         KeyCode::Char('/') => Some(Action::EnterFilterMode),
+        KeyCode::Char('y') => Some(Action::CopyFilter),
+        KeyCode::Char('F') if app.filter.error.is_some() => Some(Action::ShowFilterError),
         KeyCode::Enter => Some(Action::ShowDetails),
         KeyCode::Char('x') => Some(Action::ToggleExclude),
+        KeyCode::Char('P') => Some(Action::TogglePin),
         KeyCode::Char('X') => Some(Action::ClearExclusions),
+        KeyCode::Char('M') => Some(Action::MarkSnapshot),
+        KeyCode::Char('w') => Some(Action::WatchSelected),
+        KeyCode::Char('t') => Some(Action::ShowIndexChart),
+        KeyCode::Char('T') => Some(Action::ShowHotThreads),
+        KeyCode::Char('A') => Some(Action::ExplainAllocation),
+        KeyCode::Char('I') => Some(Action::ShowClusterInfo),
+        KeyCode::Char('E') => Some(Action::EditReplicas),
         KeyCode::Right | KeyCode::Char('l') => Some(Action::NextColumn),
         KeyCode::Left | KeyCode::Char('h') => Some(Action::PrevColumn),
+        KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(Action::UndoClearExclusions)
+        }
+        KeyCode::Char('z') => Some(Action::ToggleExcludedSection),
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(Action::RetryNow)
+        }
         KeyCode::Char('r') => Some(Action::ToggleSortOrder),
         KeyCode::Char('+') | KeyCode::Char('=') => Some(Action::DecreaseRefreshRate),
         KeyCode::Char('-') | KeyCode::Char('_') => Some(Action::IncreaseRefreshRate),
+        KeyCode::Char('}') => Some(Action::IncreaseRateSamples),
+        KeyCode::Char('{') => Some(Action::DecreaseRateSamples),
         KeyCode::Char('1') => Some(Action::ToggleGraph),
+        KeyCode::Char('a') => Some(Action::ToggleMovingAverage),
+        KeyCode::Char('m') => Some(Action::CycleChartMetric),
         KeyCode::Char('2') => Some(Action::ToggleHealth),
         KeyCode::Char('3') => Some(Action::ToggleIndices),
+        KeyCode::Char('>') if app.chart_split_adjustable() => Some(Action::GrowChart),
+        KeyCode::Char('<') if app.chart_split_adjustable() => Some(Action::ShrinkChart),
+        KeyCode::Char('L') => Some(Action::WidenColumn),
+        KeyCode::Char('H') => Some(Action::NarrowColumn),
+        KeyCode::Char('v') => Some(Action::ToggleRowLayout),
         KeyCode::Char('.') => Some(Action::ToggleSystemIndices),
+        KeyCode::Char(',') => Some(Action::ToggleSystemInList),
+        KeyCode::Char(';') => Some(Action::ToggleSystemInRate),
         KeyCode::Char('c') => Some(Action::NextColormap),
         KeyCode::Char('C') => Some(Action::PrevColormap),
+        KeyCode::Char('i') => Some(Action::ToggleGradientDirection),
+        KeyCode::Char('S') => Some(Action::ToggleSizeBasis),
+        KeyCode::Char('B') => Some(Action::ToggleRawBytes),
+        KeyCode::Char('o') => Some(Action::CycleColorMode),
+        KeyCode::Char('u') => Some(Action::ToggleRollup),
+        KeyCode::Char('!') => Some(Action::ToggleUnhealthyFirst),
         KeyCode::Up | KeyCode::Char('k') => Some(Action::SelectUp),
         KeyCode::Down | KeyCode::Char('j') => Some(Action::SelectDown),
         KeyCode::PageUp | KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -201,6 +668,8 @@ fn map_key_to_action(app: &App, key: event::KeyEvent) -> Option<Action> {
         KeyCode::PageDown => Some(Action::SelectPageDown),
         KeyCode::Home | KeyCode::Char('g') => Some(Action::SelectFirst),
         KeyCode::End | KeyCode::Char('G') => Some(Action::SelectLast),
+        KeyCode::Char(']') => Some(Action::NextProblem),
+        KeyCode::Char('[') => Some(Action::PrevProblem),
         _ => None,
     }
 }
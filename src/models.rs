@@ -1,51 +1,223 @@
-use crate::utils::{format_bytes, format_number};
+use crate::ui::types::RateUnit;
+use crate::utils::format_number;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct IndexRate {
     pub name: String,
     pub doc_count: u64,
     pub rate_per_sec: f64,
+    /// Store size of the primaries only
     pub size_bytes: u64,
+    /// Store size including replicas
+    pub total_size_bytes: u64,
     pub health: String,
+    /// Indexing operations that failed, since the shards were started
+    pub index_failed: u64,
+    /// Failures since the previous refresh
+    pub failed_delta: u64,
+    /// Closed indices have no stats; they are listed so they don't silently vanish
+    pub closed: bool,
+    /// Shard copies of this index not allocated to any node
+    pub unassigned_shards: usize,
+    /// None when the settings couldn't be read
+    pub replicas: Option<Replicas>,
+    /// Average milliseconds per indexing op since the previous refresh; None without ops
+    pub index_latency_ms: Option<f64>,
+    /// Average milliseconds per query, across all shard copies; None without queries
+    pub query_latency_ms: Option<f64>,
+    /// Merges running right now, across all shard copies
+    pub merges_current: u64,
+    /// Milliseconds spent merging per second since the previous refresh
+    pub merge_time_rate: f64,
+    /// Lucene segments of the primaries; only read with the `segments` stats group
+    pub segment_count: u64,
+    /// Segments added (or merged away, when negative) since the previous refresh
+    pub segments_delta: i64,
+    pub creation_date: Option<DateTime<Utc>>,
 }
 
+#[cfg(test)]
 impl IndexRate {
-    pub fn size_human(&self) -> String {
-        format_bytes(self.size_bytes)
+    /// A green index without stats, for tests to fill in with struct update syntax
+    pub fn named(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            health: "green".to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl IndexRate {
+    /// Primary-only or total (primary + replica) store size
+    pub fn size(&self, total: bool) -> u64 {
+        if total {
+            self.total_size_bytes
+        } else {
+            self.size_bytes
+        }
+    }
+
+    pub fn rate_human(&self, unit: RateUnit) -> String {
+        format_number(unit.scale(self.rate_per_sec))
     }
 
-    pub fn rate_human(&self) -> String {
-        format_number(self.rate_per_sec)
+    /// Yellow or red; closed indices have no health to speak of
+    pub fn is_unhealthy(&self) -> bool {
+        self.health != "green" && !self.closed
     }
 
     pub fn doc_count_human(&self) -> String {
         format_number(self.doc_count as f64)
     }
+
+    pub fn index_latency_human(&self) -> String {
+        latency_human(self.index_latency_ms)
+    }
+
+    pub fn query_latency_human(&self) -> String {
+        latency_human(self.query_latency_ms)
+    }
+}
+
+fn latency_human(ms: Option<f64>) -> String {
+    ms.map_or_else(|| "-".to_string(), |ms| format!("{:.2} ms", ms))
+}
+
+/// Replica setting of an index: a fixed count, or an `auto_expand_replicas` range
+/// (e.g. `0-all`) resolved against the number of data nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Replicas {
+    Count(u32),
+    AutoExpand(String),
+}
+
+impl Replicas {
+    /// From the raw `number_of_replicas` and `auto_expand_replicas` settings; auto-expand
+    /// wins unless it is unset or `false`.
+    pub fn from_settings(number: Option<&str>, auto_expand: Option<&str>) -> Option<Self> {
+        match auto_expand {
+            Some(range) if !range.is_empty() && range != "false" => {
+                Some(Replicas::AutoExpand(range.to_string()))
+            }
+            _ => number?.parse().ok().map(Replicas::Count),
+        }
+    }
+
+    /// Fewest replicas the setting allows, then fixed before auto-expanding, for sorting
+    pub fn sort_key(&self) -> (u32, bool) {
+        match self {
+            Replicas::Count(count) => (*count, false),
+            Replicas::AutoExpand(range) => {
+                let min = range.split('-').next().and_then(|min| min.parse().ok());
+                (min.unwrap_or(0), true)
+            }
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+impl fmt::Display for Replicas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Replicas::Count(count) => write!(f, "{}", count),
+            Replicas::AutoExpand(range) => write!(f, "{}", range),
+        }
+    }
+}
+
+/// Metric group of the `_stats` API requested by the main refresh (`--stats`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatGroup {
+    Indexing,
+    Docs,
+    Store,
+    Search,
+    Merge,
+    Segments,
+}
+
+/// What the main refresh requests without `--stats`: everything but `segments`, which
+/// only feeds the live segment line of the details popup
+pub const DEFAULT_STAT_GROUPS: [StatGroup; 5] = [
+    StatGroup::Indexing,
+    StatGroup::Docs,
+    StatGroup::Store,
+    StatGroup::Search,
+    StatGroup::Merge,
+];
+
+impl fmt::Display for StatGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatGroup::Indexing => write!(f, "indexing"),
+            StatGroup::Docs => write!(f, "docs"),
+            StatGroup::Store => write!(f, "store"),
+            StatGroup::Search => write!(f, "search"),
+            StatGroup::Merge => write!(f, "merge"),
+            StatGroup::Segments => write!(f, "segments"),
+        }
+    }
+}
+
+impl FromStr for StatGroup {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "indexing" => Ok(StatGroup::Indexing),
+            "docs" => Ok(StatGroup::Docs),
+            "store" => Ok(StatGroup::Store),
+            "search" => Ok(StatGroup::Search),
+            "merge" => Ok(StatGroup::Merge),
+            "segments" => Ok(StatGroup::Segments),
+            _ => Err(format!(
+                "Unknown stats group '{}'. Available: indexing, docs, store, search, merge, segments",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct IndexSnapshot {
     pub doc_count: u64,
     pub index_total: u64,
+    pub index_time_in_millis: u64,
+    pub query_total: u64,
+    pub query_time_in_millis: u64,
+    pub merges_current: u64,
+    pub merge_time_in_millis: u64,
+    pub segment_count: u64,
+    pub index_failed: u64,
     pub size_bytes: u64,
+    pub total_size_bytes: u64,
     pub health: String,
 }
 
 // Detailed index information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct IndexDetails {
     pub name: String,
     pub provided_name: Option<String>,
-    pub creation_date: Option<String>,
+    pub creation_date: Option<DateTime<Utc>>,
     pub primary_shards: u32,
-    pub replica_shards: u32,
+    pub replicas: Option<Replicas>,
     pub is_frozen: bool,
     pub is_partial: bool,
     pub ilm_policy: Option<String>,
     pub ilm_phase: Option<String>,
     pub total_segments: u64,
-    pub shard_allocation: Vec<ShardInfo>,
+    /// Uncommitted translog on the primaries
+    pub translog_size_bytes: u64,
+    pub translog_ops: u64,
+    /// None until loaded when shards are fetched on demand (`--lazy-shards`)
+    pub shard_allocation: Option<Vec<ShardInfo>>,
     pub templates: Vec<String>,
     pub uuid: Option<String>,
     pub health: Option<String>,
@@ -54,9 +226,28 @@ pub struct IndexDetails {
     pub rate_per_sec: f64,
     pub size_bytes: u64,
     pub data_stream: Option<DataStreamDetails>,
+    pub query_cache: Option<CacheStats>,
+    pub request_cache: Option<CacheStats>,
+    /// Unprocessed API responses the details were built from, keyed by endpoint
+    pub raw_responses: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CacheStats {
+    pub memory_bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Share of lookups served from the cache, or `None` before any lookups.
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ShardInfo {
     pub shard_id: u32,
     pub primary: bool,
@@ -66,7 +257,7 @@ pub struct ShardInfo {
     pub size: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DataStreamDetails {
     pub name: String,
     pub timestamp_field: String,
@@ -76,7 +267,40 @@ pub struct DataStreamDetails {
     pub is_write_index: bool,
     pub template: Option<String>,
     pub data_retention: Option<String>,
+    pub rollover: Option<RolloverConditions>,
+}
+
+/// Rollover conditions from the hot phase of the write index's ILM policy.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RolloverConditions {
+    pub max_docs: Option<u64>,
+    /// As written in the policy, e.g. `30d`
+    pub max_age: Option<String>,
+}
+/// Shards assigned to one node, across all indices.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeShards {
+    pub node: String,
+    pub shards: usize,
+    pub primaries: usize,
+}
+
+/// Cluster-wide shard placement, aggregated from `_cat/shards`.
+#[derive(Debug, Clone, Default)]
+pub struct ShardDistribution {
+    /// Sorted by node name
+    pub nodes: Vec<NodeShards>,
+    pub unassigned: usize,
+    pub unassigned_by_index: HashMap<String, usize>,
+}
+
+/// JVM heap usage of one node, from `_nodes/stats/jvm`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeHeap {
+    pub node: String,
+    pub heap_used_percent: u64,
 }
+
 #[derive(Debug, Clone, Default)]
 pub struct ClusterHealth {
     pub cluster_name: String,
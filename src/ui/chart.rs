@@ -1,77 +1,189 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
-    text::Line,
-    widgets::{Bar, BarChart, BarGroup, Block, Borders, Widget},
+    style::{Color, Modifier, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Widget},
 };
 
-use super::theme;
 use crate::app::App;
-use crate::utils::format_number;
+use crate::ui::types::ChartMetric;
 
 pub struct RateChart<'a> {
     app: &'a App,
+    // What the series describes, shown in the title
+    subject: &'a str,
+    metric: ChartMetric,
+    history: Vec<u64>,
+    // Marked in the title and border while the cluster history is paused
+    frozen: bool,
 }
 
 impl<'a> RateChart<'a> {
     pub fn new(app: &'a App) -> Self {
-        Self { app }
+        Self {
+            app,
+            subject: "Cluster",
+            metric: app.chart_metric,
+            history: app.chart_history_vec(),
+            frozen: app.chart_paused,
+        }
     }
+
+    pub fn for_index(app: &'a App, index: &'a str, history: Vec<u64>) -> Self {
+        Self {
+            app,
+            subject: index,
+            metric: app.chart_metric,
+            history,
+            frozen: false,
+        }
+    }
+
+    // Overrides the app's chart metric, for series that only exist for one metric
+    pub fn with_metric(mut self, metric: ChartMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+}
+
+// Place samples on a time axis where the newest point is at 0 and older ones are negative seconds
+fn history_points(history: &[u64], interval_secs: f64) -> Vec<(f64, f64)> {
+    let newest = history.len().saturating_sub(1) as f64;
+    history
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| ((i as f64 - newest) * interval_secs, value as f64))
+        .collect()
+}
+
+// Trailing average over up to `window` points, so the line starts with the first sample
+fn moving_average(points: &[(f64, f64)], window: usize) -> Vec<(f64, f64)> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, _))| {
+            let start = (i + 1).saturating_sub(window);
+            let slice = &points[start..=i];
+            let sum: f64 = slice.iter().map(|&(_, y)| y).sum();
+            (x, sum / slice.len() as f64)
+        })
+        .collect()
 }
 
 impl<'a> Widget for RateChart<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let history = self.app.rate_history_vec();
+        let metric = self.metric;
+        let history = self.history;
+        // History is stored per second; rates are drawn in the display unit
+        let unit = self.app.rate_unit;
 
         // Calculate max for display
-        let max_rate = history.iter().max().copied().unwrap_or(1);
-        let current_rate = history.last().copied().unwrap_or(0);
-
-        let title = format!(
-            " Cluster Indexing Rate History (current: {} /s, max: {} /s) ",
-            format_number(current_rate as f64),
-            format_number(max_rate as f64)
+        let max_value = metric.scale(
+            history.iter().max().copied().unwrap_or(1).max(1) as f64,
+            unit,
         );
+        let current_value = metric.scale(history.last().copied().unwrap_or(0) as f64, unit);
 
-        // Calculate how many bars we can fit based on available width
-        let available_width = area.width.saturating_sub(2) as usize; // Account for borders
-        let bar_width = 6_u16;
-        let gap = 1_u16;
-        let chars_per_bar = (bar_width + gap) as usize;
-        let max_bars = available_width / chars_per_bar.max(1);
-
-        // Take only the most recent N values that fit
-        let visible_history: Vec<u64> = if history.len() > max_bars {
-            history[history.len() - max_bars..].to_vec()
-        } else {
-            history.clone()
-        };
-
-        // Create bars with rate labels
-        let bars: Vec<Bar> = visible_history
-            .iter()
-            .map(|&value| {
-                let label = format_number(value as f64);
-                Bar::default()
-                    .value(value)
-                    .label(Line::from(label))
-                    .style(Style::new().fg(Color::Green))
-            })
+        let mut title = vec![Span::raw(format!(
+            " {} {} History (current: {}, max: {}) ",
+            self.subject,
+            metric.title(),
+            metric.format_with_unit(current_value, unit),
+            metric.format_with_unit(max_value, unit)
+        ))];
+        let frozen_style = Style::new().fg(Color::Yellow);
+        if self.frozen {
+            title.push(Span::styled(
+                "❄ FROZEN ",
+                frozen_style.add_modifier(Modifier::BOLD),
+            ));
+        }
+        if self.app.history_just_cleared() {
+            title.push(Span::styled(
+                "✓ History cleared ",
+                Style::new().fg(Color::Green).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        let interval_secs = self.app.refresh_interval.as_secs_f64();
+        let points: Vec<(f64, f64)> = history_points(&history, interval_secs)
+            .into_iter()
+            .map(|(x, y)| (x, metric.scale(y, unit)))
             .collect();
 
-        BarChart::default()
+        // Span at least one interval so the axis is valid before history accumulates
+        let window_secs =
+            (history.len().saturating_sub(1) as f64 * interval_secs).max(interval_secs);
+        let x_labels = [
+            format!("-{:.0}s", window_secs),
+            format!("-{:.0}s", window_secs / 2.0),
+            "now".to_string(),
+        ];
+        let y_labels = [
+            "0".to_string(),
+            metric.format_value(max_value / 2.0),
+            metric.format_value(max_value),
+        ];
+
+        let average = moving_average(&points, self.app.avg_window);
+
+        let mut datasets = vec![Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Bar)
+            .style(Style::new().fg(Color::Green))
+            .data(&points)];
+
+        if self.app.show_moving_average {
+            datasets.push(
+                Dataset::default()
+                    .name(format!("avg({})", self.app.avg_window))
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::new().fg(Color::Magenta))
+                    .data(&average),
+            );
+        }
+
+        Chart::new(datasets)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(theme::BORDER)
-                    .title(title),
+                    .border_style(if self.frozen {
+                        frozen_style
+                    } else {
+                        self.app.theme.border
+                    })
+                    .title(Line::from(title)),
+            )
+            .x_axis(
+                Axis::default()
+                    .style(self.app.theme.time)
+                    .bounds([-window_secs, 0.0])
+                    .labels(x_labels),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(self.app.theme.time)
+                    .bounds([0.0, max_value])
+                    .labels(y_labels),
             )
-            .data(BarGroup::default().bars(&bars))
-            .bar_width(bar_width)
-            .value_style(Style::new().bg(Color::Green))
-            .bar_gap(gap)
-            .max(max_rate)
             .render(area, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moving_average_trailing_window() {
+        let points = history_points(&[0, 10, 20, 30], 5.0);
+        let average = moving_average(&points, 2);
+
+        assert_eq!(average[0], (-15.0, 0.0));
+        assert_eq!(average[1], (-10.0, 5.0));
+        assert_eq!(average[3], (0.0, 25.0));
+    }
+}
@@ -0,0 +1,106 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Row, StatefulWidget, Table, TableState},
+};
+
+use crate::app::App;
+use crate::utils::{format_bytes, format_number};
+
+pub struct CompareTable<'a> {
+    app: &'a App,
+}
+
+impl<'a> CompareTable<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+// Prefix the magnitude with an explicit sign so growth and shrinkage read at a glance
+fn signed(delta: i64, format: impl Fn(u64) -> String) -> String {
+    let sign = if delta < 0 { "-" } else { "+" };
+    format!("{}{}", sign, format(delta.unsigned_abs()))
+}
+
+fn delta_style(delta: i64) -> Style {
+    match delta {
+        d if d > 0 => Style::new().fg(Color::Green),
+        d if d < 0 => Style::new().fg(Color::Red),
+        _ => Style::new().fg(Color::DarkGray),
+    }
+}
+
+impl<'a> StatefulWidget for CompareTable<'a> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let header = Row::new(["Index Name", "Docs Count", "Δ Docs", "Δ Size"])
+            .style(self.app.theme.table_header.add_modifier(Modifier::BOLD))
+            .height(1);
+
+        let (title, rows) = match self.app.snapshot {
+            Some(ref snapshot) => {
+                let title = format!(
+                    " Compare (since mark {}s ago) ",
+                    snapshot.taken_at.elapsed().as_secs()
+                );
+                let rows = self
+                    .app
+                    .filtered_indices()
+                    .into_iter()
+                    .map(|index| {
+                        let deltas = match snapshot.delta(index) {
+                            Some((docs, bytes)) => [
+                                Cell::from(signed(docs, |v| format_number(v as f64)))
+                                    .style(delta_style(docs)),
+                                Cell::from(signed(bytes, format_bytes)).style(delta_style(bytes)),
+                            ],
+                            None => [
+                                Cell::from("new").style(Style::new().fg(Color::Cyan)),
+                                Cell::from("new").style(Style::new().fg(Color::Cyan)),
+                            ],
+                        };
+                        let [docs_cell, size_cell] = deltas;
+                        Row::new([
+                            Cell::from(index.name.clone()).style(self.app.theme.text),
+                            Cell::from(index.doc_count_human()).style(self.app.theme.text),
+                            docs_cell,
+                            size_cell,
+                        ])
+                    })
+                    .collect();
+                (title, rows)
+            }
+            None => (
+                " Compare (press M to mark a snapshot) ".to_string(),
+                Vec::new(),
+            ),
+        };
+
+        let widths = [
+            Constraint::Percentage(55),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(self.app.theme.border)
+                    .title(Line::from(Span::raw(title))),
+            )
+            .row_highlight_style(
+                Style::new()
+                    .add_modifier(Modifier::REVERSED)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        StatefulWidget::render(table, area, buf, state);
+    }
+}
@@ -6,9 +6,10 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
 };
 
-use super::theme;
+use crate::app::details::DetailsState;
 use crate::app::App;
-use crate::utils::{format_bytes, format_number};
+use crate::models::{CacheStats, IndexDetails, Replicas, ShardInfo, StatGroup};
+use crate::utils::{format_bytes, format_duration, format_number, parse_es_duration};
 
 pub struct DetailsPopup<'a> {
     app: &'a App,
@@ -22,387 +23,933 @@ impl<'a> DetailsPopup<'a> {
 
 impl<'a> Widget for DetailsPopup<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        // Calculate popup area (80% width, 80% height, centered)
-        let popup_width = (area.width as f32 * 0.8) as u16;
-        let popup_height = (area.height as f32 * 0.8) as u16;
-        let popup_x = (area.width - popup_width) / 2;
-        let popup_y = (area.height - popup_height) / 2;
-
-        let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+        // Calculate popup area (whole screen in full-screen mode, else 80% centered,
+        // widened for a shard table that wouldn't fit)
+        let popup_area = if self.app.details.fullscreen {
+            area
+        } else {
+            let shard_width = self
+                .app
+                .details
+                .data
+                .as_ref()
+                .and_then(|details| details.shard_allocation.as_deref())
+                .map_or(0, shard_table_width);
+            let popup_width = popup_width(area.width, shard_width);
+            let popup_height = (area.height as f32 * 0.8) as u16;
+            let popup_x = (area.width - popup_width) / 2;
+            let popup_y = (area.height - popup_height) / 2;
+            Rect::new(popup_x, popup_y, popup_width, popup_height)
+        };
+        let popup_height = popup_area.height;
 
         // Clear the popup area
         Clear.render(popup_area, buf);
 
-        // Build content
-        let mut lines: Vec<Line> = Vec::new();
+        let lines = details_lines(self.app, &self.app.details);
 
-        if self.app.details.loading {
-            lines.push(Line::from(Span::styled(
-                "Loading index details...",
-                Style::new().fg(Color::Yellow),
-            )));
-        } else if let Some(ref error) = self.app.details.error {
-            lines.push(Line::from(Span::styled(
-                format!("Error: {}", error),
-                theme::ERROR,
-            )));
-        } else if let Some(ref details) = self.app.details.data {
-            // Index name as header
-            lines.push(Line::from(vec![
-                Span::styled("Index: ", Style::new().fg(Color::DarkGray)),
-                Span::styled(&details.name, theme::TITLE),
-            ]));
+        // Apply scroll offset
+        let visible_height = popup_height.saturating_sub(4) as usize; // Account for border and title
+        let max_scroll = lines.len().saturating_sub(visible_height);
+        let scroll = self.app.details.scroll.min(max_scroll);
 
-            // Show provided name if it exists
-            if let Some(ref provided_name) = details.provided_name {
-                lines.push(Line::from(vec![
-                    Span::styled("Provided Name: ", Style::new().fg(Color::DarkGray)),
-                    Span::styled(provided_name, theme::TITLE),
-                ]));
-            }
+        let mut title = Line::from(vec![
+            Span::raw(" Index Details "),
+            Span::styled(
+                if self.app.details.fullscreen {
+                    "[Esc] Overlay  [Enter] Close  [e] Export  [j/k] Scroll "
+                } else {
+                    "[Esc/Enter] Close  [f] Full screen  [e] Export  [j/k] Scroll "
+                },
+                Style::new().fg(Color::DarkGray),
+            ),
+        ]);
+        if self.app.allow_destructive && self.app.details_data_stream().is_some() {
+            title.push_span(Span::styled(
+                "[O] Rollover ",
+                Style::new().fg(Color::DarkGray),
+            ));
+        }
 
-            // UUID
-            if let Some(ref uuid) = details.uuid {
-                lines.push(Line::from(vec![
-                    Span::styled("UUID: ", Style::new().fg(Color::DarkGray)),
-                    Span::styled(uuid, Style::new().fg(Color::White)),
-                ]));
-            }
+        let mut block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::new().fg(Color::Cyan));
+        // Stepping through unhealthy indices with n/p
+        let (position, unhealthy) = self.app.unhealthy_position();
+        if unhealthy > 0 {
+            let position = position.map_or("-".to_string(), |p| (p + 1).to_string());
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" [n/p] Unhealthy {}/{} ", position, unhealthy),
+                    Style::new().fg(Color::Yellow),
+                ))
+                .right_aligned(),
+            );
+        }
+        if let Some(ref status) = self.app.details.status {
+            let (text, style) = match status {
+                Ok(text) => (text, Style::new().fg(Color::Green)),
+                Err(text) => (text, self.app.theme.error),
+            };
+            block = block.title_bottom(Span::styled(format!(" {} ", text), style));
+        }
 
-            lines.push(Line::from(""));
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((scroll as u16, 0))
+            .wrap(Wrap { trim: false })
+            .render(popup_area, buf);
+    }
+}
 
-            // Health and Status
-            let health_color = match details.health.as_deref() {
-                Some("green") => Color::Green,
-                Some("yellow") => Color::Yellow,
-                Some("red") => Color::Red,
-                _ => Color::DarkGray,
-            };
+/// Builds the details content for `state`, shared by the popup and the watch view.
+pub fn details_lines<'a>(app: &'a App, state: &'a DetailsState) -> Vec<Line<'a>> {
+    // Build content
+    let mut lines: Vec<Line> = Vec::new();
+    let text_color = app.theme.text.fg.unwrap_or(Color::Reset);
+
+    if state.loading {
+        lines.push(Line::from(Span::styled(
+            "Loading index details...",
+            Style::new().fg(Color::Yellow),
+        )));
+    } else if let Some(ref error) = state.error {
+        lines.push(Line::from(Span::styled(
+            format!("Error: {}", error),
+            app.theme.error,
+        )));
+    } else if let Some(ref details) = state.data {
+        // Index name as header
+        lines.push(Line::from(vec![
+            Span::styled("Index: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(&details.name, app.theme.title),
+        ]));
+
+        // Show provided name if it exists
+        if let Some(ref provided_name) = details.provided_name {
             lines.push(Line::from(vec![
-                Span::styled("Health: ", Style::new().fg(Color::DarkGray)),
-                Span::styled(
-                    details.health.as_deref().unwrap_or("unknown"),
-                    Style::new().fg(health_color).add_modifier(Modifier::BOLD),
-                ),
-                Span::raw("  "),
-                Span::styled("Status: ", Style::new().fg(Color::DarkGray)),
-                Span::styled(
-                    details.status.as_deref().unwrap_or("unknown"),
-                    Style::new().fg(Color::White),
-                ),
+                Span::styled("Provided Name: ", Style::new().fg(Color::DarkGray)),
+                Span::styled(provided_name, app.theme.title),
             ]));
+        }
 
-            // Creation date
+        // UUID
+        if let Some(ref uuid) = details.uuid {
             lines.push(Line::from(vec![
-                Span::styled("Created: ", Style::new().fg(Color::DarkGray)),
-                Span::styled(
-                    details.creation_date.as_deref().unwrap_or("unknown"),
-                    Style::new().fg(Color::White),
-                ),
+                Span::styled("UUID: ", Style::new().fg(Color::DarkGray)),
+                Span::styled(uuid, app.theme.text),
             ]));
+        }
 
-            lines.push(Line::from(""));
+        lines.push(Line::from(""));
+
+        // Health and Status
+        let health_color = match details.health.as_deref() {
+            Some("green") => Color::Green,
+            Some("yellow") => Color::Yellow,
+            Some("red") => Color::Red,
+            _ => Color::DarkGray,
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Health: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(
+                details.health.as_deref().unwrap_or("unknown"),
+                Style::new().fg(health_color).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  "),
+            Span::styled("Status: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(
+                details.status.as_deref().unwrap_or("unknown"),
+                app.theme.text,
+            ),
+        ]));
 
-            // Document count and size
-            lines.push(Line::from(vec![
-                Span::styled("Documents: ", Style::new().fg(Color::DarkGray)),
-                Span::styled(format_number(details.doc_count as f64), theme::TITLE),
-                Span::raw("  "),
-                Span::styled("Size: ", Style::new().fg(Color::DarkGray)),
-                Span::styled(
-                    format_bytes(details.size_bytes),
-                    Style::new().fg(Color::White),
-                ),
-            ]));
+        // Creation date
+        lines.push(Line::from(vec![
+            Span::styled("Created: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(
+                details
+                    .creation_date
+                    .map(|dt| app.timezone.format(dt))
+                    .unwrap_or_else(|| "unknown".to_string()),
+                app.theme.text,
+            ),
+        ]));
+
+        lines.push(Line::from(""));
 
-            // Index rate
-            let rate_str = format!("{} /s", format_number(details.rate_per_sec));
+        // Document count and size
+        lines.push(Line::from(vec![
+            Span::styled("Documents: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(format_number(details.doc_count as f64), app.theme.title),
+            Span::raw("  "),
+            Span::styled("Size: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{} primaries", app.format_size(details.size_bytes)),
+                size_style(app, !app.show_total_size),
+            ),
+        ]));
+        if let Some(index) = app.indices.iter().find(|i| i.name == details.name) {
+            if let Some(size_line) = lines.last_mut() {
+                size_line.push_span(Span::raw(" / "));
+                size_line.push_span(Span::styled(
+                    format!("{} total", app.format_size(index.total_size_bytes)),
+                    size_style(app, app.show_total_size),
+                ));
+            }
+        }
 
-            let rate_color = if details.rate_per_sec > 10000.0 {
+        // Index rate
+        let rate_str = format!(
+            "{} {}",
+            format_number(app.rate_unit.scale(details.rate_per_sec)),
+            app.rate_unit.suffix()
+        );
+
+        let rate_color = if details.rate_per_sec > 10000.0 {
+            Color::Red
+        } else if details.rate_per_sec > 1000.0 {
+            Color::Yellow
+        } else if details.rate_per_sec > 0.0 {
+            Color::Green
+        } else {
+            Color::DarkGray
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled("Index Rate: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(
+                rate_str,
+                Style::new().fg(rate_color).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+
+        // Indexing failures and merges, live from the latest refresh when `--stats` has them
+        let live = app.indices.iter().find(|i| i.name == details.name);
+        if let Some(index) = live.filter(|_| app.fetches(StatGroup::Indexing)) {
+            let failures_color = if index.failed_delta > 0 {
                 Color::Red
-            } else if details.rate_per_sec > 1000.0 {
+            } else if index.index_failed > 0 {
                 Color::Yellow
-            } else if details.rate_per_sec > 0.0 {
-                Color::Green
             } else {
                 Color::DarkGray
             };
-
             lines.push(Line::from(vec![
-                Span::styled("Index Rate: ", Style::new().fg(Color::DarkGray)),
+                Span::styled("Indexing Failures: ", Style::new().fg(Color::DarkGray)),
                 Span::styled(
-                    rate_str,
-                    Style::new().fg(rate_color).add_modifier(Modifier::BOLD),
+                    format!(
+                        "{} total (+{} since last refresh)",
+                        format_number(index.index_failed as f64),
+                        index.failed_delta
+                    ),
+                    Style::new().fg(failures_color),
                 ),
             ]));
+        }
 
-            lines.push(Line::from(""));
-
-            // Shards
-            let shard_info = if details.is_frozen || details.is_partial {
-                format!(
-                    "{} primary, {} replicas (Frozen/Searchable Snapshot)",
-                    details.primary_shards, details.replica_shards
-                )
+        // Merge pressure competes with indexing for I/O
+        if let Some(index) = live.filter(|_| app.fetches(StatGroup::Merge)) {
+            let merges_color = if index.merges_current > 0 {
+                Color::Yellow
             } else {
-                format!(
-                    "{} primary, {} replicas",
-                    details.primary_shards, details.replica_shards
-                )
+                Color::DarkGray
             };
+            lines.push(Line::from(vec![
+                Span::styled("Merges: ", Style::new().fg(Color::DarkGray)),
+                Span::styled(
+                    format!(
+                        "{} ongoing, {} ms/s merging",
+                        index.merges_current,
+                        format_number(index.merge_time_rate)
+                    ),
+                    Style::new().fg(merges_color),
+                ),
+            ]));
+        }
 
+        // Segment churn between refreshes, only requested with `--stats ...,segments`
+        if let Some(index) = live {
+            let segments = app.if_fetched(
+                &[StatGroup::Segments],
+                format!(
+                    "{} ({:+} since last refresh)",
+                    index.segment_count, index.segments_delta
+                ),
+            );
             lines.push(Line::from(vec![
-                Span::styled("Shards: ", Style::new().fg(Color::DarkGray)),
-                Span::styled(shard_info, Style::new().fg(Color::White)),
+                Span::styled("Live Segments: ", Style::new().fg(Color::DarkGray)),
+                Span::styled(segments, app.theme.text),
             ]));
+        }
 
-            if details.is_frozen {
-                lines.push(Line::from(Span::styled(
-                    "  ❄ Index is FROZEN (searchable snapshot from frozen tier)",
-                    Style::new().fg(Color::Cyan),
-                )));
-            }
-            if details.is_partial {
-                lines.push(Line::from(Span::styled(
-                    "  ⚡ Partial index (searchable snapshot)",
-                    Style::new().fg(Color::Magenta),
-                )));
+        lines.push(Line::from(""));
+
+        // Shards
+        let replicas = match &details.replicas {
+            Some(Replicas::AutoExpand(range)) => format!("auto-expand {}", range),
+            Some(replicas) => replicas.to_string(),
+            None => "?".to_string(),
+        };
+        let shard_info = if details.is_frozen || details.is_partial {
+            format!(
+                "{} primary, {} replicas (Frozen/Searchable Snapshot)",
+                details.primary_shards, replicas
+            )
+        } else {
+            format!("{} primary, {} replicas", details.primary_shards, replicas)
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled("Shards: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(shard_info, app.theme.text),
+        ]));
+
+        if details.is_frozen {
+            lines.push(Line::from(Span::styled(
+                "  ❄ Index is FROZEN (searchable snapshot from frozen tier)",
+                Style::new().fg(Color::Cyan),
+            )));
+        }
+        if details.is_partial {
+            lines.push(Line::from(Span::styled(
+                "  ⚡ Partial index (searchable snapshot)",
+                Style::new().fg(Color::Magenta),
+            )));
+        }
+
+        // Segments
+        lines.push(Line::from(vec![
+            Span::styled("Segments: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(format!("{}", details.total_segments), app.theme.text),
+        ]));
+
+        // Translog
+        lines.push(Line::from(vec![
+            Span::styled("Translog: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(
+                format!(
+                    "{} ({} ops)",
+                    format_bytes(details.translog_size_bytes),
+                    format_number(details.translog_ops as f64)
+                ),
+                app.theme.text,
+            ),
+        ]));
+
+        lines.push(Line::from(""));
+
+        // Caches
+        if details.query_cache.is_some() || details.request_cache.is_some() {
+            lines.push(Line::from(Span::styled(
+                "Caches:",
+                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )));
+            for (label, cache) in [
+                ("  Query Cache: ", &details.query_cache),
+                ("  Request Cache: ", &details.request_cache),
+            ] {
+                if let Some(cache) = cache {
+                    lines.push(Line::from(vec![
+                        Span::styled(label, Style::new().fg(Color::DarkGray)),
+                        cache_span(app, cache),
+                    ]));
+                }
             }
+            lines.push(Line::from(""));
+        }
+
+        // ILM Policy
+        lines.push(Line::from(vec![
+            Span::styled("ILM Policy: ", Style::new().fg(Color::DarkGray)),
+            Span::styled(
+                details.ilm_policy.as_deref().unwrap_or("none"),
+                Style::new().fg(if details.ilm_policy.is_some() {
+                    Color::Green
+                } else {
+                    Color::DarkGray
+                }),
+            ),
+        ]));
 
-            // Segments
+        if let Some(ref phase) = details.ilm_phase {
             lines.push(Line::from(vec![
-                Span::styled("Segments: ", Style::new().fg(Color::DarkGray)),
+                Span::styled("ILM Phase: ", Style::new().fg(Color::DarkGray)),
                 Span::styled(
-                    format!("{}", details.total_segments),
-                    Style::new().fg(Color::White),
+                    phase,
+                    Style::new().fg(match phase.as_str() {
+                        "hot" => Color::Red,
+                        "warm" => Color::Yellow,
+                        "cold" => Color::Cyan,
+                        "frozen" => Color::Blue,
+                        "delete" => Color::Magenta,
+                        _ => text_color,
+                    }),
                 ),
             ]));
+        }
 
-            lines.push(Line::from(""));
+        lines.push(Line::from(""));
+
+        // Data Stream
+        if let Some(ref ds) = details.data_stream {
+            lines.push(Line::from(Span::styled(
+                "Data Stream:",
+                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )));
+
+            lines.push(Line::from(vec![
+                Span::styled("  Name: ", Style::new().fg(Color::DarkGray)),
+                Span::styled(&ds.name, app.theme.title),
+            ]));
 
-            // ILM Policy
+            let write_indicator = if ds.is_write_index {
+                " (write index)"
+            } else {
+                ""
+            };
             lines.push(Line::from(vec![
-                Span::styled("ILM Policy: ", Style::new().fg(Color::DarkGray)),
+                Span::styled("  Backing Index: ", Style::new().fg(Color::DarkGray)),
                 Span::styled(
-                    details.ilm_policy.as_deref().unwrap_or("none"),
-                    Style::new().fg(if details.ilm_policy.is_some() {
+                    format!(
+                        "{} of {}{}",
+                        ds.backing_index_position, ds.total_backing_indices, write_indicator
+                    ),
+                    Style::new().fg(if ds.is_write_index {
                         Color::Green
                     } else {
-                        Color::DarkGray
+                        text_color
                     }),
                 ),
             ]));
 
-            if let Some(ref phase) = details.ilm_phase {
-                lines.push(Line::from(vec![
-                    Span::styled("ILM Phase: ", Style::new().fg(Color::DarkGray)),
-                    Span::styled(
-                        phase,
-                        Style::new().fg(match phase.as_str() {
-                            "hot" => Color::Red,
-                            "warm" => Color::Yellow,
-                            "cold" => Color::Cyan,
-                            "frozen" => Color::Blue,
-                            "delete" => Color::Magenta,
-                            _ => Color::White,
-                        }),
-                    ),
-                ]));
-            }
-
-            lines.push(Line::from(""));
-
-            // Data Stream
-            if let Some(ref ds) = details.data_stream {
-                lines.push(Line::from(Span::styled(
-                    "Data Stream:",
-                    Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-                )));
-
-                lines.push(Line::from(vec![
-                    Span::styled("  Name: ", Style::new().fg(Color::DarkGray)),
-                    Span::styled(&ds.name, theme::TITLE),
-                ]));
+            lines.push(Line::from(vec![
+                Span::styled("  Generation: ", Style::new().fg(Color::DarkGray)),
+                Span::styled(format!("{}", ds.generation), app.theme.text),
+            ]));
 
-                let write_indicator = if ds.is_write_index {
-                    " (write index)"
-                } else {
-                    ""
-                };
-                lines.push(Line::from(vec![
-                    Span::styled("  Backing Index: ", Style::new().fg(Color::DarkGray)),
-                    Span::styled(
-                        format!(
-                            "{} of {}{}",
-                            ds.backing_index_position, ds.total_backing_indices, write_indicator
+            if ds.is_write_index {
+                if let Some(created) = details.creation_date {
+                    lines.push(Line::from(vec![
+                        Span::styled("  Write Index Age: ", Style::new().fg(Color::DarkGray)),
+                        Span::styled(
+                            format_duration(chrono::Utc::now() - created),
+                            app.theme.text,
                         ),
-                        Style::new().fg(if ds.is_write_index {
-                            Color::Green
-                        } else {
-                            Color::White
-                        }),
-                    ),
-                ]));
-
-                lines.push(Line::from(vec![
-                    Span::styled("  Generation: ", Style::new().fg(Color::DarkGray)),
-                    Span::styled(format!("{}", ds.generation), Style::new().fg(Color::White)),
-                ]));
-
-                lines.push(Line::from(vec![
-                    Span::styled("  Timestamp Field: ", Style::new().fg(Color::DarkGray)),
-                    Span::styled(&ds.timestamp_field, Style::new().fg(Color::White)),
-                ]));
+                    ]));
+                }
+            }
 
-                if let Some(ref template) = ds.template {
+            if let Some(ref rollover) = ds.rollover {
+                let mut conditions = Vec::new();
+                if let Some(max_docs) = rollover.max_docs {
+                    let reached = details.doc_count as f64 / max_docs.max(1) as f64 * 100.0;
+                    conditions.push(format!(
+                        "{} docs ({:.0}% reached)",
+                        format_number(max_docs as f64),
+                        reached
+                    ));
+                }
+                if let Some(ref max_age) = rollover.max_age {
+                    conditions.push(max_age.clone());
+                }
+                if !conditions.is_empty() {
                     lines.push(Line::from(vec![
-                        Span::styled("  Template: ", Style::new().fg(Color::DarkGray)),
-                        Span::styled(template, Style::new().fg(Color::Green)),
+                        Span::styled("  Rollover At: ", Style::new().fg(Color::DarkGray)),
+                        Span::styled(conditions.join(" / "), app.theme.text),
                     ]));
                 }
 
-                if let Some(ref retention) = ds.data_retention {
+                if let Some((eta, condition)) = next_rollover(details, chrono::Utc::now()) {
+                    let eta = if eta <= chrono::Duration::zero() {
+                        "due now".to_string()
+                    } else {
+                        format!("~{}", format_duration(eta))
+                    };
                     lines.push(Line::from(vec![
-                        Span::styled("  Data Retention: ", Style::new().fg(Color::DarkGray)),
-                        Span::styled(retention, Style::new().fg(Color::Yellow)),
+                        Span::styled("  Next Rollover: ", Style::new().fg(Color::DarkGray)),
+                        Span::styled(
+                            format!("{} ({})", eta, condition),
+                            Style::new().fg(Color::Cyan),
+                        ),
                     ]));
                 }
-
-                lines.push(Line::from(""));
             }
 
-            // Templates
             lines.push(Line::from(vec![
-                Span::styled("Templates: ", Style::new().fg(Color::DarkGray)),
-                if details.templates.is_empty() {
-                    Span::styled("none", Style::new().fg(Color::DarkGray))
-                } else {
-                    Span::styled(details.templates.join(", "), Style::new().fg(Color::Green))
-                },
+                Span::styled("  Timestamp Field: ", Style::new().fg(Color::DarkGray)),
+                Span::styled(&ds.timestamp_field, app.theme.text),
             ]));
 
+            if let Some(ref template) = ds.template {
+                lines.push(Line::from(vec![
+                    Span::styled("  Template: ", Style::new().fg(Color::DarkGray)),
+                    Span::styled(template, Style::new().fg(Color::Green)),
+                ]));
+            }
+
+            if let Some(ref retention) = ds.data_retention {
+                lines.push(Line::from(vec![
+                    Span::styled("  Data Retention: ", Style::new().fg(Color::DarkGray)),
+                    Span::styled(retention, Style::new().fg(Color::Yellow)),
+                ]));
+            }
+
             lines.push(Line::from(""));
+        }
 
-            // Shard Allocation
+        // Templates
+        lines.push(Line::from(vec![
+            Span::styled("Templates: ", Style::new().fg(Color::DarkGray)),
+            if details.templates.is_empty() {
+                Span::styled("none", Style::new().fg(Color::DarkGray))
+            } else {
+                Span::styled(details.templates.join(", "), Style::new().fg(Color::Green))
+            },
+        ]));
+
+        lines.push(Line::from(""));
+
+        // Shard Allocation
+        lines.push(Line::from(Span::styled(
+            "Shard Allocation:",
+            Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(""));
+
+        let shard_allocation = details.shard_allocation.as_deref().unwrap_or_default();
+        if details.shard_allocation.is_none() {
+            let message = if state.shards_loading {
+                "  Loading shards...".to_string()
+            } else if let Some(ref error) = state.shards_error {
+                format!("  Failed to load shards: {}", error)
+            } else {
+                "  Press s to load shards".to_string()
+            };
             lines.push(Line::from(Span::styled(
-                "Shard Allocation:",
-                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                message,
+                Style::new().fg(Color::DarkGray),
+            )));
+        } else if shard_allocation.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No shard information available",
+                Style::new().fg(Color::DarkGray),
             )));
+        } else {
+            // Per-node tally, so a node carrying most of the shards stands out
+            let tallies = node_tallies(shard_allocation);
+            let mut tally_spans = vec![Span::styled("  Nodes: ", Style::new().fg(Color::DarkGray))];
+            for (i, tally) in tallies.iter().enumerate() {
+                if i > 0 {
+                    tally_spans.push(Span::raw(", "));
+                }
+                let color = if tally.hot { Color::Red } else { Color::Cyan };
+                tally_spans.push(Span::styled(
+                    format!(
+                        "{}: {} shards ({}P)",
+                        tally.node, tally.shards, tally.primaries
+                    ),
+                    Style::new().fg(color),
+                ));
+            }
+            lines.push(Line::from(tally_spans));
             lines.push(Line::from(""));
 
-            if details.shard_allocation.is_empty() {
-                lines.push(Line::from(Span::styled(
-                    "  No shard information available",
-                    Style::new().fg(Color::DarkGray),
-                )));
+            lines.extend(shard_table_lines(shard_allocation, text_color));
+        }
+    }
+
+    lines
+}
+
+// Centered overlay width: 80% of the screen, or enough for `content_width` columns plus
+// the borders, up to the full width
+fn popup_width(area_width: u16, content_width: usize) -> u16 {
+    let default = (area_width as f32 * 0.8) as u16;
+    let needed = (content_width + 2).min(area_width as usize) as u16;
+    default.max(needed)
+}
+
+const SHARD_HEADERS: [&str; 6] = ["Shard", "Role", "Node", "State", "Docs", "Size"];
+// Indent before and gap between shard table columns
+const SHARD_INDENT: &str = "  ";
+const SHARD_GAP: &str = "  ";
+
+// Shard copies by shard number, each primary before its replicas
+fn sorted_shards(shards: &[ShardInfo]) -> Vec<&ShardInfo> {
+    let mut sorted: Vec<&ShardInfo> = shards.iter().collect();
+    sorted.sort_by_key(|shard| (shard.shard_id, !shard.primary));
+    sorted
+}
+
+// One shard copy as cells in `SHARD_HEADERS` order; the number only on the first copy
+fn shard_cells(shard: &ShardInfo, first_copy: bool) -> [String; 6] {
+    [
+        if first_copy {
+            shard.shard_id.to_string()
+        } else {
+            String::new()
+        },
+        if shard.primary { "P" } else { "R" }.to_string(),
+        shard.node.clone(),
+        shard.state.clone(),
+        shard.docs.map_or("-".to_string(), |docs| docs.to_string()),
+        shard.size.clone().unwrap_or_else(|| "-".to_string()),
+    ]
+}
+
+fn shard_rows(shards: &[ShardInfo]) -> Vec<(&ShardInfo, [String; 6])> {
+    let mut previous_id = None;
+    sorted_shards(shards)
+        .into_iter()
+        .map(|shard| {
+            let first_copy = previous_id != Some(shard.shard_id);
+            previous_id = Some(shard.shard_id);
+            (shard, shard_cells(shard, first_copy))
+        })
+        .collect()
+}
+
+// Widest cell per column, headers included
+fn shard_column_widths<'a>(rows: impl Iterator<Item = &'a [String; 6]>) -> [usize; 6] {
+    let mut widths = SHARD_HEADERS.map(|header| header.chars().count());
+    for cells in rows {
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    widths
+}
+
+/// Display width of the aligned shard table, indent included.
+fn shard_table_width(shards: &[ShardInfo]) -> usize {
+    let rows = shard_rows(shards);
+    let widths = shard_column_widths(rows.iter().map(|(_, cells)| cells));
+    SHARD_INDENT.len() + widths.iter().sum::<usize>() + SHARD_GAP.len() * (widths.len() - 1)
+}
+
+// Numbers line up on the right, text on the left
+fn pad_shard_cell(column: usize, cell: &str, width: usize) -> String {
+    match SHARD_HEADERS[column] {
+        "Shard" | "Docs" | "Size" => format!("{:>width$}", cell, width = width),
+        _ => format!("{:<width$}", cell, width = width),
+    }
+}
+
+/// Shard copies in aligned columns under a header row, colored by role and state.
+fn shard_table_lines(shards: &[ShardInfo], text_color: Color) -> Vec<Line<'static>> {
+    let rows = shard_rows(shards);
+    let widths = shard_column_widths(rows.iter().map(|(_, cells)| cells));
+
+    let header_style = Style::new()
+        .fg(Color::DarkGray)
+        .add_modifier(Modifier::BOLD);
+    let mut header = vec![Span::raw(SHARD_INDENT)];
+    for (column, title) in SHARD_HEADERS.iter().enumerate() {
+        if column > 0 {
+            header.push(Span::raw(SHARD_GAP));
+        }
+        header.push(Span::styled(
+            pad_shard_cell(column, title, widths[column]),
+            header_style,
+        ));
+    }
+
+    let mut lines = vec![Line::from(header)];
+    for (shard, cells) in rows {
+        let state_color = match shard.state.as_str() {
+            "STARTED" => Color::Green,
+            "RELOCATING" => Color::Yellow,
+            "INITIALIZING" => Color::Cyan,
+            "UNASSIGNED" => Color::Red,
+            _ => text_color,
+        };
+        let styles = [
+            Style::new().fg(text_color),
+            if shard.primary {
+                Style::new().fg(Color::Green).add_modifier(Modifier::BOLD)
             } else {
-                // Group by shard ID
-                let mut shards_by_id: std::collections::HashMap<u32, Vec<_>> =
-                    std::collections::HashMap::new();
-                for shard in &details.shard_allocation {
-                    shards_by_id.entry(shard.shard_id).or_default().push(shard);
-                }
+                Style::new().fg(Color::Yellow)
+            },
+            Style::new().fg(Color::Cyan),
+            Style::new().fg(state_color),
+            Style::new().fg(Color::DarkGray),
+            Style::new().fg(Color::DarkGray),
+        ];
+
+        let mut spans = vec![Span::raw(SHARD_INDENT)];
+        for (column, cell) in cells.iter().enumerate() {
+            if column > 0 {
+                spans.push(Span::raw(SHARD_GAP));
+            }
+            spans.push(Span::styled(
+                pad_shard_cell(column, cell, widths[column]),
+                styles[column],
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
 
-                let mut shard_ids: Vec<_> = shards_by_id.keys().collect();
-                shard_ids.sort();
-
-                for shard_id in shard_ids {
-                    if let Some(shards) = shards_by_id.get(shard_id) {
-                        let primary = shards.iter().find(|s| s.primary);
-                        let replicas: Vec<_> = shards.iter().filter(|s| !s.primary).collect();
-
-                        // Primary shard
-                        if let Some(p) = primary {
-                            let state_color = match p.state.as_str() {
-                                "STARTED" => Color::Green,
-                                "RELOCATING" => Color::Yellow,
-                                "INITIALIZING" => Color::Cyan,
-                                "UNASSIGNED" => Color::Red,
-                                _ => Color::White,
-                            };
-
-                            let size_str = p.size.as_deref().unwrap_or("-");
-                            let docs_str = p
-                                .docs
-                                .map(|d| format!("{}", d))
-                                .unwrap_or_else(|| "-".to_string());
-
-                            lines.push(Line::from(vec![
-                                Span::styled(
-                                    format!("  Shard {} ", shard_id),
-                                    Style::new().fg(Color::White),
-                                ),
-                                Span::styled(
-                                    "[P] ",
-                                    Style::new().fg(Color::Green).add_modifier(Modifier::BOLD),
-                                ),
-                                Span::styled(&p.node, Style::new().fg(Color::Cyan)),
-                                Span::raw(" "),
-                                Span::styled(&p.state, Style::new().fg(state_color)),
-                                Span::raw(" "),
-                                Span::styled(
-                                    format!("docs:{} size:{}", docs_str, size_str),
-                                    Style::new().fg(Color::DarkGray),
-                                ),
-                            ]));
-                        }
-
-                        // Replica shards
-                        for r in replicas {
-                            let state_color = match r.state.as_str() {
-                                "STARTED" => Color::Green,
-                                "RELOCATING" => Color::Yellow,
-                                "INITIALIZING" => Color::Cyan,
-                                "UNASSIGNED" => Color::Red,
-                                _ => Color::White,
-                            };
-
-                            let size_str = r.size.as_deref().unwrap_or("-");
-                            let docs_str = r
-                                .docs
-                                .map(|d| format!("{}", d))
-                                .unwrap_or_else(|| "-".to_string());
-
-                            lines.push(Line::from(vec![
-                                Span::raw("          "),
-                                Span::styled("[R] ", Style::new().fg(Color::Yellow)),
-                                Span::styled(&r.node, Style::new().fg(Color::Cyan)),
-                                Span::raw(" "),
-                                Span::styled(&r.state, Style::new().fg(state_color)),
-                                Span::raw(" "),
-                                Span::styled(
-                                    format!("docs:{} size:{}", docs_str, size_str),
-                                    Style::new().fg(Color::DarkGray),
-                                ),
-                            ]));
-                        }
-                    }
-                }
+// A node holding more than this multiple of its fair share of shards is flagged
+const HOT_NODE_FACTOR: f64 = 1.5;
+
+struct NodeTally<'a> {
+    node: &'a str,
+    shards: usize,
+    primaries: usize,
+    hot: bool,
+}
+
+// Counts assigned shards per node, flagging nodes with a disproportionate share of
+// all shards or of the primaries
+fn node_tallies(shards: &[ShardInfo]) -> Vec<NodeTally<'_>> {
+    let mut tallies: Vec<NodeTally> = Vec::new();
+    for shard in shards.iter().filter(|s| s.state != "UNASSIGNED") {
+        let index = match tallies.iter().position(|t| t.node == shard.node) {
+            Some(index) => index,
+            None => {
+                tallies.push(NodeTally {
+                    node: &shard.node,
+                    shards: 0,
+                    primaries: 0,
+                    hot: false,
+                });
+                tallies.len() - 1
             }
+        };
+        tallies[index].shards += 1;
+        if shard.primary {
+            tallies[index].primaries += 1;
         }
+    }
 
-        // Apply scroll offset
-        let visible_height = popup_height.saturating_sub(4) as usize; // Account for border and title
-        let max_scroll = lines.len().saturating_sub(visible_height);
-        let scroll = self.app.details.scroll.min(max_scroll);
+    let nodes = tallies.len() as f64;
+    let total_shards: usize = tallies.iter().map(|t| t.shards).sum();
+    let total_primaries: usize = tallies.iter().map(|t| t.primaries).sum();
+    let over_share = |count: usize, total: usize| {
+        total > 1 && count as f64 > total as f64 / nodes * HOT_NODE_FACTOR
+    };
+    if tallies.len() > 1 {
+        for tally in &mut tallies {
+            tally.hot = over_share(tally.shards, total_shards)
+                || over_share(tally.primaries, total_primaries);
+        }
+    }
 
-        let title = Line::from(vec![
-            Span::raw(" Index Details "),
-            Span::styled(
-                "[Esc/Enter] Close  [j/k] Scroll ",
-                Style::new().fg(Color::DarkGray),
-            ),
-        ]);
+    tallies.sort_by(|a, b| a.node.cmp(b.node));
+    tallies
+}
 
-        Paragraph::new(lines)
-            .block(
-                Block::default()
-                    .title(title)
-                    .borders(Borders::ALL)
-                    .border_style(Style::new().fg(Color::Cyan)),
+// Hit percentage (colored by how well the cache is doing) and memory, or "no activity"
+fn cache_span(app: &App, cache: &CacheStats) -> Span<'static> {
+    match cache.hit_ratio() {
+        Some(ratio) => {
+            let color = if ratio >= 0.8 {
+                Color::Green
+            } else if ratio >= 0.5 {
+                Color::Yellow
+            } else {
+                Color::Red
+            };
+            Span::styled(
+                format!(
+                    "{:.1}% hits ({} of {} lookups), {}",
+                    ratio * 100.0,
+                    format_number(cache.hits as f64),
+                    format_number((cache.hits + cache.misses) as f64),
+                    format_bytes(cache.memory_bytes)
+                ),
+                Style::new().fg(color),
             )
-            .scroll((scroll as u16, 0))
-            .wrap(Wrap { trim: false })
-            .render(popup_area, buf);
+        }
+        None => Span::styled(
+            format!("no activity, {}", format_bytes(cache.memory_bytes)),
+            app.theme.time,
+        ),
+    }
+}
+
+// The size basis picked with `S` stands out from the other one
+fn size_style(app: &App, active: bool) -> Style {
+    if active {
+        app.theme.text.add_modifier(Modifier::BOLD)
+    } else {
+        Style::new().fg(Color::DarkGray)
+    }
+}
+
+// Time until the first rollover condition trips, and which one. Doc count is
+// extrapolated from the current rate; unparseable conditions are skipped.
+fn next_rollover(
+    details: &IndexDetails,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<(chrono::Duration, &'static str)> {
+    let rollover = details.data_stream.as_ref()?.rollover.as_ref()?;
+
+    let by_age = rollover
+        .max_age
+        .as_deref()
+        .and_then(parse_es_duration)
+        .zip(details.creation_date)
+        .map(|(max_age, created)| (created + max_age - now, "max_age"));
+
+    let by_docs = rollover
+        .max_docs
+        .filter(|_| details.rate_per_sec > 0.0)
+        .and_then(|max_docs| {
+            let remaining = max_docs.saturating_sub(details.doc_count) as f64;
+            chrono::Duration::try_seconds((remaining / details.rate_per_sec) as i64)
+                .map(|eta| (eta, "max_docs"))
+        });
+
+    [by_age, by_docs]
+        .into_iter()
+        .flatten()
+        .min_by_key(|(eta, _)| *eta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DataStreamDetails, RolloverConditions};
+
+    fn shard(node: &str, primary: bool) -> ShardInfo {
+        ShardInfo {
+            shard_id: 0,
+            primary,
+            state: "STARTED".to_string(),
+            node: node.to_string(),
+            docs: None,
+            size: None,
+        }
+    }
+
+    #[test]
+    fn test_node_tallies_flag_primaries_on_one_node() {
+        let shards = [
+            shard("node-1", true),
+            shard("node-1", true),
+            shard("node-1", true),
+            shard("node-2", false),
+            shard("node-2", false),
+            shard("node-2", false),
+        ];
+        let tallies = node_tallies(&shards);
+
+        assert_eq!(tallies.len(), 2);
+        assert_eq!(
+            (tallies[0].node, tallies[0].shards, tallies[0].primaries),
+            ("node-1", 3, 3)
+        );
+        assert!(tallies[0].hot);
+        assert!(!tallies[1].hot);
+    }
+
+    #[test]
+    fn test_shard_table_columns_align() {
+        let mut long = shard("a-very-long-node-name", false);
+        long.docs = Some(12345);
+        let mut primary = shard("n1", true);
+        primary.size = Some("1.2gb".to_string());
+        let shards = [long, primary];
+
+        let lines = shard_table_lines(&shards, Color::White);
+        let texts: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            [
+                "  Shard  Role  Node                   State     Docs   Size",
+                "      0  P     n1                     STARTED      -  1.2gb",
+                "         R     a-very-long-node-name  STARTED  12345      -",
+            ]
+        );
+        assert_eq!(shard_table_width(&shards), texts[0].len());
+    }
+
+    #[test]
+    fn test_popup_width_grows_for_wide_content() {
+        assert_eq!(popup_width(100, 40), 80);
+        assert_eq!(popup_width(100, 90), 92);
+        assert_eq!(popup_width(100, 200), 100);
+    }
+
+    #[test]
+    fn test_node_tallies_balanced() {
+        let shards = [
+            shard("node-1", true),
+            shard("node-2", false),
+            shard("node-2", true),
+            shard("node-1", false),
+        ];
+        assert!(node_tallies(&shards).iter().all(|t| !t.hot));
+    }
+
+    #[test]
+    fn test_next_rollover_picks_earliest_condition() {
+        let now = chrono::Utc::now();
+        let mut details = IndexDetails {
+            name: ".ds-logs-2024.01.01-000003".to_string(),
+            provided_name: None,
+            creation_date: Some(now - chrono::Duration::hours(20)),
+            primary_shards: 1,
+            replicas: Some(Replicas::Count(1)),
+            is_frozen: false,
+            is_partial: false,
+            ilm_policy: Some("logs".to_string()),
+            ilm_phase: Some("hot".to_string()),
+            total_segments: 0,
+            translog_size_bytes: 0,
+            translog_ops: 0,
+            shard_allocation: Some(Vec::new()),
+            templates: Vec::new(),
+            uuid: None,
+            health: None,
+            status: None,
+            doc_count: 9_000,
+            rate_per_sec: 1.0,
+            size_bytes: 0,
+            data_stream: Some(DataStreamDetails {
+                name: "logs".to_string(),
+                timestamp_field: "@timestamp".to_string(),
+                generation: 3,
+                total_backing_indices: 3,
+                backing_index_position: 3,
+                is_write_index: true,
+                template: None,
+                data_retention: None,
+                rollover: Some(RolloverConditions {
+                    max_docs: Some(10_000),
+                    max_age: Some("1d".to_string()),
+                }),
+            }),
+            query_cache: None,
+            request_cache: None,
+            raw_responses: Default::default(),
+        };
+
+        // 1000 docs at 1/s beats the 4h left on max_age
+        let (eta, condition) = next_rollover(&details, now).unwrap();
+        assert_eq!((eta.num_seconds(), condition), (1_000, "max_docs"));
+
+        // With no ingest only the age condition can fire
+        details.rate_per_sec = 0.0;
+        let (eta, condition) = next_rollover(&details, now).unwrap();
+        assert_eq!((eta.num_hours(), condition), (4, "max_age"));
+
+        // Unparseable age and no rate leave nothing to estimate
+        if let Some(ds) = details.data_stream.as_mut() {
+            ds.rollover.as_mut().unwrap().max_age = Some("soon".to_string());
+        }
+        assert!(next_rollover(&details, now).is_none());
     }
 }
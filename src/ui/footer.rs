@@ -6,8 +6,16 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Widget},
 };
 
-use super::theme;
+use std::time::Duration;
+
 use crate::app::App;
+use crate::models::StatGroup;
+use crate::ui::types::ColorMode;
+use crate::utils::{format_bytes, format_duration, format_number};
+
+// Data older than these multiples of the refresh interval is flagged yellow, then red
+const STALE_INTERVALS: u32 = 2;
+const VERY_STALE_INTERVALS: u32 = 5;
 
 pub struct Footer<'a> {
     app: &'a App,
@@ -26,6 +34,19 @@ impl<'a> Widget for Footer<'a> {
             Span::raw("Help"),
         ];
 
+        if let Some(cleared) = self.app.exclusions_undo_hint() {
+            spans.push(Span::raw("  |  "));
+            spans.push(Span::styled(
+                format!("Cleared {} exclusions — [Ctrl+z] to undo", cleared),
+                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        if let Some(status) = self.app.status_message() {
+            spans.push(Span::raw("  |  "));
+            spans.push(Span::styled(status, Style::new().fg(Color::Yellow)));
+        }
+
         if self.app.filter.active {
             spans.push(Span::raw("  |  "));
             spans.push(Span::styled(
@@ -39,7 +60,11 @@ impl<'a> Widget for Footer<'a> {
             // Pause status
             if self.app.paused {
                 spans.push(Span::styled(
-                    "⏸ PAUSED",
+                    if self.app.blur_paused {
+                        "⏸ PAUSED (unfocused)"
+                    } else {
+                        "⏸ PAUSED"
+                    },
                     Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
                 ));
                 spans.push(Span::raw("  "));
@@ -51,6 +76,33 @@ impl<'a> Widget for Footer<'a> {
                 Style::new().fg(Color::Cyan),
             ));
 
+            // Refreshes averaged into each index rate, adjustable with } and {
+            spans.push(Span::styled(
+                format!(" avg×{}", self.app.rate_samples),
+                Style::new().fg(Color::Cyan),
+            ));
+
+            // Age of the displayed data, colored once refreshes fall behind
+            if let Some(age) = self.app.data_age() {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(
+                    format!(
+                        "updated {} ago",
+                        format_duration(chrono::Duration::seconds(age.as_secs() as i64))
+                    ),
+                    Style::new().fg(staleness_color(age, self.app.refresh_interval)),
+                ));
+            }
+
+            // Round trip of the lightweight health call, separate from the full fetch time
+            if let Some(latency) = self.app.api_latency_display() {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(
+                    format!("api: {}", latency),
+                    Style::new().fg(Color::Cyan),
+                ));
+            }
+
             // Toggle states
             spans.push(Span::raw("  "));
             spans.push(Span::styled(
@@ -73,24 +125,96 @@ impl<'a> Widget for Footer<'a> {
             spans.push(Span::raw("/"));
             spans.push(Span::styled(
                 "Hidden",
-                Style::new().fg(if self.app.show_system_indices {
+                Style::new().fg(if self.app.show_system_in_list {
                     Color::Green
                 } else {
                     Color::DarkGray
                 }),
             ));
-
-            // Colormap indicator
-            spans.push(Span::raw("  |  "));
+            // Whether system indices count toward the rate total
             spans.push(Span::styled(
-                format!("{}", self.app.colormap),
-                Style::new().fg(Color::Magenta),
+                "Σ",
+                Style::new().fg(if self.app.count_system_in_rate {
+                    Color::Green
+                } else {
+                    Color::DarkGray
+                }),
             ));
 
+            if self.app.rollup {
+                spans.push(Span::styled("  Rollup", Style::new().fg(Color::Green)));
+            }
+            if self.app.unhealthy_first {
+                spans.push(Span::styled("  Unhealthy↑", Style::new().fg(Color::Yellow)));
+            }
+
+            // Colormap indicator, or the coloring mode when rows aren't gradient-colored
+            spans.push(Span::raw("  |  "));
+            let coloring = match self.app.color_mode {
+                ColorMode::Gradient => self.app.column_colormap(self.app.sort.column).to_string(),
+                mode => format!("by {}", mode),
+            };
+            spans.push(Span::styled(coloring, Style::new().fg(Color::Magenta)));
+
             // Excluded count
             let excluded = self.app.excluded_count();
             if excluded > 0 {
-                spans.push(Span::styled(format!("  ✗{}", excluded), theme::ERROR));
+                spans.push(Span::styled(
+                    format!("  ✗{}", excluded),
+                    self.app.theme.error,
+                ));
+            }
+
+            // Pinned count
+            let pinned = self.app.pinned_indices.len();
+            if pinned > 0 {
+                spans.push(Span::styled(
+                    format!("  ◆{}", pinned),
+                    Style::new().fg(Color::Cyan),
+                ));
+            }
+
+            // Yellow and red indices, reachable with ] and [
+            let (_, problems) = self.app.unhealthy_position();
+            if problems > 0 {
+                spans.push(Span::styled(
+                    format!("  ⚠{} unhealthy (]/[)", problems),
+                    Style::new().fg(Color::Yellow),
+                ));
+            }
+
+            // Startup glob scope
+            if !self.app.include_patterns.is_empty() {
+                spans.push(Span::styled(
+                    format!("  +{}", self.app.include_patterns.join(",")),
+                    Style::new().fg(Color::Green),
+                ));
+            }
+            if !self.app.exclude_patterns.is_empty() {
+                spans.push(Span::styled(
+                    format!("  -{}", self.app.exclude_patterns.join(",")),
+                    Style::new().fg(Color::Red),
+                ));
+            }
+
+            // Group total of what the filter matched, e.g. all `logs-*` indices
+            if let Some(totals) = self.app.filter_totals() {
+                spans.push(Span::raw("  |  "));
+                spans.push(Span::styled(
+                    format!(
+                        "Σ {} {} · {} docs · {}",
+                        self.app.if_fetched(
+                            &[StatGroup::Indexing],
+                            format_number(self.app.rate_unit.scale(totals.rate_per_sec))
+                        ),
+                        self.app.rate_unit.suffix(),
+                        self.app
+                            .if_fetched(&[StatGroup::Docs], format_number(totals.doc_count as f64)),
+                        self.app
+                            .if_fetched(&[StatGroup::Store], format_bytes(totals.size_bytes))
+                    ),
+                    Style::new().fg(Color::Cyan),
+                ));
             }
 
             // Index count
@@ -101,16 +225,58 @@ impl<'a> Widget for Footer<'a> {
                     self.app.filtered_indices().len(),
                     self.app.indices.len()
                 ),
-                Style::new().fg(Color::White),
+                self.app.theme.text,
             ));
         }
 
-        Paragraph::new(Line::from(spans))
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(theme::BORDER),
-            )
-            .render(area, buf);
+        let paragraph = Paragraph::new(Line::from(spans));
+        if self.app.compact {
+            paragraph.render(area, buf);
+        } else {
+            paragraph
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(self.app.theme.border),
+                )
+                .render(area, buf);
+        }
+    }
+}
+
+// Normal color while the data is within a couple of refresh intervals
+fn staleness_color(age: Duration, interval: Duration) -> Color {
+    if age > interval * VERY_STALE_INTERVALS {
+        Color::Red
+    } else if age > interval * STALE_INTERVALS {
+        Color::Yellow
+    } else {
+        Color::Cyan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_staleness_color_by_refresh_intervals() {
+        let interval = Duration::from_secs(5);
+        assert_eq!(
+            staleness_color(Duration::from_secs(3), interval),
+            Color::Cyan
+        );
+        assert_eq!(
+            staleness_color(Duration::from_secs(10), interval),
+            Color::Cyan
+        );
+        assert_eq!(
+            staleness_color(Duration::from_secs(11), interval),
+            Color::Yellow
+        );
+        assert_eq!(
+            staleness_color(Duration::from_secs(26), interval),
+            Color::Red
+        );
     }
 }
@@ -1,14 +1,15 @@
-use chrono::Local;
+use chrono::Utc;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Widget},
 };
 
-use super::theme;
 use crate::app::App;
+use crate::models::IndexRate;
+use crate::utils::format_number;
 
 pub struct Header<'a> {
     app: &'a App,
@@ -22,44 +23,141 @@ impl<'a> Header<'a> {
 
 impl<'a> Widget for Header<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let now = Local::now();
-        let datetime = now.format("%Y-%m-%d %H:%M:%S").to_string();
+        let datetime = self.app.timezone.format(Utc::now());
 
-        let title = if let Some(ref error) = self.app.error {
+        let mut title = if let Some(ref error) = self.app.error {
             Line::from(vec![
-                Span::styled(" EstiCLI ", theme::TITLE),
+                Span::styled(" EstiCLI ", self.app.theme.title),
                 Span::raw(" | "),
-                Span::styled(format!("Error: {}", error), theme::ERROR),
+                Span::styled(format!("Error: {}", error), self.app.theme.error),
                 Span::raw(" | "),
-                Span::styled(datetime, theme::TIME),
+                Span::styled(datetime, self.app.theme.time),
             ])
         } else {
             Line::from(vec![
-                Span::styled(" EstiCLI ", theme::TITLE),
+                Span::styled(" EstiCLI ", self.app.theme.title),
                 Span::raw(" | "),
-                Span::styled(&self.app.es_url, theme::URL),
+                Span::styled(&self.app.es_url, self.app.theme.url),
+                Span::styled(
+                    self.app
+                        .index_pattern
+                        .as_ref()
+                        .map(|pattern| format!(" [{}]", pattern))
+                        .unwrap_or_default(),
+                    self.app.theme.url,
+                ),
                 Span::raw(" | Cluster Rate: "),
                 Span::styled(
-                    format!("{} /s", self.app.total_cluster_rate_human()),
-                    theme::RATE,
+                    format!(
+                        "{} {}",
+                        self.app.total_cluster_rate_human(),
+                        self.app.rate_unit.suffix()
+                    ),
+                    self.app.theme.rate,
                 ),
                 Span::raw(" ("),
                 Span::styled(
-                    format!("{}/s", self.app.total_cluster_bytes_per_sec_human()),
-                    theme::RATE,
+                    format!(
+                        "{}{}",
+                        self.app.total_cluster_bytes_per_sec_human(),
+                        self.app.rate_unit.suffix()
+                    ),
+                    self.app.theme.rate,
                 ),
                 Span::raw(")"),
                 Span::raw(" | "),
-                Span::styled(datetime, Style::new().fg(Color::White)),
+                Span::styled(index_count(self.app), self.app.theme.text),
+                Span::raw(" | "),
+                Span::styled(datetime, self.app.theme.text),
             ])
         };
 
-        Paragraph::new(title)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(theme::BORDER),
+        // Failures rising since the last refresh
+        let failures = self.app.recent_failures();
+        if failures > 0 {
+            title.push_span(Span::raw(" | "));
+            title.push_span(Span::styled(
+                format!("⚠ {} indexing failures", format_number(failures as f64)),
+                self.app.theme.error.add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        // A node over the --heap-warning threshold
+        if let Some(node) = self.app.heap_pressure() {
+            title.push_span(Span::raw(" | "));
+            title.push_span(Span::styled(
+                format!("⚠ JVM heap {}% on {}", node.heap_used_percent, node.node),
+                self.app.theme.error.add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        // Flash the header in the error color right after a health alert
+        let (style, border_style) = if self.app.alert.is_flashing() {
+            (
+                self.app.theme.error.add_modifier(Modifier::REVERSED),
+                self.app.theme.error,
             )
-            .render(area, buf);
+        } else {
+            (Style::default(), self.app.theme.border)
+        };
+
+        let paragraph = Paragraph::new(title).style(style);
+        if self.app.compact {
+            paragraph.render(area, buf);
+        } else {
+            paragraph
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(border_style),
+                )
+                .render(area, buf);
+        }
+    }
+}
+
+// "N indices", or "shown/N indices" while a filter, exclusion or system setting hides some,
+// with the closed ones among those shown
+fn index_count(app: &App) -> String {
+    index_count_label(&app.filtered_indices(), app.indices.len())
+}
+
+fn index_count_label(shown: &[&IndexRate], total: usize) -> String {
+    let noun = if total == 1 { "index" } else { "indices" };
+    let mut label = if shown.len() == total {
+        format!("{} {}", total, noun)
+    } else {
+        format!("{}/{} {}", shown.len(), total, noun)
+    };
+    let closed = shown.iter().filter(|index| index.closed).count();
+    if closed > 0 {
+        label.push_str(&format!(
+            " ({} open, {} closed)",
+            shown.len() - closed,
+            closed
+        ));
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_count_label() {
+        let open = IndexRate::named("logs-1");
+        let closed = IndexRate {
+            closed: true,
+            ..IndexRate::named("old")
+        };
+
+        assert_eq!(index_count_label(&[&open], 1), "1 index");
+        assert_eq!(index_count_label(&[], 0), "0 indices");
+        assert_eq!(index_count_label(&[&open], 3), "1/3 indices");
+        assert_eq!(
+            index_count_label(&[&open, &closed], 2),
+            "2 indices (1 open, 1 closed)"
+        );
     }
 }
@@ -5,9 +5,14 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Widget},
 };
+use unicode_width::UnicodeWidthStr;
 
-use super::theme;
 use crate::app::App;
+use crate::models::ClusterHealth;
+use crate::ui::types::HealthIcons;
+
+// Cells reserved for each icon, so glyphs of differing width keep values aligned
+const ICON_WIDTH: usize = 2;
 
 pub struct ClusterHealthWidget<'a> {
     app: &'a App,
@@ -21,18 +26,9 @@ impl<'a> ClusterHealthWidget<'a> {
 
 impl<'a> Widget for ClusterHealthWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let health = &self.app.cluster_health;
-
-        let status_color = match health.status.as_str() {
-            "green" => Color::Green,
-            "yellow" => Color::Yellow,
-            "red" => Color::Red,
-            _ => Color::Gray,
-        };
-
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(theme::BORDER)
+            .border_style(self.app.theme.border)
             .title(Span::styled(
                 " Cluster Health ",
                 Style::new().add_modifier(Modifier::BOLD),
@@ -45,196 +41,199 @@ impl<'a> Widget for ClusterHealthWidget<'a> {
             return;
         }
 
-        // Divide inner area into rows for metrics
-        let [name_area, status_nodes_area, shards_area, moving_unassigned_area, pending_tasks_area, _] =
-            Layout::vertical([
-                Constraint::Length(1), // Cluster Name
-                Constraint::Length(1), // Status and Nodes
-                Constraint::Length(1), // Shards
-                Constraint::Length(1), // Relocating and Unassigned
-                Constraint::Length(1), // Pending Tasks
-                Constraint::Min(0),
-            ])
-            .areas(inner_area);
-
-        // Row: Cluster Name
-        let name_line = Line::from(vec![
-            Span::styled("󰆼 ", Style::new().fg(Color::Gray)),
-            Span::styled(
-                &health.cluster_name,
-                Style::new().add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        buf.set_line(name_area.x, name_area.y, &name_line, name_area.width);
-
-        // Row: Status and Nodes
-        let [status_area, nodes_area] =
-            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .areas(status_nodes_area);
-
-        // Status
-        let status_line = Line::from(vec![
-            Span::styled("♥ ", Style::new().fg(status_color)),
-            Span::styled(
-                health.status.to_uppercase(),
-                Style::new().fg(status_color).add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        buf.set_line(
-            status_area.x,
-            status_area.y,
-            &status_line,
-            status_area.width,
+        let rows = metric_rows(
+            &self.app.cluster_health,
+            self.app.spinner_style.health_icons(),
         );
+        let row_areas =
+            Layout::vertical(rows.iter().map(|_| Constraint::Length(1))).split(inner_area);
+
+        for ((left, right), &row_area) in rows.iter().zip(row_areas.iter()) {
+            match right {
+                Some(right) => {
+                    let (left_area, right_area) = split_row(row_area, &rows);
+                    buf.set_line(left_area.x, left_area.y, left, left_area.width);
+                    buf.set_line(right_area.x, right_area.y, right, right_area.width);
+                }
+                None => {
+                    buf.set_line(row_area.x, row_area.y, left, row_area.width);
+                }
+            }
+        }
+    }
+}
 
-        // Nodes: Total / Data
-        let nodes_line = Line::from(vec![
-            Span::styled("󰄳 ", Style::new().fg(Color::Cyan)),
-            Span::styled(
-                format!("{}", health.number_of_nodes),
-                Style::new().add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(" / ", Style::new().fg(Color::Gray)),
-            Span::styled("󰋊 ", Style::new().fg(Color::Blue)),
-            Span::styled(
-                format!("{}", health.number_of_data_nodes),
-                Style::new().add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        buf.set_line(nodes_area.x, nodes_area.y, &nodes_line, nodes_area.width);
-
-        // Row: Shards
-        let [shards_left_area, shards_right_area] =
-            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .areas(shards_area);
-
-        // Shards: P Pri / A Total Active
-        let shards_line = Line::from(vec![
-            Span::styled("P ", Style::new().fg(Color::Green)),
-            Span::styled(
-                format!("{}", health.active_primary_shards),
-                Style::new().add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(" / ", Style::new().fg(Color::Gray)),
-            Span::styled("A ", Style::new().fg(Color::Magenta)),
-            Span::styled(
-                format!("{}", health.active_shards),
-                Style::new().add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        buf.set_line(
-            shards_left_area.x,
-            shards_left_area.y,
-            &shards_line,
-            shards_left_area.width,
-        );
+// Left and optional right metric line for each row, top to bottom
+type MetricRow = (Line<'static>, Option<Line<'static>>);
+
+fn metric_rows(health: &ClusterHealth, icons: &HealthIcons) -> Vec<MetricRow> {
+    let bold = Style::new().add_modifier(Modifier::BOLD);
+    let separator = || Span::styled(" / ", Style::new().fg(Color::Gray));
+    let active_color = |active: bool, color: Color| if active { color } else { Color::Gray };
+
+    let status_color = match health.status.as_str() {
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "red" => Color::Red,
+        _ => Color::Gray,
+    };
+    let percent_color = if health.active_shards_percent >= 100.0 {
+        Color::Green
+    } else if health.active_shards_percent >= 90.0 {
+        Color::Yellow
+    } else {
+        Color::Red
+    };
+    let relocating_color = active_color(health.relocating_shards > 0, Color::Cyan);
+    let initializing_color = active_color(health.initializing_shards > 0, Color::Yellow);
+    let unassigned_color = active_color(health.unassigned_shards > 0, Color::Red);
+    let pending_color = active_color(health.number_of_pending_tasks > 0, Color::Yellow);
+
+    vec![
+        // Cluster Name
+        (
+            Line::from(vec![
+                icon(icons.cluster, Color::Gray),
+                Span::styled(health.cluster_name.clone(), bold),
+            ]),
+            None,
+        ),
+        // Status and Nodes: Total / Data
+        (
+            Line::from(vec![
+                icon(icons.status, status_color),
+                Span::styled(health.status.to_uppercase(), bold.fg(status_color)),
+            ]),
+            Some(Line::from(vec![
+                icon(icons.nodes, Color::Cyan),
+                Span::styled(health.number_of_nodes.to_string(), bold),
+                separator(),
+                icon(icons.data_nodes, Color::Blue),
+                Span::styled(health.number_of_data_nodes.to_string(), bold),
+            ])),
+        ),
+        // Shards: Primaries / Total Active, and Active %
+        (
+            Line::from(vec![
+                icon(icons.primaries, Color::Green),
+                Span::styled(health.active_primary_shards.to_string(), bold),
+                separator(),
+                icon(icons.active, Color::Magenta),
+                Span::styled(health.active_shards.to_string(), bold),
+            ]),
+            Some(Line::from(vec![
+                icon(icons.percent, percent_color),
+                Span::styled(
+                    format!("{:.1}", health.active_shards_percent),
+                    bold.fg(percent_color),
+                ),
+            ])),
+        ),
+        // Relocating / Initializing, and Unassigned
+        (
+            Line::from(vec![
+                icon(icons.relocating, relocating_color),
+                Span::styled(
+                    health.relocating_shards.to_string(),
+                    bold.fg(relocating_color),
+                ),
+                separator(),
+                icon(icons.initializing, initializing_color),
+                Span::styled(
+                    health.initializing_shards.to_string(),
+                    bold.fg(initializing_color),
+                ),
+            ]),
+            Some(Line::from(vec![
+                icon(icons.unassigned, unassigned_color),
+                Span::styled(
+                    health.unassigned_shards.to_string(),
+                    bold.fg(unassigned_color),
+                ),
+            ])),
+        ),
+        // Pending Tasks
+        (
+            Line::from(vec![
+                icon(icons.pending, pending_color),
+                Span::styled(
+                    health.number_of_pending_tasks.to_string(),
+                    bold.fg(pending_color),
+                ),
+            ]),
+            None,
+        ),
+    ]
+}
 
-        // Active %
-        let percent_color = if health.active_shards_percent >= 100.0 {
-            Color::Green
-        } else if health.active_shards_percent >= 90.0 {
-            Color::Yellow
-        } else {
-            Color::Red
-        };
-        let active_pct_line = Line::from(vec![
-            Span::styled("% ", Style::new().fg(percent_color)),
-            Span::styled(
-                format!("{:.1}", health.active_shards_percent),
-                Style::new().fg(percent_color).add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        buf.set_line(
-            shards_right_area.x,
-            shards_right_area.y,
-            &active_pct_line,
-            shards_right_area.width,
-        );
+// Icon padded by its display width to fill the reserved cells
+fn icon(glyph: &'static str, color: Color) -> Span<'static> {
+    let padding = ICON_WIDTH.saturating_sub(glyph.width()).max(1);
+    Span::styled(
+        format!("{}{}", glyph, " ".repeat(padding)),
+        Style::new().fg(color),
+    )
+}
 
-        // Row: Relocating and Unassigned
-        let [moving_area, unassigned_area] =
-            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .areas(moving_unassigned_area);
-
-        // Relocating and Initializing
-        let relocating_color = if health.relocating_shards > 0 {
-            Color::Cyan
-        } else {
-            Color::Gray
-        };
-        let initializing_color = if health.initializing_shards > 0 {
-            Color::Yellow
-        } else {
-            Color::Gray
-        };
-        let moving_line = Line::from(vec![
-            Span::styled("󰪹 ", Style::new().fg(relocating_color)),
-            Span::styled(
-                format!("{}", health.relocating_shards),
-                Style::new()
-                    .fg(relocating_color)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(" / ", Style::new().fg(Color::Gray)),
-            Span::styled("󰗖 ", Style::new().fg(initializing_color)),
-            Span::styled(
-                format!("{}", health.initializing_shards),
-                Style::new()
-                    .fg(initializing_color)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        buf.set_line(
-            moving_area.x,
-            moving_area.y,
-            &moving_line,
-            moving_area.width,
-        );
+// Splits a row at the half-way point, or further right when a left line needs the room
+fn split_row(row_area: Rect, rows: &[MetricRow]) -> (Rect, Rect) {
+    let widest_left = rows
+        .iter()
+        .filter(|(_, right)| right.is_some())
+        .map(|(left, _)| left.width() as u16 + 1)
+        .max()
+        .unwrap_or(0);
+    let left_width = widest_left.max(row_area.width / 2).min(row_area.width);
+
+    let [left, right] =
+        Layout::horizontal([Constraint::Length(left_width), Constraint::Min(0)]).areas(row_area);
+    (left, right)
+}
 
-        // Unassigned
-        let unassigned_color = if health.unassigned_shards > 0 {
-            Color::Red
-        } else {
-            Color::Gray
-        };
-        let unassigned_line = Line::from(vec![
-            Span::styled("󰀦 ", Style::new().fg(unassigned_color)),
-            Span::styled(
-                format!("{}", health.unassigned_shards),
-                Style::new()
-                    .fg(unassigned_color)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        buf.set_line(
-            unassigned_area.x,
-            unassigned_area.y,
-            &unassigned_line,
-            unassigned_area.width,
-        );
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn busy_cluster() -> ClusterHealth {
+        ClusterHealth {
+            cluster_name: "prod-logging".to_string(),
+            status: "yellow".to_string(),
+            number_of_nodes: 12,
+            number_of_data_nodes: 9,
+            active_primary_shards: 1_250,
+            active_shards: 2_480,
+            relocating_shards: 4,
+            initializing_shards: 16,
+            unassigned_shards: 20,
+            active_shards_percent: 99.2,
+            number_of_pending_tasks: 3,
+        }
+    }
 
-        // Row: Pending Tasks
-        let [pending_area] =
-            Layout::horizontal([Constraint::Percentage(100)]).areas(pending_tasks_area);
-
-        // Pending Tasks: 󱎫
-        let pending_color = if health.number_of_pending_tasks > 0 {
-            Color::Yellow
-        } else {
-            Color::Gray
-        };
-        let pending_line = Line::from(vec![
-            Span::styled("󱎫 ", Style::new().fg(pending_color)),
-            Span::styled(
-                format!("{}", health.number_of_pending_tasks),
-                Style::new().fg(pending_color).add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        buf.set_line(
-            pending_area.x,
-            pending_area.y,
-            &pending_line,
-            pending_area.width,
-        );
+    #[test]
+    fn test_metric_lines_fit_their_row() {
+        // Health pane at the default 70/30 split on a 120-column terminal, minus borders
+        let row = Rect::new(0, 0, 34, 1);
+        let health = busy_cluster();
+
+        for icons in [&HealthIcons::NERD, &HealthIcons::ASCII] {
+            let rows = metric_rows(&health, icons);
+            for (left, right) in &rows {
+                let (left_area, right_area) = split_row(row, &rows);
+                if let Some(right) = right {
+                    assert!(left.width() <= left_area.width as usize, "{:?}", left);
+                    assert!(right.width() <= right_area.width as usize, "{:?}", right);
+                } else {
+                    assert!(left.width() <= row.width as usize, "{:?}", left);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_icons_take_the_same_cells() {
+        for glyph in ["♥", "󰆼", "P", "#"] {
+            assert_eq!(icon(glyph, Color::Gray).width(), ICON_WIDTH);
+        }
     }
 }
@@ -6,9 +6,140 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
-use super::theme;
 use crate::app::App;
 
+/// One titled group of key bindings in the help.
+pub struct HelpSection {
+    pub title: &'static str,
+    /// Key and what it does
+    pub entries: &'static [(&'static str, &'static str)],
+}
+
+/// Key bindings shown in the help popup and written by `--dump-help`.
+pub const KEY_SECTIONS: &[HelpSection] = &[
+    HelpSection {
+        title: "Navigation",
+        entries: &[
+            ("j/↓", "Move selection down"),
+            ("k/↑", "Move selection up"),
+            ("PgUp/PgDn", "Page up/down"),
+            ("g/Home", "Go to first index"),
+            ("G/End", "Go to last index"),
+            ("]/[", "Next/previous yellow or red index"),
+        ],
+    },
+    HelpSection {
+        title: "Actions",
+        entries: &[
+            ("Tab/S-Tab", "Next/previous view"),
+            ("F1-F4", "Jump to view (also Alt+1-4)"),
+            ("Enter", "Show index details"),
+            ("x", "Exclude/include selected index from stats"),
+            ("P", "Pin/unpin selected index at the top"),
+            ("X", "Clear all exclusions"),
+            ("Ctrl+z", "Undo clearing the exclusions"),
+            (
+                "z",
+                "List excluded indices in a section below (Enter folds)",
+            ),
+            ("M", "Mark snapshot for the Compare view"),
+            ("w", "Watch selected index (Esc to return)"),
+            ("t", "Chart selected index rate (Esc to close)"),
+            (
+                "o",
+                "Cycle row coloring: gradient / index age / write share",
+            ),
+            ("u", "Roll up indices by prefix (Enter folds a group)"),
+            ("!", "Pin yellow/red indices above green ones"),
+            ("S", "Toggle primary / total (with replicas) size"),
+            ("B", "Toggle exact byte counts for sizes"),
+            ("T", "Show node hot threads"),
+            ("A", "Explain unassigned shard allocation"),
+            ("I", "Show cluster name, version and build"),
+            ("E", "Set replica count (needs --allow-destructive)"),
+            ("/", "Enter filter mode (jq)"),
+            ("y", "Copy filter to clipboard (OSC 52)"),
+            ("F", "Show why the filter doesn't compile"),
+            (
+                "Paste",
+                "Paste into the filter (replaces it outside filter mode)",
+            ),
+            ("Space", "Pause/resume refresh"),
+            ("p", "Freeze/unfreeze the chart (table stays live)"),
+            ("Ctrl+l", "Clear chart and rate history"),
+            ("Ctrl+r", "Retry now (clears error and backoff)"),
+        ],
+    },
+    HelpSection {
+        title: "Filter Mode",
+        entries: &[
+            ("←/→", "Move cursor left/right"),
+            ("Ctrl+←/→", "Move cursor by word"),
+            ("Home/End", "Jump to start/end of filter"),
+            ("Backspace", "Delete character before cursor"),
+            ("Delete", "Delete character at cursor"),
+            ("Ctrl+u", "Clear filter"),
+            ("Ctrl+y", "Copy filter to clipboard"),
+            (
+                "Ctrl+e",
+                "Show why the filter doesn't compile (else end of line)",
+            ),
+            ("Esc/Enter", "Exit filter input"),
+        ],
+    },
+    HelpSection {
+        title: "Sorting",
+        entries: &[
+            ("←(h)/→(l)", "Change sort column"),
+            ("r", "Reverse sort order"),
+        ],
+    },
+    HelpSection {
+        title: "Display",
+        entries: &[
+            ("1", "Toggle graph visibility"),
+            ("a", "Toggle moving-average line on graph"),
+            ("m", "Cycle graph metric (rate/throughput/docs/unassigned)"),
+            ("2", "Toggle cluster health visibility"),
+            ("3", "Toggle indices table visibility"),
+            ("</>", "Shrink/grow graph next to cluster health"),
+            ("H/L", "Narrow/widen the sort column"),
+            ("v", "Stack graph above health / side by side"),
+            (".", "Toggle system indices"),
+            (",", "Toggle system indices in list only"),
+            (";", "Toggle system indices in rate total only"),
+            ("+/-", "Increase/decrease refresh interval"),
+            ("}/{", "Widen/narrow the rate smoothing window"),
+            ("c/C", "Cycle colormap of the sort column"),
+            ("i", "Reverse gradient direction"),
+        ],
+    },
+    HelpSection {
+        title: "General",
+        entries: &[("q/Esc", "Quit / Close popup")],
+    },
+];
+
+pub const FILTER_FIELDS: &str = ".name, .doc_count, .rate_per_sec, .health, .size_bytes";
+
+/// jq filter and what it selects
+pub const FILTER_EXAMPLES: &[(&str, &str)] = &[
+    ("select(.name == \"idx-1\")", "Exact name match"),
+    ("select(.doc_count > 1000)", "Docs > 1000"),
+    ("select(.health != \"green\")", "Problematic health"),
+    ("select(.rate_per_sec > 5)", "High rate"),
+    ("select(.name | contains(\"test\"))", "Name contains 'test'"),
+    (
+        "select(.name | test(\"idx-[0-9]+\"))",
+        "Regex match on name",
+    ),
+    ("select(.health | match(\"red\"))", "Match health 'red'"),
+    (".doc_count > 1000", "Predicate, no select needed"),
+];
+
+pub const FILTER_COMBINE_NOTE: &str =
+    "Combine with: and, or, not (e.g., select(.a > 1 and .b < 5))";
+
 pub struct HelpPopup<'a> {
     app: &'a App,
 }
@@ -32,202 +163,7 @@ impl<'a> Widget for HelpPopup<'a> {
         // Clear the popup area
         Clear.render(popup_area, buf);
 
-        let help_lines = vec![
-            Line::from(Span::styled("Keyboard Shortcuts", theme::TITLE)),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "  Navigation",
-                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            )]),
-            Line::from(vec![
-                Span::styled("  j/↓       ", Style::new().fg(Color::Green)),
-                Span::raw("Move selection down"),
-            ]),
-            Line::from(vec![
-                Span::styled("  k/↑       ", Style::new().fg(Color::Green)),
-                Span::raw("Move selection up"),
-            ]),
-            Line::from(vec![
-                Span::styled("  PgUp/PgDn ", Style::new().fg(Color::Green)),
-                Span::raw("Page up/down"),
-            ]),
-            Line::from(vec![
-                Span::styled("  g/Home    ", Style::new().fg(Color::Green)),
-                Span::raw("Go to first index"),
-            ]),
-            Line::from(vec![
-                Span::styled("  G/End     ", Style::new().fg(Color::Green)),
-                Span::raw("Go to last index"),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "  Actions",
-                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            )]),
-            Line::from(vec![
-                Span::styled("  Enter     ", Style::new().fg(Color::Green)),
-                Span::raw("Show index details"),
-            ]),
-            Line::from(vec![
-                Span::styled("  x         ", Style::new().fg(Color::Green)),
-                Span::raw("Exclude/include selected index from stats"),
-            ]),
-            Line::from(vec![
-                Span::styled("  X         ", Style::new().fg(Color::Green)),
-                Span::raw("Clear all exclusions"),
-            ]),
-            Line::from(vec![
-                Span::styled("  /         ", Style::new().fg(Color::Green)),
-                Span::raw("Enter filter mode (jq)"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Space     ", Style::new().fg(Color::Green)),
-                Span::raw("Pause/resume refresh"),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "  Filter Mode",
-                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            )]),
-            Line::from(vec![
-                Span::styled("  ←/→       ", Style::new().fg(Color::Green)),
-                Span::raw("Move cursor left/right"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Ctrl+←/→  ", Style::new().fg(Color::Green)),
-                Span::raw("Move cursor by word"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Home/End  ", Style::new().fg(Color::Green)),
-                Span::raw("Jump to start/end of filter"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Backspace ", Style::new().fg(Color::Green)),
-                Span::raw("Delete character before cursor"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Delete    ", Style::new().fg(Color::Green)),
-                Span::raw("Delete character at cursor"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Ctrl+u    ", Style::new().fg(Color::Green)),
-                Span::raw("Clear filter"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Esc/Enter ", Style::new().fg(Color::Green)),
-                Span::raw("Exit filter input"),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "  Sorting",
-                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            )]),
-            Line::from(vec![
-                Span::styled("  ←(h)/→(l) ", Style::new().fg(Color::Green)),
-                Span::raw("Change sort column"),
-            ]),
-            Line::from(vec![
-                Span::styled("  r         ", Style::new().fg(Color::Green)),
-                Span::raw("Reverse sort order"),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "  Display",
-                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            )]),
-            Line::from(vec![
-                Span::styled("  1         ", Style::new().fg(Color::Green)),
-                Span::raw("Toggle graph visibility"),
-            ]),
-            Line::from(vec![
-                Span::styled("  2         ", Style::new().fg(Color::Green)),
-                Span::raw("Toggle cluster health visibility"),
-            ]),
-            Line::from(vec![
-                Span::styled("  3         ", Style::new().fg(Color::Green)),
-                Span::raw("Toggle indices table visibility"),
-            ]),
-            Line::from(vec![
-                Span::styled("  .         ", Style::new().fg(Color::Green)),
-                Span::raw("Toggle system indices (dot-prefixed)"),
-            ]),
-            Line::from(vec![
-                Span::styled("  +/-       ", Style::new().fg(Color::Green)),
-                Span::raw("Increase/decrease refresh interval"),
-            ]),
-            Line::from(vec![
-                Span::styled("  c/C       ", Style::new().fg(Color::Green)),
-                Span::raw("Cycle colormap forward/backward"),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("  q/Esc     ", Style::new().fg(Color::Green)),
-                Span::raw("Quit / Close popup"),
-            ]),
-            Line::from(""),
-            Line::from(Span::styled("jq Filter Syntax", theme::TITLE)),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("  Fields:   ", Style::new().fg(Color::Yellow)),
-                Span::raw(".name, .doc_count, .rate_per_sec, .health, .size_bytes"),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "  Examples",
-                Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            )]),
-            Line::from(vec![
-                Span::styled(
-                    "  select(.name == \"idx-1\")         ",
-                    Style::new().fg(Color::Cyan),
-                ),
-                Span::raw("Exact name match"),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "  select(.doc_count > 1000)        ",
-                    Style::new().fg(Color::Cyan),
-                ),
-                Span::raw("Docs > 1000"),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "  select(.health != \"green\")       ",
-                    Style::new().fg(Color::Cyan),
-                ),
-                Span::raw("Problematic health"),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "  select(.rate_per_sec > 5)        ",
-                    Style::new().fg(Color::Cyan),
-                ),
-                Span::raw("High rate"),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "  select(.name | contains(\"test\")) ",
-                    Style::new().fg(Color::Cyan),
-                ),
-                Span::raw("Name contains 'test'"),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "  select(.name | test(\"idx-[0-9]+\")) ",
-                    Style::new().fg(Color::Cyan),
-                ),
-                Span::raw("Regex match on name"),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "  select(.health | match(\"red\"))    ",
-                    Style::new().fg(Color::Cyan),
-                ),
-                Span::raw("Match health 'red'"),
-            ]),
-            Line::from(""),
-            Line::from("  Combine with: and, or, not (e.g., select(.a > 1 and .b < 5))"),
-        ];
+        let help_lines = help_lines(self.app);
 
         // Apply scroll offset
         let visible_height = popup_height.saturating_sub(2) as usize; // Account for border
@@ -251,3 +187,122 @@ impl<'a> Widget for HelpPopup<'a> {
             .render(popup_area, buf);
     }
 }
+
+// Key column wide enough for the longest key, and at least the classic 10 columns
+fn key_width(entries: &[(&str, &str)], min: usize) -> usize {
+    entries
+        .iter()
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(min)
+}
+
+fn help_lines(app: &App) -> Vec<Line<'static>> {
+    let section_style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(Span::styled("Keyboard Shortcuts", app.theme.title)),
+        Line::from(""),
+    ];
+
+    for section in KEY_SECTIONS {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", section.title),
+            section_style,
+        )));
+        let width = key_width(section.entries, 10);
+        for (key, action) in section.entries {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<width$}", key, width = width),
+                    Style::new().fg(Color::Green),
+                ),
+                Span::raw(*action),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(Span::styled(
+        "jq Filter Syntax",
+        app.theme.title,
+    )));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  Fields:   ", Style::new().fg(Color::Yellow)),
+        Span::raw(FILTER_FIELDS),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("  Examples", section_style)));
+    let width = key_width(FILTER_EXAMPLES, 0) + 1;
+    for (filter, description) in FILTER_EXAMPLES {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<width$}", filter, width = width),
+                Style::new().fg(Color::Cyan),
+            ),
+            Span::raw(*description),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!("  {}", FILTER_COMBINE_NOTE)));
+    lines
+}
+
+// Table cells can't hold a raw `|`
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+/// The help content as a Markdown document, from the same data the popup shows.
+pub fn help_markdown() -> String {
+    let mut out = String::from("# esticli keyboard shortcuts\n");
+    for section in KEY_SECTIONS {
+        out.push_str(&format!(
+            "\n## {}\n\n| Key | Action |\n|-----|--------|\n",
+            section.title
+        ));
+        for (key, action) in section.entries {
+            out.push_str(&format!(
+                "| `{}` | {} |\n",
+                markdown_cell(key),
+                markdown_cell(action)
+            ));
+        }
+    }
+
+    out.push_str("\n# jq filter syntax\n\nFields: ");
+    let fields: Vec<String> = FILTER_FIELDS
+        .split(", ")
+        .map(|field| format!("`{}`", field))
+        .collect();
+    out.push_str(&fields.join(", "));
+    out.push_str("\n\n| Filter | Description |\n|--------|-------------|\n");
+    for (filter, description) in FILTER_EXAMPLES {
+        out.push_str(&format!(
+            "| `{}` | {} |\n",
+            markdown_cell(filter),
+            markdown_cell(description)
+        ));
+    }
+    out.push_str(&format!("\n{}\n", FILTER_COMBINE_NOTE));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_covers_every_binding() {
+        let markdown = help_markdown();
+        for section in KEY_SECTIONS {
+            assert!(markdown.contains(&format!("## {}", section.title)));
+            for (key, _) in section.entries {
+                assert!(markdown.contains(&format!("`{}`", markdown_cell(key))));
+            }
+        }
+        // Pipes inside filters are escaped so the table keeps its columns
+        assert!(markdown.contains(r#"| `select(.name \| contains("test"))` |"#));
+    }
+}
@@ -0,0 +1,41 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Clear, Widget},
+};
+
+use crate::app::App;
+use crate::ui::chart::RateChart;
+use crate::ui::types::ChartMetric;
+
+/// Overlay plotting the rate history of a single index.
+pub struct IndexChartPopup<'a> {
+    app: &'a App,
+    index: &'a str,
+}
+
+impl<'a> IndexChartPopup<'a> {
+    pub fn new(app: &'a App, index: &'a str) -> Self {
+        Self { app, index }
+    }
+}
+
+impl<'a> Widget for IndexChartPopup<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Calculate popup area (80% wide, 60% tall, centered)
+        let popup_width = (area.width as f32 * 0.8) as u16;
+        let popup_height = (area.height as f32 * 0.6) as u16;
+        let popup_x = (area.width - popup_width) / 2;
+        let popup_y = (area.height - popup_height) / 2;
+        let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+        // Clear the popup area
+        Clear.render(popup_area, buf);
+
+        // Only indexing rates are kept per index, whatever the main chart shows
+        let history = self.app.index_rate_history_vec(self.index);
+        RateChart::for_index(self.app, self.index, history)
+            .with_metric(ChartMetric::IndexingRate)
+            .render(popup_area, buf);
+    }
+}
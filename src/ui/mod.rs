@@ -1,56 +1,140 @@
 use ratatui::{
-    layout::{Constraint, Layout},
+    layout::{Constraint, Layout, Rect},
     widgets::TableState,
     Frame,
 };
 
 pub mod chart;
+pub mod compare;
 pub mod details_popup;
 pub mod footer;
 pub mod header;
 pub mod health;
 pub mod help_popup;
+pub mod index_chart;
+pub mod nodes;
+pub mod problems;
+pub mod replica_prompt;
+pub mod tab_bar;
 pub mod table;
+pub mod text_popup;
 pub mod theme;
 pub mod types;
+pub mod watch;
 
 use crate::app::App;
+use crate::ui::types::{RowLayout, View};
 use chart::RateChart;
+use compare::CompareTable;
 use details_popup::DetailsPopup;
 use footer::Footer;
 use header::Header;
 use health::ClusterHealthWidget;
 use help_popup::HelpPopup;
+use index_chart::IndexChartPopup;
+use nodes::NodesView;
+use problems::ProblemsBanner;
+use replica_prompt::ReplicaPromptPopup;
+use tab_bar::TabBar;
 use table::IndicesTable;
+use text_popup::TextPopup;
+use watch::WatchView;
+
+const CHART_HEIGHT: u16 = 10;
+const HEALTH_HEIGHT: u16 = 7;
 
 pub fn draw(frame: &mut Frame, app: &App) {
-    // Build dynamic layout based on visibility settings
-    let mut constraints = vec![Constraint::Length(3)]; // Header always visible
+    // Header and footer are always visible, one line each in compact mode
+    let bar_height = if app.compact { 1 } else { 3 };
+    let [header_area, problems_area, tabs_area, body_area, footer_area] = Layout::vertical([
+        Constraint::Length(bar_height),
+        Constraint::Length(ProblemsBanner::height(app)),
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(bar_height),
+    ])
+    .areas(frame.area());
 
-    if app.show_graph || app.show_health {
-        constraints.push(Constraint::Length(8)); // Row for graph/health
+    frame.render_widget(Header::new(app), header_area);
+    frame.render_widget(ProblemsBanner::new(app), problems_area);
+    frame.render_widget(TabBar::new(app), tabs_area);
+
+    // Watch mode replaces the active view until it's dismissed
+    if let Some(ref watch) = app.watch {
+        frame.render_widget(WatchView::new(app, watch), body_area);
+    } else {
+        match app.view {
+            View::Overview => draw_overview(frame, app, body_area),
+            View::Indices => draw_indices_table(frame, app, body_area),
+            View::Compare => {
+                let mut state = TableState::default().with_selected(app.selected_index);
+                frame.render_stateful_widget(CompareTable::new(app), body_area, &mut state);
+            }
+            View::Nodes => frame.render_widget(NodesView::new(app), body_area),
+        }
     }
-    if app.show_indices {
-        constraints.push(Constraint::Min(0)); // Table
+
+    frame.render_widget(Footer::new(app), footer_area);
+
+    // Details popup overlay
+    if app.details.show_popup {
+        frame.render_widget(DetailsPopup::new(app), frame.area());
     }
-    constraints.push(Constraint::Length(3)); // Footer always visible
 
-    let areas = Layout::vertical(constraints).split(frame.area());
-    let mut area_iter = areas.iter();
+    // Single-index rate chart overlay
+    if let Some(ref index) = app.index_chart {
+        frame.render_widget(IndexChartPopup::new(app, index), frame.area());
+    }
+
+    // Fetched-text popup overlay (hot threads, allocation explain)
+    if app.text_popup.show_popup {
+        frame.render_widget(TextPopup::new(app), frame.area());
+    }
 
-    // Header
-    if let Some(&area) = area_iter.next() {
-        frame.render_widget(Header::new(app), area);
+    // Replica count prompt overlay
+    if let Some(ref prompt) = app.replica_prompt {
+        frame.render_widget(ReplicaPromptPopup::new(app, prompt), frame.area());
     }
 
+    // Help popup overlay
+    if app.show_help_popup {
+        frame.render_widget(HelpPopup::new(app), frame.area());
+    }
+}
+
+// Chart and health row above the indices table, each part toggleable
+fn draw_overview(frame: &mut Frame, app: &App, area: Rect) {
+    // Build dynamic layout based on visibility settings
+    let mut constraints = Vec::new();
+
+    let stacked = app.show_graph && app.show_health && app.row_layout == RowLayout::Vertical;
+    if stacked {
+        constraints.push(Constraint::Length(CHART_HEIGHT + HEALTH_HEIGHT)); // Graph above health
+    } else if app.show_graph || app.show_health {
+        constraints.push(Constraint::Length(CHART_HEIGHT)); // Row for graph/health
+    }
+    constraints.push(Constraint::Min(0)); // Table, or empty space when hidden
+
+    let areas = Layout::vertical(constraints).split(area);
+    let mut area_iter = areas.iter();
+
     // Charts and Health (if visible)
     if app.show_graph || app.show_health {
         if let Some(&area) = area_iter.next() {
             match (app.show_graph, app.show_health) {
+                (true, true) if stacked => {
+                    let [chart_area, health_area] = Layout::vertical([
+                        Constraint::Length(CHART_HEIGHT),
+                        Constraint::Length(HEALTH_HEIGHT),
+                    ])
+                    .areas(area);
+                    frame.render_widget(RateChart::new(app), chart_area);
+                    frame.render_widget(ClusterHealthWidget::new(app), health_area);
+                }
                 (true, true) => {
                     let [chart_area, health_area] = Layout::horizontal([
-                        Constraint::Percentage(70),
-                        Constraint::Percentage(30),
+                        Constraint::Percentage(app.chart_split),
+                        Constraint::Percentage(100 - app.chart_split),
                     ])
                     .areas(area);
                     frame.render_widget(RateChart::new(app), chart_area);
@@ -70,23 +154,12 @@ pub fn draw(frame: &mut Frame, app: &App) {
     // Table (if visible)
     if app.show_indices {
         if let Some(&area) = area_iter.next() {
-            let mut state = TableState::default().with_selected(app.selected_index);
-            frame.render_stateful_widget(IndicesTable::new(app), area, &mut state);
+            draw_indices_table(frame, app, area);
         }
     }
+}
 
-    // Footer
-    if let Some(&area) = area_iter.next() {
-        frame.render_widget(Footer::new(app), area);
-    }
-
-    // Details popup overlay
-    if app.details.show_popup {
-        frame.render_widget(DetailsPopup::new(app), frame.area());
-    }
-
-    // Help popup overlay
-    if app.show_help_popup {
-        frame.render_widget(HelpPopup::new(app), frame.area());
-    }
+fn draw_indices_table(frame: &mut Frame, app: &App, area: Rect) {
+    let mut state = TableState::default().with_selected(app.selected_index);
+    frame.render_stateful_widget(IndicesTable::new(app), area, &mut state);
 }
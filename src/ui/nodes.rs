@@ -0,0 +1,94 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use crate::app::App;
+
+// A node this far above/below the per-node average is highlighted
+const IMBALANCE_THRESHOLD: f64 = 0.2;
+
+/// One horizontal bar per node, sized by its shard count across all indices.
+pub struct NodesView<'a> {
+    app: &'a App,
+}
+
+impl<'a> NodesView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl<'a> Widget for NodesView<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let distribution = &self.app.shard_distribution;
+        let nodes = &distribution.nodes;
+
+        let total: usize = nodes.iter().map(|n| n.shards).sum();
+        let average = if nodes.is_empty() {
+            0.0
+        } else {
+            total as f64 / nodes.len() as f64
+        };
+        let max = nodes.iter().map(|n| n.shards).max().unwrap_or(0).max(1);
+
+        let name_width = nodes.iter().map(|n| n.node.len()).max().unwrap_or(0);
+        // Leave room for borders, the name column and the " 1234 (567P)" suffix
+        let bar_width = (area.width as usize).saturating_sub(name_width + 18);
+
+        let mut lines: Vec<Line> = nodes
+            .iter()
+            .map(|node| {
+                let color = if node.shards as f64 > average * (1.0 + IMBALANCE_THRESHOLD) {
+                    Color::Red
+                } else if (node.shards as f64) < average * (1.0 - IMBALANCE_THRESHOLD) {
+                    Color::Yellow
+                } else {
+                    Color::Green
+                };
+                let filled = node.shards * bar_width / max;
+
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$} ", node.node, width = name_width),
+                        Style::new().fg(Color::Cyan),
+                    ),
+                    Span::styled("█".repeat(filled), Style::new().fg(color)),
+                    Span::styled(
+                        format!(" {} ({}P)", node.shards, node.primaries),
+                        self.app.theme.text,
+                    ),
+                ])
+            })
+            .collect();
+
+        if nodes.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No shard information available",
+                Style::new().fg(Color::DarkGray),
+            )));
+        }
+
+        if distribution.unassigned > 0 {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("Unassigned: {}", distribution.unassigned),
+                self.app.theme.error,
+            )));
+        }
+
+        let title = format!(" Shard Distribution (avg {:.1} per node) ", average);
+
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(self.app.theme.border)
+                    .title(title),
+            )
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,166 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::app::App;
+use crate::models::ClusterHealth;
+
+/// One-line summary of everything currently wrong; only drawn when there is something to say.
+pub struct ProblemsBanner<'a> {
+    app: &'a App,
+}
+
+impl<'a> ProblemsBanner<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+
+    // Row height to reserve: nothing at all while the cluster is healthy
+    pub fn height(app: &App) -> u16 {
+        if problems(app).is_empty() {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+impl<'a> Widget for ProblemsBanner<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let problems = problems(self.app);
+        if problems.is_empty() {
+            return;
+        }
+
+        // Red when anything is critical, yellow for warnings only
+        let color = if problems.iter().any(|(_, critical)| *critical) {
+            Color::Red
+        } else {
+            Color::Yellow
+        };
+
+        let mut spans = vec![Span::styled(" ⚠ ", Style::new().fg(Color::Black).bg(color))];
+        for (i, (text, _)) in problems.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(" · ", Style::new().fg(Color::DarkGray)));
+            } else {
+                spans.push(Span::raw(" "));
+            }
+            spans.push(Span::styled(
+                text,
+                Style::new().fg(color).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+}
+
+fn problems(app: &App) -> Vec<(String, bool)> {
+    problems_of(
+        &app.cluster_health,
+        app.error.as_deref(),
+        app.health_error.as_deref(),
+    )
+}
+
+// Each problem with whether it's critical (red) rather than a warning
+fn problems_of(
+    health: &ClusterHealth,
+    error: Option<&str>,
+    health_error: Option<&str>,
+) -> Vec<(String, bool)> {
+    let mut problems = Vec::new();
+
+    if let Some(error) = error {
+        problems.push((format!("Fetch error: {} ([Ctrl+r] to retry)", error), true));
+    }
+    match health.status.as_str() {
+        "yellow" => problems.push(("Health YELLOW".to_string(), false)),
+        "red" => problems.push(("Health RED".to_string(), true)),
+        "unknown" => problems.push((
+            match health_error {
+                Some(e) => format!("Health unknown: {}", e),
+                None => "Health unknown".to_string(),
+            },
+            false,
+        )),
+        _ => {}
+    }
+    if health.unassigned_shards > 0 {
+        problems.push((
+            format!("{} unassigned shards", health.unassigned_shards),
+            true,
+        ));
+    }
+    if health.number_of_pending_tasks > 0 {
+        problems.push((
+            format!("{} pending tasks", health.number_of_pending_tasks),
+            false,
+        ));
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_healthy_cluster_has_no_problems() {
+        let health = ClusterHealth {
+            status: "green".to_string(),
+            ..Default::default()
+        };
+        assert!(problems_of(&health, None, None).is_empty());
+    }
+
+    #[test]
+    fn test_problems_are_aggregated() {
+        let health = ClusterHealth {
+            status: "yellow".to_string(),
+            unassigned_shards: 3,
+            number_of_pending_tasks: 2,
+            ..Default::default()
+        };
+
+        let found = problems_of(&health, Some("connection refused"), None);
+        let texts: Vec<&str> = found.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "Fetch error: connection refused ([Ctrl+r] to retry)",
+                "Health YELLOW",
+                "3 unassigned shards",
+                "2 pending tasks"
+            ]
+        );
+
+        // Yellow and pending tasks alone are only warnings
+        let warnings = ClusterHealth {
+            unassigned_shards: 0,
+            ..health
+        };
+        assert!(problems_of(&warnings, None, None)
+            .iter()
+            .all(|(_, critical)| !critical));
+    }
+
+    #[test]
+    fn test_failed_health_is_a_warning_with_reason() {
+        let health = ClusterHealth {
+            status: "unknown".to_string(),
+            ..Default::default()
+        };
+        let found = problems_of(&health, None, Some("request timeout"));
+        assert_eq!(
+            found,
+            [("Health unknown: request timeout".to_string(), false)]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::app::replicas::ReplicaPrompt;
+use crate::app::App;
+use crate::utils::split_at_cursor;
+
+const PROMPT_WIDTH: u16 = 60;
+const PROMPT_HEIGHT: u16 = 5;
+
+/// Small centered box asking for the new replica count of the selected index.
+pub struct ReplicaPromptPopup<'a> {
+    app: &'a App,
+    prompt: &'a ReplicaPrompt,
+}
+
+impl<'a> ReplicaPromptPopup<'a> {
+    pub fn new(app: &'a App, prompt: &'a ReplicaPrompt) -> Self {
+        Self { app, prompt }
+    }
+}
+
+impl<'a> Widget for ReplicaPromptPopup<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = PROMPT_WIDTH.min(area.width);
+        let height = PROMPT_HEIGHT.min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        Clear.render(popup_area, buf);
+
+        let value = self.prompt.input.value();
+        let (before, after) = split_at_cursor(value, self.prompt.input.cursor());
+        let input_style = self.app.theme.text.add_modifier(Modifier::BOLD);
+        let input = Line::from(vec![
+            Span::styled("number_of_replicas: ", Style::new().fg(Color::Yellow)),
+            Span::styled(before, input_style),
+            Span::styled("▏", self.app.theme.text.add_modifier(Modifier::RAPID_BLINK)),
+            Span::styled(after, input_style),
+        ]);
+
+        // Rejected input first, then the auto-expand caveat
+        let note = if let Some(ref error) = self.prompt.error {
+            Line::styled(error.as_str(), self.app.theme.error)
+        } else if let Some(ref range) = self.prompt.auto_expand {
+            Line::styled(
+                format!("auto_expand_replicas {} overrides a fixed count", range),
+                Style::new().fg(Color::Yellow),
+            )
+        } else {
+            Line::default()
+        };
+
+        let title = Line::from(vec![
+            Span::raw(format!(" Replicas of {} ", self.prompt.index)),
+            Span::styled(
+                "[Enter] Apply  [Esc] Cancel ",
+                Style::new().fg(Color::DarkGray),
+            ),
+        ]);
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::new().fg(Color::Cyan));
+
+        Paragraph::new(vec![input, note])
+            .block(block)
+            .render(popup_area, buf);
+    }
+}
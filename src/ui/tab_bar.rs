@@ -0,0 +1,35 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::Line,
+    widgets::{Tabs, Widget},
+};
+
+use crate::app::App;
+use crate::ui::types::View;
+
+pub struct TabBar<'a> {
+    app: &'a App,
+}
+
+impl<'a> TabBar<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl<'a> Widget for TabBar<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let titles = View::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, view)| Line::from(format!("{} {}", i + 1, view.title())));
+
+        Tabs::new(titles)
+            .select(self.app.view.position())
+            .style(self.app.theme.time)
+            .highlight_style(self.app.theme.title)
+            .divider("|")
+            .render(area, buf);
+    }
+}
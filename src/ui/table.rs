@@ -1,14 +1,21 @@
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Rect},
+    layout::{Alignment, Constraint, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Row, StatefulWidget, Table, TableState},
+    widgets::{
+        Block, Borders, Cell, Paragraph, Row, StatefulWidget, Table, TableState, Widget, Wrap,
+    },
 };
 
-use super::theme;
+use crate::app::rollup::{RowKind, TableRow};
 use crate::app::App;
-use crate::ui::types::{SortColumn, SortOrder};
+use crate::models::{IndexRate, StatGroup};
+use crate::ui::types::{ColorMode, GradientScale, SortColumn, SortOrder};
+use crate::utils::split_at_cursor;
+use chrono::Utc;
+use std::borrow::Cow;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub struct IndicesTable<'a> {
     app: &'a App,
@@ -24,24 +31,36 @@ impl<'a> StatefulWidget for IndicesTable<'a> {
     type State = TableState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        // Get filtered indices once
-        let filtered_indices = self.app.filtered_indices();
-        let filtered_count = filtered_indices.len();
+        // Get the displayed rows once (indices, or groups when rolled up)
+        let table_rows = self.app.table_rows();
+        let row_count = table_rows.len();
+        let filtered_count = self.app.filtered_indices().len();
         let total_count = self.app.indices.len();
 
+        // Label which size is shown: primaries only or with replicas
+        let size_label = if self.app.show_total_size {
+            "Size (all)"
+        } else {
+            "Size (pri)"
+        };
+        let rate_label = format!("Rate ({})", self.app.rate_unit.suffix());
         let header_cells = [
             ("Index Name", SortColumn::Name),
             ("Docs Count", SortColumn::DocCount),
-            ("Rate (/s)", SortColumn::Rate),
-            ("Size", SortColumn::Size),
+            (rate_label.as_str(), SortColumn::Rate),
+            (size_label, SortColumn::Size),
             ("Health", SortColumn::Health),
+            ("Unassigned", SortColumn::Unassigned),
+            ("Replicas", SortColumn::Replicas),
+            ("Idx Latency", SortColumn::IndexLatency),
+            ("Qry Latency", SortColumn::QueryLatency),
         ]
-        .iter()
+        .into_iter()
         .map(|(name, col)| {
             let mut style = Style::new().add_modifier(Modifier::BOLD);
             let mut text = name.to_string();
 
-            if *col == self.app.sort.column {
+            if col == self.app.sort.column {
                 style = style.fg(Color::Yellow);
                 let arrow = match self.app.sort.order {
                     SortOrder::Ascending => " ▲",
@@ -54,25 +73,81 @@ impl<'a> StatefulWidget for IndicesTable<'a> {
         });
 
         let header = Row::new(header_cells)
-            .style(Style::new().bg(Color::DarkGray))
+            .style(self.app.theme.table_header)
             .height(1);
 
-        // Find max value for gradient calculation based on current sort column
-        let max_value: f64 = filtered_indices
+        // Find value range for gradient calculation based on current sort column
+        let (min_value, max_value) = table_rows
             .iter()
+            .filter(|row| !is_excluded_row(row))
+            .map(|row| &row.index)
+            .filter(|i| !i.closed)
             .map(|i| match self.app.sort.column {
                 SortColumn::Name | SortColumn::Health => 0.0,
                 SortColumn::DocCount => i.doc_count as f64,
                 SortColumn::Rate => i.rate_per_sec,
-                SortColumn::Size => i.size_bytes as f64,
+                SortColumn::Size => i.size(self.app.show_total_size) as f64,
+                SortColumn::Unassigned => i.unassigned_shards as f64,
+                SortColumn::Replicas => replicas_value(i),
+                SortColumn::IndexLatency => i.index_latency_ms.unwrap_or(0.0),
+                SortColumn::QueryLatency => i.query_latency_ms.unwrap_or(0.0),
             })
-            .fold(0.0_f64, f64::max);
+            .fold((f64::MAX, 0.0_f64), |(min, max), v| {
+                (min.min(v), max.max(v))
+            });
+
+        // Only build rows for the visible window; the table state is made relative to it
+        let available_height = area.height.saturating_sub(3) as usize;
+        let offset = visible_offset(row_count, available_height, self.app.selected_index);
+        let visible_end = (offset + available_height).min(row_count);
+        *state = TableState::default().with_selected(
+            self.app
+                .selected_index
+                .map(|selected| selected.saturating_sub(offset)),
+        );
+
+        // Proportional to the user-adjustable widths, always filling the table
+        let widths = self.app.column_widths.map(Constraint::Fill);
+
+        // Width of the name column, laid out the same way the table does inside its borders
+        let name_width = Layout::horizontal(widths).spacing(1).split(Rect::new(
+            0,
+            0,
+            area.width
+                .saturating_sub(2)
+                .saturating_sub(self.app.selection_style.symbol().width() as u16),
+            1,
+        ))[0]
+            .width as usize;
+
+        let cluster_rate = self.app.total_cluster_rate();
 
-        let rows: Vec<Row> = filtered_indices
+        let rows: Vec<Row> = table_rows[offset..visible_end]
             .iter()
-            .map(|index| {
-                let style = match self.app.sort.column {
-                    SortColumn::Name | SortColumn::Health => {
+            .enumerate()
+            .map(|(position, row)| {
+                let index = &row.index;
+                let style = match (self.app.color_mode, self.app.sort.column) {
+                    (ColorMode::Age, _) => match index.creation_date {
+                        Some(created) => {
+                            let tier = age_tier(Utc::now() - created, &self.app.age_tiers);
+                            Style::new().fg(AGE_TIER_COLORS[tier.min(AGE_TIER_COLORS.len() - 1)])
+                        }
+                        None => Style::new(),
+                    },
+                    (ColorMode::Share, _) => match write_share(index.rate_per_sec, cluster_rate) {
+                        Some(share) => {
+                            // Hot end of the rate colormap for the dominant writers
+                            let mut position = 1.0 - share as f32;
+                            if self.app.invert_gradient {
+                                position = 1.0 - position;
+                            }
+                            let colormap = self.app.column_colormap(SortColumn::Rate);
+                            Style::new().fg(colormap.color_at(position))
+                        }
+                        None => Style::new().fg(Color::DarkGray),
+                    },
+                    (_, SortColumn::Name | SortColumn::Health) => {
                         let color = match index.health.as_str() {
                             "green" => Color::Green,
                             "yellow" => Color::Yellow,
@@ -86,46 +161,101 @@ impl<'a> StatefulWidget for IndicesTable<'a> {
                         let current_value = match self.app.sort.column {
                             SortColumn::DocCount => index.doc_count as f64,
                             SortColumn::Rate => index.rate_per_sec,
-                            SortColumn::Size => index.size_bytes as f64,
+                            SortColumn::Size => index.size(self.app.show_total_size) as f64,
+                            SortColumn::Unassigned => index.unassigned_shards as f64,
+                            SortColumn::Replicas => replicas_value(index),
+                            SortColumn::IndexLatency => index.index_latency_ms.unwrap_or(0.0),
+                            SortColumn::QueryLatency => index.query_latency_ms.unwrap_or(0.0),
                             _ => 0.0,
                         };
 
-                        // Use logarithmic scale to spread colors more evenly
-                        let position = if max_value > 0.0 {
-                            let log_current = (1.0 + current_value).ln();
-                            let log_max = (1.0 + max_value).ln();
-                            1.0 - (log_current / log_max) as f32
-                        } else {
-                            1.0 // No gradient or zero values
-                        };
-
-                        let color = self.app.colormap.color_at(position);
+                        let mut position = gradient_position(
+                            current_value,
+                            min_value,
+                            max_value,
+                            self.app.gradient_scale,
+                        );
+                        if self.app.invert_gradient {
+                            position = 1.0 - position;
+                        }
+                        let color = self
+                            .app
+                            .column_colormap(self.app.sort.column)
+                            .color_at(position);
                         Style::new().fg(color)
                     }
                 };
 
-                let cells = [
-                    Cell::from(index.name.clone()),
-                    Cell::from(index.doc_count_human()),
-                    Cell::from(index.rate_human()),
-                    Cell::from(index.size_human()),
-                    Cell::from(index.health.clone()),
-                ];
+                // Recent indexing failures trump the gradient; closed and excluded indices
+                // are greyed out
+                let style = if let RowKind::Excluded { .. } = row.kind {
+                    Style::new()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD)
+                } else if row.kind == RowKind::ExcludedMember {
+                    Style::new().fg(Color::DarkGray)
+                } else if index.closed {
+                    Style::new()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::ITALIC)
+                } else if index.failed_delta > 0 {
+                    Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else {
+                    style
+                };
+
+                let name = Cell::from(highlight_matches(
+                    ellipsize(&row_label(self.app, row), name_width).into_owned(),
+                    self.app.filter.name_match(),
+                ));
+                let cells = if index.closed {
+                    [
+                        name,
+                        Cell::from("-"),
+                        Cell::from("-"),
+                        Cell::from("-"),
+                        Cell::from("closed"),
+                        Cell::from("-"),
+                        replicas_cell(index),
+                        Cell::from("-"),
+                        Cell::from("-"),
+                    ]
+                } else {
+                    // Groups left out of `--stats` have nothing to show
+                    let fetched = |group, text| Cell::from(self.app.if_fetched(&[group], text));
+                    [
+                        name,
+                        fetched(StatGroup::Docs, index.doc_count_human()),
+                        fetched(StatGroup::Indexing, index.rate_human(self.app.rate_unit)),
+                        fetched(
+                            StatGroup::Store,
+                            self.app.format_size(index.size(self.app.show_total_size)),
+                        ),
+                        Cell::from(index.health.as_str()),
+                        unassigned_cell(index.unassigned_shards),
+                        replicas_cell(index),
+                        Cell::from(index.index_latency_human()),
+                        Cell::from(index.query_latency_human()),
+                    ]
+                };
+
+                // Striped by absolute position so stripes scroll with their rows; the
+                // selected row keeps the plain background under its highlight
+                let style = if self.app.zebra
+                    && (offset + position) % 2 == 1
+                    && self.app.selected_index != Some(offset + position)
+                {
+                    style.patch(self.app.theme.stripe)
+                } else {
+                    style
+                };
 
                 Row::new(cells).style(style)
             })
             .collect();
 
-        let widths = [
-            Constraint::Percentage(60),
-            Constraint::Percentage(10),
-            Constraint::Percentage(10),
-            Constraint::Percentage(10),
-            Constraint::Percentage(10),
-        ];
-
         // Create title
-        let spinner = self.app.spinner_char();
+        let spinner = self.app.status_marker();
         let duration = self.app.fetch_duration_display();
         let spinner_color = if self.app.loading {
             Color::Cyan
@@ -136,11 +266,11 @@ impl<'a> StatefulWidget for IndicesTable<'a> {
         let mut title_spans = vec![
             Span::raw(" Indices "),
             Span::styled(
-                format!("{}", spinner),
+                spinner,
                 Style::new().fg(spinner_color).add_modifier(Modifier::BOLD),
             ),
             Span::raw(" "),
-            Span::styled(format!("({})", duration), theme::TIME),
+            Span::styled(format!("({})", duration), self.app.theme.time),
         ];
 
         // Add filter display
@@ -150,24 +280,22 @@ impl<'a> StatefulWidget for IndicesTable<'a> {
             title_spans.push(Span::styled("Filter: ", Style::new().fg(Color::Yellow)));
 
             let filter_style = if self.app.filter.error.is_some() {
-                theme::ERROR
+                self.app.theme.error
             } else if self.app.filter.active {
-                Style::new().fg(Color::White).add_modifier(Modifier::BOLD)
+                self.app.theme.text.add_modifier(Modifier::BOLD)
             } else {
                 Style::new().fg(Color::Green)
             };
 
             if self.app.filter.active {
                 let cursor = self.app.filter.input.cursor();
-                let (before, after) = filter_value.split_at(cursor);
+                let (before, after) = split_at_cursor(filter_value, cursor);
                 if !before.is_empty() {
                     title_spans.push(Span::styled(before.to_string(), filter_style));
                 }
                 title_spans.push(Span::styled(
                     "▏",
-                    Style::new()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::RAPID_BLINK),
+                    self.app.theme.text.add_modifier(Modifier::RAPID_BLINK),
                 ));
                 if !after.is_empty() {
                     title_spans.push(Span::styled(after.to_string(), filter_style));
@@ -179,8 +307,20 @@ impl<'a> StatefulWidget for IndicesTable<'a> {
             // Show match count
             title_spans.push(Span::styled(
                 format!(" ({}/{})", filtered_count, total_count),
-                theme::TIME,
+                self.app.theme.time,
             ));
+
+            if self.app.filter.error.is_some() {
+                let key = if self.app.filter.active {
+                    "Ctrl+e"
+                } else {
+                    "F"
+                };
+                title_spans.push(Span::styled(
+                    format!(" invalid, [{}] for details", key),
+                    self.app.theme.error,
+                ));
+            }
         }
 
         if self.app.paused {
@@ -196,37 +336,344 @@ impl<'a> StatefulWidget for IndicesTable<'a> {
         let border_style = if self.app.paused {
             Style::new().fg(Color::Yellow)
         } else {
-            theme::BORDER
+            self.app.theme.border
         };
 
-        let available_height = area.height.saturating_sub(3) as usize;
-
-        if let Some(selected) = self.app.selected_index {
-            let total_rows = rows.len();
-            if total_rows > available_height {
-                let center_offset = available_height / 2;
-                let ideal_offset = selected.saturating_sub(center_offset);
-                let max_offset = total_rows.saturating_sub(available_height);
-                let offset = ideal_offset.min(max_offset);
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(title);
 
-                *state = (*state).with_offset(offset);
+        // Spell out the selected name when the column cuts it short or shows it transformed
+        if let Some(index) = self
+            .app
+            .selected_index
+            .and_then(|selected| table_rows.get(selected))
+            .and_then(|row| row.as_index())
+        {
+            if index.name.width() > name_width || self.app.display_name(&index.name) != index.name {
+                block = block.title_bottom(Line::from(vec![
+                    Span::raw(" "),
+                    Span::styled(index.name.as_str(), self.app.theme.title),
+                    Span::raw(" "),
+                ]));
             }
         }
 
         let table = Table::new(rows, widths)
             .header(header)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(border_style)
-                    .title(title),
-            )
-            .row_highlight_style(
-                Style::new()
-                    .add_modifier(Modifier::REVERSED)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .block(block)
+            .row_highlight_style(self.app.selection_style.style())
+            .highlight_symbol(self.app.selection_style.symbol());
 
         StatefulWidget::render(table, area, buf, state);
+
+        // Placeholder below the header when there is nothing to list
+        if let Some(message) = self.app.empty_table_message() {
+            let inner = area.inner(Margin::new(1, 1));
+            let body = Rect {
+                y: inner.y.saturating_add(1),
+                height: inner.height.saturating_sub(1),
+                ..inner
+            };
+            Paragraph::new(message)
+                .style(Style::new().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+                .render(body, buf);
+        }
+    }
+}
+
+// Rows of the excluded section, kept out of the gradient range
+fn is_excluded_row(row: &TableRow) -> bool {
+    matches!(row.kind, RowKind::Excluded { .. } | RowKind::ExcludedMember)
+}
+
+/// Name cell text: groups show their member count and fold state, members are indented.
+/// Index names go through the display transforms; group names don't.
+fn row_label(app: &App, row: &TableRow) -> String {
+    let mut name = app.display_name(&row.index.name);
+    if app.is_pinned(&row.index.name) {
+        name = format!("◆ {}", name).into();
+    }
+    match row.kind {
+        RowKind::Index => name.into_owned(),
+        RowKind::Group { members, expanded } => format!(
+            "{} {} ({})",
+            if expanded { "▾" } else { "▸" },
+            row.index.name,
+            members
+        ),
+        RowKind::Member => format!("  {}", name),
+        RowKind::Excluded { members, expanded } => format!(
+            "── {} Excluded ({}) ──",
+            if expanded { "▾" } else { "▸" },
+            members
+        ),
+        RowKind::ExcludedMember => format!("  {}", name),
+    }
+}
+
+/// Splits `text` into spans with every occurrence of `needle` styled as a match.
+fn highlight_matches(text: String, needle: Option<&str>) -> Line<'static> {
+    let Some(needle) = needle else {
+        return Line::from(text);
+    };
+    // Modifiers only, so the row's gradient color shows through
+    let matched = Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let mut spans = Vec::new();
+    let mut rest = text.as_str();
+    while let Some(start) = rest.find(needle) {
+        if start > 0 {
+            spans.push(Span::raw(rest[..start].to_string()));
+        }
+        let end = start + needle.len();
+        spans.push(Span::styled(rest[start..end].to_string(), matched));
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        spans.push(Span::raw(rest.to_string()));
+    }
+    Line::from(spans)
+}
+
+/// Cuts `text` to `width` terminal columns, ending in `…` when anything was dropped.
+/// Measured by display width, so wide (e.g. CJK) characters are never split.
+fn ellipsize(text: &str, width: usize) -> Cow<'_, str> {
+    if text.width() <= width {
+        return Cow::Borrowed(text);
+    }
+
+    let budget = width.saturating_sub(1);
+    let mut used = 0;
+    let mut truncated = String::new();
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width > budget {
+            break;
+        }
+        used += char_width;
+        truncated.push(c);
+    }
+    if width > 0 {
+        truncated.push('…');
+    }
+    Cow::Owned(truncated)
+}
+
+// Any unassigned shard copy needs attention, so a non-zero count is flagged in red
+fn unassigned_cell(count: usize) -> Cell<'static> {
+    let cell = Cell::from(count.to_string());
+    if count > 0 {
+        cell.style(Style::new().fg(Color::Red).add_modifier(Modifier::BOLD))
+    } else {
+        cell
+    }
+}
+
+// Fixed count or auto-expand range; `-` when the settings couldn't be read
+fn replicas_cell(index: &IndexRate) -> Cell<'static> {
+    Cell::from(
+        index
+            .replicas
+            .as_ref()
+            .map_or("-".to_string(), ToString::to_string),
+    )
+}
+
+// Gradient value: the fewest replicas the setting allows
+fn replicas_value(index: &IndexRate) -> f64 {
+    index
+        .replicas
+        .as_ref()
+        .map_or(0.0, |replicas| replicas.sort_key().0 as f64)
+}
+
+// Indices taking less of the cluster indexing rate than this stay neutral in share coloring
+const MIN_WRITE_SHARE: f64 = 0.05;
+
+/// Fraction of the cluster rate `rate` accounts for, or None below `MIN_WRITE_SHARE`
+/// (including when the cluster isn't indexing at all).
+fn write_share(rate: f64, cluster_rate: f64) -> Option<f64> {
+    if cluster_rate <= 0.0 {
+        return None;
+    }
+    let share = (rate / cluster_rate).clamp(0.0, 1.0);
+    (share >= MIN_WRITE_SHARE).then_some(share)
+}
+
+// Newest tier first; ages beyond the last threshold share the final color
+const AGE_TIER_COLORS: [Color; 4] = [Color::Green, Color::Cyan, Color::Yellow, Color::DarkGray];
+
+/// Tier of an index age: the number of (ascending) thresholds it has passed.
+fn age_tier(age: chrono::Duration, thresholds: &[chrono::Duration]) -> usize {
+    thresholds.iter().take_while(|&&limit| age >= limit).count()
+}
+
+/// First row to display so the selection stays centered, clamped so the last page is full.
+fn visible_offset(total_rows: usize, available_height: usize, selected: Option<usize>) -> usize {
+    match selected {
+        Some(selected) if total_rows > available_height => {
+            let ideal_offset = selected.saturating_sub(available_height / 2);
+            let max_offset = total_rows - available_height;
+            ideal_offset.min(max_offset)
+        }
+        _ => 0,
+    }
+}
+
+// Neutral position used when there is no spread of values to color
+const NEUTRAL_POSITION: f32 = 0.5;
+
+/// Maps a value to a colormap position in `0.0..=1.0`, where the largest value gets `0.0`.
+///
+/// `scale` maps the value first (logarithmic by default). When every value is zero or
+/// all values are equal there is nothing to differentiate, so a neutral mid-gradient
+/// position is returned instead of one of the extremes.
+fn gradient_position(value: f64, min_value: f64, max_value: f64, scale: GradientScale) -> f32 {
+    if max_value <= 0.0 || min_value >= max_value {
+        return NEUTRAL_POSITION;
+    }
+
+    1.0 - scale.fraction(value, max_value) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_age_tier() {
+        let tiers = [chrono::Duration::days(1), chrono::Duration::days(7)];
+        assert_eq!(age_tier(chrono::Duration::hours(3), &tiers), 0);
+        assert_eq!(age_tier(chrono::Duration::days(1), &tiers), 1);
+        assert_eq!(age_tier(chrono::Duration::days(30), &tiers), 2);
+        assert_eq!(age_tier(chrono::Duration::days(30), &[]), 0);
+    }
+
+    #[test]
+    fn test_visible_offset_centers_selection() {
+        // Everything fits, or nothing selected
+        assert_eq!(visible_offset(5, 10, Some(4)), 0);
+        assert_eq!(visible_offset(100, 10, None), 0);
+
+        // Near the top, in the middle, and clamped at the end
+        assert_eq!(visible_offset(100, 10, Some(3)), 0);
+        assert_eq!(visible_offset(100, 10, Some(50)), 45);
+        assert_eq!(visible_offset(100, 10, Some(99)), 90);
+    }
+
+    #[test]
+    fn test_highlight_matches_every_occurrence() {
+        let line = highlight_matches("logs-app-logs".to_string(), Some("logs"));
+        let parts: Vec<(&str, bool)> = line
+            .spans
+            .iter()
+            .map(|span| {
+                (
+                    span.content.as_ref(),
+                    span.style.add_modifier.contains(Modifier::UNDERLINED),
+                )
+            })
+            .collect();
+        assert_eq!(parts, [("logs", true), ("-app-", false), ("logs", true)]);
+
+        assert_eq!(highlight_matches("logs".to_string(), None).spans.len(), 1);
+    }
+
+    #[test]
+    fn test_ellipsize_by_display_width() {
+        assert_eq!(ellipsize("logs-app", 8), "logs-app");
+        assert_eq!(ellipsize("logs-app-2024", 8), "logs-ap…");
+
+        // Each CJK character is two columns wide; a half-fitting one is dropped whole
+        assert_eq!(ellipsize("日志索引", 8), "日志索引");
+        assert_eq!(ellipsize("日志索引", 6), "日志…");
+        assert_eq!(ellipsize("日志索引", 5), "日志…");
+    }
+
+    #[test]
+    fn test_write_share_threshold() {
+        assert_eq!(write_share(90.0, 100.0), Some(0.9));
+        assert_eq!(write_share(5.0, 100.0), Some(0.05));
+        assert_eq!(write_share(4.0, 100.0), None);
+        assert_eq!(write_share(0.0, 0.0), None);
+        // Excluded indices can out-rate the total they don't count toward
+        assert_eq!(write_share(150.0, 100.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_gradient_position_zero_max_is_neutral() {
+        assert_eq!(
+            gradient_position(0.0, 0.0, 0.0, GradientScale::Log),
+            NEUTRAL_POSITION
+        );
+    }
+
+    #[test]
+    fn test_gradient_position_all_equal_is_neutral() {
+        assert_eq!(
+            gradient_position(42.0, 42.0, 42.0, GradientScale::Linear),
+            NEUTRAL_POSITION
+        );
+    }
+
+    #[test]
+    fn test_gradient_position_spread() {
+        for scale in [
+            GradientScale::Linear,
+            GradientScale::Log,
+            GradientScale::Sqrt,
+        ] {
+            assert_eq!(gradient_position(100.0, 0.0, 100.0, scale), 0.0);
+            assert_eq!(gradient_position(0.0, 0.0, 100.0, scale), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_default_scale_keeps_baseline_colors() {
+        assert_eq!(GradientScale::default(), GradientScale::Log);
+        // 1 - ln(1 + v) / ln(1 + max), whatever the smallest shown value
+        for (value, min, max) in [(25.0, 0.0, 100.0), (90.0, 80.0, 100.0), (3.0, 1.0, 1e6)] {
+            let baseline = 1.0 - ((1.0_f64 + value).ln() / (1.0_f64 + max).ln()) as f32;
+            assert_eq!(
+                gradient_position(value, min, max, GradientScale::default()),
+                baseline
+            );
+        }
+    }
+
+    #[test]
+    fn test_gradient_position_per_scale() {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+
+        assert!(close(
+            gradient_position(25.0, 0.0, 100.0, GradientScale::Linear),
+            0.75
+        ));
+        assert!(close(
+            gradient_position(25.0, 0.0, 100.0, GradientScale::Sqrt),
+            0.5
+        ));
+        // ln(26) / ln(101)
+        assert!(close(
+            gradient_position(25.0, 0.0, 100.0, GradientScale::Log),
+            0.294
+        ));
+
+        // Close-together values: log barely separates them, linear does
+        assert!(close(
+            gradient_position(90.0, 80.0, 100.0, GradientScale::Linear),
+            0.1
+        ));
+        assert!(close(
+            gradient_position(90.0, 80.0, 100.0, GradientScale::Sqrt),
+            0.051
+        ));
+        assert!(close(
+            gradient_position(90.0, 80.0, 100.0, GradientScale::Log),
+            0.023
+        ));
     }
 }
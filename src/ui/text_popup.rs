@@ -0,0 +1,78 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::app::App;
+
+pub struct TextPopup<'a> {
+    app: &'a App,
+}
+
+impl<'a> TextPopup<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl<'a> Widget for TextPopup<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let state = &self.app.text_popup;
+
+        // Calculate popup area (80% of screen, centered)
+        let popup_width = (area.width as f32 * 0.8) as u16;
+        let popup_height = (area.height as f32 * 0.8) as u16;
+        let popup_x = (area.width - popup_width) / 2;
+        let popup_y = (area.height - popup_height) / 2;
+        let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+        // Clear the popup area
+        Clear.render(popup_area, buf);
+
+        let lines: Vec<Line> = if state.loading {
+            vec![Line::from(Span::styled(
+                "Loading...",
+                Style::new().fg(Color::Yellow),
+            ))]
+        } else if let Some(ref error) = state.error {
+            vec![Line::from(Span::styled(
+                format!("Error: {}", error),
+                self.app.theme.error,
+            ))]
+        } else {
+            state
+                .text
+                .as_deref()
+                .unwrap_or_default()
+                .lines()
+                .map(|line| Line::styled(line, self.app.theme.text))
+                .collect()
+        };
+
+        // Apply scroll offset; long lines are clipped rather than wrapped so stack frames stay aligned
+        let visible_height = popup_height.saturating_sub(2) as usize;
+        let max_scroll = lines.len().saturating_sub(visible_height);
+        let scroll = state.scroll.min(max_scroll);
+
+        let title = Line::from(vec![
+            Span::raw(format!(" {} ", state.title)),
+            Span::styled(
+                "[Esc/Enter] Close  [j/k] Scroll  [PgUp/PgDn] Page ",
+                Style::new().fg(Color::DarkGray),
+            ),
+        ]);
+
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::new().fg(Color::Cyan));
+
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((scroll as u16, 0))
+            .render(popup_area, buf);
+    }
+}
@@ -1,8 +1,198 @@
+use std::time::Duration;
+
 use ratatui::style::{Color, Modifier, Style};
 
-pub const TITLE: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
-pub const ERROR: Style = Style::new().fg(Color::Red);
-pub const TIME: Style = Style::new().fg(Color::DarkGray);
-pub const URL: Style = Style::new().fg(Color::Green);
-pub const RATE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
-pub const BORDER: Style = Style::new().fg(Color::DarkGray);
+use super::types::ThemeMode;
+
+// How long to wait for the terminal to answer the background color query
+const QUERY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Palette shared by all widgets, picked once at startup for the terminal background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub title: Style,
+    pub error: Style,
+    pub time: Style,
+    pub url: Style,
+    pub rate: Style,
+    pub border: Style,
+    /// Regular foreground text
+    pub text: Style,
+    /// Column header row of the indices table
+    pub table_header: Style,
+    /// Background of every other table row when zebra striping is on
+    pub stripe: Style,
+}
+
+impl Theme {
+    pub const DARK: Theme = Theme {
+        title: Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        error: Style::new().fg(Color::Red),
+        time: Style::new().fg(Color::DarkGray),
+        url: Style::new().fg(Color::Green),
+        rate: Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        border: Style::new().fg(Color::DarkGray),
+        text: Style::new().fg(Color::White),
+        table_header: Style::new().bg(Color::DarkGray),
+        stripe: Style::new().bg(Color::Indexed(235)),
+    };
+
+    pub const LIGHT: Theme = Theme {
+        title: Style::new().fg(Color::Blue).add_modifier(Modifier::BOLD),
+        error: Style::new().fg(Color::Red),
+        time: Style::new().fg(Color::Gray),
+        url: Style::new().fg(Color::Green),
+        rate: Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+        border: Style::new().fg(Color::Gray),
+        text: Style::new().fg(Color::Black),
+        table_header: Style::new().bg(Color::Gray),
+        stripe: Style::new().bg(Color::Indexed(254)),
+    };
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::DARK
+    }
+}
+
+/// Resolves the theme for the requested mode.
+///
+/// `Auto` asks the terminal for its background color (OSC 11), then falls back to
+/// the `COLORFGBG` environment variable, and finally to the dark theme.
+/// Must be called before the terminal enters the alternate screen.
+pub fn resolve(mode: ThemeMode) -> Theme {
+    let light = match mode {
+        ThemeMode::Dark => false,
+        ThemeMode::Light => true,
+        ThemeMode::Auto => query_background_is_light()
+            .or_else(|| {
+                std::env::var("COLORFGBG")
+                    .ok()
+                    .and_then(|value| colorfgbg_is_light(&value))
+            })
+            .unwrap_or(false),
+    };
+
+    if light {
+        Theme::LIGHT
+    } else {
+        Theme::DARK
+    }
+}
+
+// COLORFGBG is "fg;bg" (sometimes "fg;default;bg") using ANSI color indices
+fn colorfgbg_is_light(value: &str) -> Option<bool> {
+    let bg: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    Some(bg == 7 || bg >= 9)
+}
+
+// Parses a reply like "\x1b]11;rgb:ffff/ffff/ffff\x07" into a light/dark verdict
+fn osc11_is_light(response: &[u8]) -> Option<bool> {
+    let text = std::str::from_utf8(response).ok()?;
+    let start = text.find("rgb:")? + 4;
+    let end = text[start..]
+        .find(['\x07', '\x1b'])
+        .map(|i| start + i)
+        .unwrap_or(text.len());
+
+    let channels: Vec<f64> = text[start..end]
+        .split('/')
+        .map(|hex| {
+            let max = 16_f64.powi(hex.len() as i32) - 1.0;
+            u32::from_str_radix(hex, 16).ok().map(|v| v as f64 / max)
+        })
+        .collect::<Option<_>>()?;
+
+    let [r, g, b] = channels[..] else {
+        return None;
+    };
+    let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    Some(luminance > 0.5)
+}
+
+#[cfg(unix)]
+fn query_background_is_light() -> Option<bool> {
+    use std::io::{IsTerminal, Write};
+    use std::os::fd::AsFd;
+    use std::time::Instant;
+
+    use rustix::event::{poll, PollFd, PollFlags, Timespec};
+
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    if !stdin.is_terminal() || !stdout.is_terminal() {
+        return None;
+    }
+
+    crossterm::terminal::enable_raw_mode().ok()?;
+
+    // Follow the OSC 11 query with a DA1 request, which every terminal answers.
+    // If the DA1 reply arrives without an OSC 11 reply, the terminal doesn't support it.
+    let response = (|| {
+        stdout.write_all(b"\x1b]11;?\x07\x1b[c").ok()?;
+        stdout.flush().ok()?;
+
+        let deadline = Instant::now() + QUERY_TIMEOUT;
+        let mut response = Vec::new();
+        let mut chunk = [0_u8; 64];
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let timeout = Timespec::try_from(remaining).ok()?;
+            let mut fds = [PollFd::new(&stdin, PollFlags::IN)];
+            if remaining.is_zero() || poll(&mut fds, Some(&timeout)).ok()? == 0 {
+                return None;
+            }
+
+            let read = rustix::io::read(stdin.as_fd(), &mut chunk).ok()?;
+            if read == 0 {
+                return None;
+            }
+            response.extend_from_slice(&chunk[..read]);
+
+            // DA1 reply is "\x1b[?...c"
+            if let Some(da1) = response.windows(3).position(|w| w == b"\x1b[?") {
+                if response[da1..].contains(&b'c') {
+                    return Some(response);
+                }
+            }
+        }
+    })();
+
+    let _ = crossterm::terminal::disable_raw_mode();
+    osc11_is_light(&response?)
+}
+
+#[cfg(not(unix))]
+fn query_background_is_light() -> Option<bool> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colorfgbg_detection() {
+        assert_eq!(colorfgbg_is_light("15;0"), Some(false));
+        assert_eq!(colorfgbg_is_light("0;15"), Some(true));
+        assert_eq!(colorfgbg_is_light("0;default;7"), Some(true));
+        assert_eq!(colorfgbg_is_light("garbage"), None);
+    }
+
+    #[test]
+    fn test_osc11_detection() {
+        assert_eq!(
+            osc11_is_light(b"\x1b]11;rgb:ffff/ffff/ffff\x07\x1b[?62c"),
+            Some(true)
+        );
+        assert_eq!(osc11_is_light(b"\x1b]11;rgb:1e/1e/2e\x1b\\"), Some(false));
+        assert_eq!(osc11_is_light(b"\x1b[?62c"), None);
+    }
+
+    #[test]
+    fn test_explicit_mode_skips_detection() {
+        assert_eq!(resolve(ThemeMode::Light), Theme::LIGHT);
+        assert_eq!(resolve(ThemeMode::Dark), Theme::DARK);
+    }
+}
@@ -1,7 +1,26 @@
-use colorgrad::{preset, Gradient};
-use ratatui::style::Color;
+use crate::utils::{format_bytes, format_number};
+use chrono::{DateTime, Local, Utc};
+use colorgrad::{preset, Gradient, GradientBuilder, LinearGradient};
+use ratatui::style::{Color, Modifier, Style};
 use std::fmt;
 use std::str::FromStr;
+use std::sync::OnceLock;
+
+// User-defined gradient for `Colormap::Custom`, built once from the config file
+static CUSTOM_GRADIENT: OnceLock<LinearGradient> = OnceLock::new();
+
+// Build the custom colormap from hex color stops. Only the first call takes effect.
+pub fn set_custom_gradient(stops: &[String]) -> Result<(), String> {
+    let _ = CUSTOM_GRADIENT.set(custom_gradient(stops)?);
+    Ok(())
+}
+
+fn custom_gradient(stops: &[String]) -> Result<LinearGradient, String> {
+    GradientBuilder::new()
+        .html_colors(stops)
+        .build::<LinearGradient>()
+        .map_err(|e| format!("Invalid custom colormap: {}", e))
+}
 
 // Available colormaps for gradient visualization
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -12,11 +31,15 @@ pub enum Colormap {
     Inferno,
     Magma,
     Plasma,
+    PlasmaReverse,
     Viridis,
     Rainbow,
     Cividis,
     Warm,
     Cool,
+    Greyscale,
+    /// Gradient from the config file's `[colormaps] custom` stops
+    Custom,
 }
 
 impl Colormap {
@@ -24,6 +47,7 @@ impl Colormap {
         Colormap::Inferno,
         Colormap::Magma,
         Colormap::Plasma,
+        Colormap::PlasmaReverse,
         Colormap::Viridis,
         Colormap::Turbo,
         Colormap::Spectral,
@@ -31,39 +55,42 @@ impl Colormap {
         Colormap::Cividis,
         Colormap::Warm,
         Colormap::Cool,
+        Colormap::Greyscale,
+        Colormap::Custom,
     ];
 
+    // Custom only counts once a gradient has been configured
+    pub fn is_available(&self) -> bool {
+        *self != Colormap::Custom || CUSTOM_GRADIENT.get().is_some()
+    }
+
     pub fn next(&self) -> Self {
-        match self {
-            Colormap::Inferno => Colormap::Magma,
-            Colormap::Magma => Colormap::Plasma,
-            Colormap::Plasma => Colormap::Viridis,
-            Colormap::Viridis => Colormap::Turbo,
-            Colormap::Turbo => Colormap::Spectral,
-            Colormap::Spectral => Colormap::Rainbow,
-            Colormap::Rainbow => Colormap::Cividis,
-            Colormap::Cividis => Colormap::Warm,
-            Colormap::Warm => Colormap::Cool,
-            Colormap::Cool => Colormap::Inferno,
-        }
+        self.step(true)
     }
 
     pub fn prev(&self) -> Self {
-        match self {
-            Colormap::Inferno => Colormap::Cool,
-            Colormap::Magma => Colormap::Inferno,
-            Colormap::Plasma => Colormap::Magma,
-            Colormap::Viridis => Colormap::Plasma,
-            Colormap::Turbo => Colormap::Viridis,
-            Colormap::Spectral => Colormap::Turbo,
-            Colormap::Rainbow => Colormap::Spectral,
-            Colormap::Cividis => Colormap::Rainbow,
-            Colormap::Warm => Colormap::Cividis,
-            Colormap::Cool => Colormap::Warm,
-        }
+        self.step(false)
+    }
+
+    // Neighbour in ALL order, wrapping around and skipping unavailable colormaps
+    fn step(&self, forward: bool) -> Self {
+        let available: Vec<Colormap> = Self::ALL
+            .iter()
+            .copied()
+            .filter(Colormap::is_available)
+            .collect();
+        let len = available.len();
+        let position = available.iter().position(|c| c == self).unwrap_or(0);
+        let next = if forward {
+            position + 1
+        } else {
+            position + len - 1
+        };
+        available[next % len]
     }
 
-    // Generate a color from this colormap at a given position (0.0 to 1.0)
+    // Generate a color from this colormap at a given position (0.0 to 1.0).
+    // Position 0.0 is the end of the preset gradient and 1.0 its start.
     pub fn color_at(&self, position: f32) -> Color {
         let t = position.clamp(0.0, 1.0);
         let rgba = match self {
@@ -72,11 +99,18 @@ impl Colormap {
             Colormap::Inferno => preset::inferno().at(1.0 - t).to_rgba8(),
             Colormap::Magma => preset::magma().at(1.0 - t).to_rgba8(),
             Colormap::Plasma => preset::plasma().at(1.0 - t).to_rgba8(),
+            Colormap::PlasmaReverse => preset::plasma().at(t).to_rgba8(),
             Colormap::Viridis => preset::viridis().at(1.0 - t).to_rgba8(),
             Colormap::Rainbow => preset::rainbow().at(1.0 - t).to_rgba8(),
             Colormap::Cividis => preset::cividis().at(1.0 - t).to_rgba8(),
             Colormap::Warm => preset::warm().at(1.0 - t).to_rgba8(),
             Colormap::Cool => preset::cool().at(1.0 - t).to_rgba8(),
+            // The greys preset runs white to black; keep the largest values brightest
+            Colormap::Greyscale => preset::greys().at(t).to_rgba8(),
+            Colormap::Custom => match CUSTOM_GRADIENT.get() {
+                Some(gradient) => gradient.at(1.0 - t).to_rgba8(),
+                None => preset::greys().at(t).to_rgba8(),
+            },
         };
         let [r, g, b, _] = rgba;
         Color::Rgb(r, g, b)
@@ -91,11 +125,14 @@ impl fmt::Display for Colormap {
             Colormap::Inferno => write!(f, "inferno"),
             Colormap::Magma => write!(f, "magma"),
             Colormap::Plasma => write!(f, "plasma"),
+            Colormap::PlasmaReverse => write!(f, "plasma-reverse"),
             Colormap::Viridis => write!(f, "viridis"),
             Colormap::Rainbow => write!(f, "rainbow"),
             Colormap::Cividis => write!(f, "cividis"),
             Colormap::Warm => write!(f, "warm"),
             Colormap::Cool => write!(f, "cool"),
+            Colormap::Greyscale => write!(f, "greyscale"),
+            Colormap::Custom => write!(f, "custom"),
         }
     }
 }
@@ -110,11 +147,14 @@ impl FromStr for Colormap {
             "inferno" => Ok(Colormap::Inferno),
             "magma" => Ok(Colormap::Magma),
             "plasma" => Ok(Colormap::Plasma),
+            "plasma-reverse" => Ok(Colormap::PlasmaReverse),
             "viridis" => Ok(Colormap::Viridis),
             "rainbow" => Ok(Colormap::Rainbow),
             "cividis" => Ok(Colormap::Cividis),
             "warm" => Ok(Colormap::Warm),
             "cool" => Ok(Colormap::Cool),
+            "greyscale" | "grayscale" => Ok(Colormap::Greyscale),
+            "custom" => Ok(Colormap::Custom),
             _ => Err(format!(
                 "Unknown colormap '{}'. Available: {}",
                 s,
@@ -128,7 +168,551 @@ impl FromStr for Colormap {
     }
 }
 
+// Cluster-wide series that the history chart can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartMetric {
+    #[default]
+    IndexingRate,
+    BytesRate,
+    DocCount,
+    UnassignedShards,
+}
+
+impl ChartMetric {
+    pub fn next(&self) -> Self {
+        match self {
+            ChartMetric::IndexingRate => ChartMetric::BytesRate,
+            ChartMetric::BytesRate => ChartMetric::DocCount,
+            ChartMetric::DocCount => ChartMetric::UnassignedShards,
+            ChartMetric::UnassignedShards => ChartMetric::IndexingRate,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ChartMetric::IndexingRate => "Indexing Rate",
+            ChartMetric::BytesRate => "Ingest Throughput",
+            ChartMetric::DocCount => "Document Count",
+            ChartMetric::UnassignedShards => "Unassigned Shards",
+        }
+    }
+
+    // Convert a stored per-second value to the display unit; counts are left alone
+    pub fn scale(&self, value: f64, unit: RateUnit) -> f64 {
+        match self {
+            ChartMetric::IndexingRate | ChartMetric::BytesRate => unit.scale(value),
+            ChartMetric::DocCount | ChartMetric::UnassignedShards => value,
+        }
+    }
+
+    // Format a value of this metric for axis labels
+    pub fn format_value(&self, value: f64) -> String {
+        match self {
+            ChartMetric::IndexingRate | ChartMetric::DocCount | ChartMetric::UnassignedShards => {
+                format_number(value)
+            }
+            ChartMetric::BytesRate => format_bytes(value as u64),
+        }
+    }
+
+    // Format an already scaled value of this metric including its unit
+    pub fn format_with_unit(&self, value: f64, unit: RateUnit) -> String {
+        match self {
+            ChartMetric::IndexingRate => format!("{} {}", self.format_value(value), unit.suffix()),
+            ChartMetric::BytesRate => format!("{}{}", self.format_value(value), unit.suffix()),
+            ChartMetric::DocCount | ChartMetric::UnassignedShards => self.format_value(value),
+        }
+    }
+}
+
+// Time unit rates are displayed in; rates are always computed per second
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateUnit {
+    #[default]
+    Sec,
+    Min,
+}
+
+impl RateUnit {
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            RateUnit::Sec => "/s",
+            RateUnit::Min => "/min",
+        }
+    }
+
+    // Convert a per-second rate to this unit
+    pub fn scale(&self, per_sec: f64) -> f64 {
+        match self {
+            RateUnit::Sec => per_sec,
+            RateUnit::Min => per_sec * 60.0,
+        }
+    }
+}
+
+impl fmt::Display for RateUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateUnit::Sec => write!(f, "sec"),
+            RateUnit::Min => write!(f, "min"),
+        }
+    }
+}
+
+impl FromStr for RateUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sec" => Ok(RateUnit::Sec),
+            "min" => Ok(RateUnit::Min),
+            _ => Err(format!("Unknown rate unit '{}'. Available: sec, min", s)),
+        }
+    }
+}
+
+// How values map onto the row color gradient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientScale {
+    Linear,
+    /// Spreads colors over values spanning orders of magnitude
+    #[default]
+    Log,
+    /// Between the two, for values that are close together
+    Sqrt,
+}
+
+impl GradientScale {
+    // Fraction of `max` that `value` represents on this scale, 0.0..=1.0 for 0..=max
+    pub fn fraction(&self, value: f64, max: f64) -> f64 {
+        match self {
+            GradientScale::Linear => value / max,
+            GradientScale::Log => (1.0 + value).ln() / (1.0 + max).ln(),
+            GradientScale::Sqrt => value.sqrt() / max.sqrt(),
+        }
+    }
+}
+
+impl fmt::Display for GradientScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GradientScale::Linear => write!(f, "linear"),
+            GradientScale::Log => write!(f, "log"),
+            GradientScale::Sqrt => write!(f, "sqrt"),
+        }
+    }
+}
+
+impl FromStr for GradientScale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "linear" => Ok(GradientScale::Linear),
+            "log" => Ok(GradientScale::Log),
+            "sqrt" => Ok(GradientScale::Sqrt),
+            _ => Err(format!(
+                "Unknown gradient scale '{}'. Available: linear, log, sqrt",
+                s
+            )),
+        }
+    }
+}
+
+// Timezone used when displaying timestamps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timezone {
+    #[default]
+    Local,
+    Utc,
+    Named(chrono_tz::Tz),
+}
+
+impl Timezone {
+    // Format a UTC timestamp in this timezone, appending the zone name except for local time
+    pub fn format(&self, datetime: DateTime<Utc>) -> String {
+        const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+        match self {
+            Timezone::Local => datetime.with_timezone(&Local).format(FORMAT).to_string(),
+            Timezone::Utc => format!("{} UTC", datetime.format(FORMAT)),
+            Timezone::Named(tz) => datetime
+                .with_timezone(tz)
+                .format(&format!("{} %Z", FORMAT))
+                .to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Timezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Timezone::Local => write!(f, "local"),
+            Timezone::Utc => write!(f, "utc"),
+            Timezone::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "local" => Ok(Timezone::Local),
+            "utc" => Ok(Timezone::Utc),
+            _ => s
+                .parse::<chrono_tz::Tz>()
+                .map(Timezone::Named)
+                .map_err(|_| {
+                    format!(
+                        "Unknown timezone '{}'. Use local, utc or an IANA name like Europe/Lisbon",
+                        s
+                    )
+                }),
+        }
+    }
+}
+
+// Top-level screens selectable from the tab bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum View {
+    #[default]
+    Overview,
+    Indices,
+    Compare,
+    Nodes,
+}
+
+impl View {
+    pub const ALL: [View; 4] = [View::Overview, View::Indices, View::Compare, View::Nodes];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            View::Overview => "Overview",
+            View::Indices => "Indices",
+            View::Compare => "Compare",
+            View::Nodes => "Nodes",
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        View::ALL.iter().position(|v| v == self).unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Self {
+        View::ALL[(self.position() + 1) % View::ALL.len()]
+    }
+
+    pub fn prev(&self) -> Self {
+        View::ALL[(self.position() + View::ALL.len() - 1) % View::ALL.len()]
+    }
+
+    // 1-based, matching the numbers shown in the tab bar
+    pub fn from_number(number: usize) -> Option<Self> {
+        View::ALL.get(number.checked_sub(1)?).copied()
+    }
+}
+
+// How index rows in the table are colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// Colormap gradient over the active sort column's values
+    #[default]
+    Gradient,
+    /// Tier of the index's age since creation
+    Age,
+    /// The index's share of the cluster indexing rate
+    Share,
+}
+
+impl ColorMode {
+    pub fn next(&self) -> Self {
+        match self {
+            ColorMode::Gradient => ColorMode::Age,
+            ColorMode::Age => ColorMode::Share,
+            ColorMode::Share => ColorMode::Gradient,
+        }
+    }
+}
+
+impl fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorMode::Gradient => write!(f, "gradient"),
+            ColorMode::Age => write!(f, "age"),
+            ColorMode::Share => write!(f, "share"),
+        }
+    }
+}
+
+// Arrangement of the chart and health widgets when both are visible
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RowLayout {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl RowLayout {
+    pub fn toggle(&self) -> Self {
+        match self {
+            RowLayout::Horizontal => RowLayout::Vertical,
+            RowLayout::Vertical => RowLayout::Horizontal,
+        }
+    }
+}
+
+impl fmt::Display for RowLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowLayout::Horizontal => write!(f, "horizontal"),
+            RowLayout::Vertical => write!(f, "vertical"),
+        }
+    }
+}
+
+impl FromStr for RowLayout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "horizontal" => Ok(RowLayout::Horizontal),
+            "vertical" => Ok(RowLayout::Vertical),
+            _ => Err(format!(
+                "Unknown layout '{}'. Available: horizontal, vertical",
+                s
+            )),
+        }
+    }
+}
+
+// Frame set used for the loading spinner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpinnerStyle {
+    #[default]
+    Braille,
+    Ascii,
+    Dots,
+}
+
+impl SpinnerStyle {
+    pub fn frames(&self) -> &'static [char] {
+        match self {
+            SpinnerStyle::Braille => &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'],
+            SpinnerStyle::Ascii => &['|', '/', '-', '\\'],
+            SpinnerStyle::Dots => &['.', 'o', 'O', 'o'],
+        }
+    }
+
+    // Marker shown when no fetch is in progress
+    pub fn idle_char(&self) -> char {
+        match self {
+            SpinnerStyle::Braille => '✓',
+            SpinnerStyle::Ascii | SpinnerStyle::Dots => '*',
+        }
+    }
+
+    // Only the braille style assumes a Nerd Font; the others stay plain ASCII
+    pub fn health_icons(&self) -> &'static HealthIcons {
+        match self {
+            SpinnerStyle::Braille => &HealthIcons::NERD,
+            SpinnerStyle::Ascii | SpinnerStyle::Dots => &HealthIcons::ASCII,
+        }
+    }
+}
+
+// Glyphs prefixing each cluster health metric
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthIcons {
+    pub cluster: &'static str,
+    pub status: &'static str,
+    pub nodes: &'static str,
+    pub data_nodes: &'static str,
+    pub primaries: &'static str,
+    pub active: &'static str,
+    pub percent: &'static str,
+    pub relocating: &'static str,
+    pub initializing: &'static str,
+    pub unassigned: &'static str,
+    pub pending: &'static str,
+}
+
+impl HealthIcons {
+    pub const NERD: Self = Self {
+        cluster: "󰆼",
+        status: "♥",
+        nodes: "󰄳",
+        data_nodes: "󰋊",
+        primaries: "P",
+        active: "A",
+        percent: "%",
+        relocating: "󰪹",
+        initializing: "󰗖",
+        unassigned: "󰀦",
+        pending: "󱎫",
+    };
+
+    pub const ASCII: Self = Self {
+        cluster: "#",
+        status: "*",
+        nodes: "N",
+        data_nodes: "D",
+        primaries: "P",
+        active: "A",
+        percent: "%",
+        relocating: ">",
+        initializing: "+",
+        unassigned: "!",
+        pending: "T",
+    };
+}
+
+impl fmt::Display for SpinnerStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpinnerStyle::Braille => write!(f, "braille"),
+            SpinnerStyle::Ascii => write!(f, "ascii"),
+            SpinnerStyle::Dots => write!(f, "dots"),
+        }
+    }
+}
+
+impl FromStr for SpinnerStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "braille" => Ok(SpinnerStyle::Braille),
+            "ascii" => Ok(SpinnerStyle::Ascii),
+            "dots" => Ok(SpinnerStyle::Dots),
+            _ => Err(format!(
+                "Unknown spinner '{}'. Available: braille, ascii, dots",
+                s
+            )),
+        }
+    }
+}
+
+// How the indices title shows whether a fetch is running
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatusIndicator {
+    /// Spinner frames while loading, an idle glyph otherwise
+    #[default]
+    Icon,
+    /// The words "loading" and "idle"
+    Text,
+}
+
+impl fmt::Display for StatusIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusIndicator::Icon => write!(f, "icon"),
+            StatusIndicator::Text => write!(f, "text"),
+        }
+    }
+}
+
+impl FromStr for StatusIndicator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "icon" => Ok(StatusIndicator::Icon),
+            "text" => Ok(StatusIndicator::Text),
+            _ => Err(format!(
+                "Unknown status indicator '{}'. Available: icon, text",
+                s
+            )),
+        }
+    }
+}
+
+// How the selected table row stands out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionStyle {
+    #[default]
+    Reversed,
+    Underline,
+    /// A marker left of the row, leaving its colors untouched
+    Bar,
+    /// A fixed background color under the row's own foreground
+    Background(Color),
+}
+
+impl SelectionStyle {
+    pub fn style(&self) -> Style {
+        let style = Style::new().add_modifier(Modifier::BOLD);
+        match self {
+            SelectionStyle::Reversed => style.add_modifier(Modifier::REVERSED),
+            SelectionStyle::Underline => style.add_modifier(Modifier::UNDERLINED),
+            SelectionStyle::Bar => style,
+            SelectionStyle::Background(color) => style.bg(*color),
+        }
+    }
+
+    // Drawn in a column of its own before the selected row
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SelectionStyle::Bar => "▌ ",
+            _ => "",
+        }
+    }
+}
+
+impl FromStr for SelectionStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "reversed" => Ok(SelectionStyle::Reversed),
+            "underline" => Ok(SelectionStyle::Underline),
+            "bar" => Ok(SelectionStyle::Bar),
+            _ => s.parse().map(SelectionStyle::Background).map_err(|_| {
+                format!(
+                    "Unknown selection style '{}'. Available: reversed, underline, bar, or a background color",
+                    s
+                )
+            }),
+        }
+    }
+}
+
+// Terminal color theme selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+    #[default]
+    Auto,
+    Dark,
+    Light,
+}
+
+impl fmt::Display for ThemeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeMode::Auto => write!(f, "auto"),
+            ThemeMode::Dark => write!(f, "dark"),
+            ThemeMode::Light => write!(f, "light"),
+        }
+    }
+}
+
+impl FromStr for ThemeMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(ThemeMode::Auto),
+            "dark" => Ok(ThemeMode::Dark),
+            "light" => Ok(ThemeMode::Light),
+            _ => Err(format!(
+                "Unknown theme '{}'. Available: auto, dark, light",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum SortColumn {
     Name,
     DocCount,
@@ -136,6 +720,10 @@ pub enum SortColumn {
     Rate,
     Size,
     Health,
+    Unassigned,
+    Replicas,
+    IndexLatency,
+    QueryLatency,
 }
 
 impl SortColumn {
@@ -145,17 +733,25 @@ impl SortColumn {
             SortColumn::DocCount => SortColumn::Rate,
             SortColumn::Rate => SortColumn::Size,
             SortColumn::Size => SortColumn::Health,
-            SortColumn::Health => SortColumn::Name,
+            SortColumn::Health => SortColumn::Unassigned,
+            SortColumn::Unassigned => SortColumn::Replicas,
+            SortColumn::Replicas => SortColumn::IndexLatency,
+            SortColumn::IndexLatency => SortColumn::QueryLatency,
+            SortColumn::QueryLatency => SortColumn::Name,
         }
     }
 
     pub fn prev(&self) -> Self {
         match self {
-            SortColumn::Name => SortColumn::Health,
+            SortColumn::Name => SortColumn::QueryLatency,
             SortColumn::DocCount => SortColumn::Name,
             SortColumn::Rate => SortColumn::DocCount,
             SortColumn::Size => SortColumn::Rate,
             SortColumn::Health => SortColumn::Size,
+            SortColumn::Unassigned => SortColumn::Health,
+            SortColumn::Replicas => SortColumn::Unassigned,
+            SortColumn::IndexLatency => SortColumn::Replicas,
+            SortColumn::QueryLatency => SortColumn::IndexLatency,
         }
     }
 }
@@ -175,3 +771,48 @@ impl SortOrder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_unit_scales_rates_only() {
+        assert_eq!(RateUnit::Min.scale(0.5), 30.0);
+        assert_eq!(RateUnit::Sec.scale(0.5), 0.5);
+        assert_eq!(ChartMetric::DocCount.scale(10.0, RateUnit::Min), 10.0);
+
+        let per_min = ChartMetric::IndexingRate.scale(2.0, RateUnit::Min);
+        assert_eq!(
+            ChartMetric::IndexingRate.format_with_unit(per_min, RateUnit::Min),
+            "120.0 /min"
+        );
+        assert_eq!(
+            ChartMetric::IndexingRate.format_with_unit(2.0, RateUnit::Sec),
+            "2.0 /s"
+        );
+    }
+
+    #[test]
+    fn test_custom_gradient_stops() {
+        let stops = ["#000080".to_string(), "#ff0000".to_string()];
+        let gradient = custom_gradient(&stops).unwrap();
+        assert_eq!(gradient.at(0.0).to_rgba8(), [0, 0, 128, 255]);
+        assert_eq!(gradient.at(1.0).to_rgba8(), [255, 0, 0, 255]);
+
+        assert!(custom_gradient(&["#000080".to_string(), "not-a-color".to_string()]).is_err());
+        assert_eq!("custom".parse::<Colormap>(), Ok(Colormap::Custom));
+    }
+
+    #[test]
+    fn test_greyscale_keeps_largest_values_brightest() {
+        let brightness = |position| match Colormap::Greyscale.color_at(position) {
+            Color::Rgb(r, g, b) => r as u32 + g as u32 + b as u32,
+            color => panic!("unexpected color {:?}", color),
+        };
+        // Position 0.0 is where the largest value lands
+        assert!(brightness(0.0) > brightness(0.5));
+        assert!(brightness(0.5) > brightness(1.0));
+        assert_eq!("grayscale".parse::<Colormap>(), Ok(Colormap::Greyscale));
+    }
+}
@@ -0,0 +1,76 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+use crate::app::watch::WatchState;
+use crate::app::App;
+use crate::ui::chart::RateChart;
+use crate::ui::details_popup::details_lines;
+
+/// Focused layout for a single index: its history chart above its details.
+pub struct WatchView<'a> {
+    app: &'a App,
+    watch: &'a WatchState,
+}
+
+impl<'a> WatchView<'a> {
+    pub fn new(app: &'a App, watch: &'a WatchState) -> Self {
+        Self { app, watch }
+    }
+}
+
+impl<'a> Widget for WatchView<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [chart_area, details_area] =
+            Layout::vertical([Constraint::Length(12), Constraint::Min(0)]).areas(area);
+
+        let history = self.watch.history_vec(self.app.chart_metric);
+        RateChart::for_index(self.app, &self.watch.index, history).render(chart_area, buf);
+
+        let found = self.app.indices.iter().any(|i| i.name == self.watch.index);
+        let mut lines = if found || self.app.indices.is_empty() {
+            details_lines(self.app, &self.watch.details)
+        } else {
+            vec![Line::from(Span::styled(
+                format!("Index '{}' not found", self.watch.index),
+                self.app.theme.error,
+            ))]
+        };
+        // Nothing fetched yet until the first refresh completes
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "Waiting for index data...",
+                Style::new().fg(Color::Yellow),
+            )));
+        }
+
+        // Account for borders
+        let visible_height = details_area.height.saturating_sub(2) as usize;
+        let scroll = self
+            .watch
+            .details
+            .scroll
+            .min(lines.len().saturating_sub(visible_height));
+
+        let title = Line::from(vec![
+            Span::raw(" Watching "),
+            Span::styled(&self.watch.index, self.app.theme.title),
+            Span::styled(" [Esc] Back  [j/k] Scroll ", self.app.theme.time),
+        ]);
+
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(self.app.theme.border),
+            )
+            .scroll((scroll as u16, 0))
+            .wrap(Wrap { trim: false })
+            .render(details_area, buf);
+    }
+}
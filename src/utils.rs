@@ -1,5 +1,32 @@
 use human_format::{Formatter, Scales};
 
+// Replace NaN/infinite values (e.g. from dividing by a tiny elapsed time) with zero
+pub fn finite_or_zero(value: f64) -> f64 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
+// Rank health statuses so transitions can be compared (higher is worse)
+pub fn health_severity(status: &str) -> Option<u8> {
+    match status {
+        "green" => Some(0),
+        "yellow" => Some(1),
+        "red" => Some(2),
+        _ => None,
+    }
+}
+
+// True when the status moved to a worse, known health
+pub fn health_worsened(old_status: &str, new_status: &str) -> bool {
+    match (health_severity(old_status), health_severity(new_status)) {
+        (Some(old), Some(new)) => new > old,
+        _ => false,
+    }
+}
+
 // Format a number with SI suffixes (K, M, B, T)
 pub fn format_number(value: f64) -> String {
     Formatter::new()
@@ -17,3 +44,79 @@ pub fn format_bytes(bytes: u64) -> String {
         .with_units("B")
         .format(bytes as f64)
 }
+
+// Exact byte count with thousands separators, e.g. `1,234,567 B`
+pub fn format_bytes_exact(bytes: u64) -> String {
+    let digits = bytes.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    format!("{grouped} B")
+}
+
+// Parse an Elasticsearch time value such as `30d`, `12h` or `500ms`
+pub fn parse_es_duration(value: &str) -> Option<chrono::Duration> {
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount.parse().ok()?;
+    match unit {
+        "d" => chrono::Duration::try_days(amount),
+        "h" => chrono::Duration::try_hours(amount),
+        "m" => chrono::Duration::try_minutes(amount),
+        "s" => chrono::Duration::try_seconds(amount),
+        "ms" => chrono::Duration::try_milliseconds(amount),
+        _ => None,
+    }
+}
+
+// Format a duration as its two largest units, e.g. `3d 4h` or `12m 5s`
+pub fn format_duration(duration: chrono::Duration) -> String {
+    let secs = duration.num_seconds().max(0);
+    let (days, hours, mins) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else if mins > 0 {
+        format!("{}m {}s", mins, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+// Splits `text` at a text input's cursor, which counts characters rather than bytes
+pub fn split_at_cursor(text: &str, cursor: usize) -> (&str, &str) {
+    let byte = text
+        .char_indices()
+        .nth(cursor)
+        .map_or(text.len(), |(i, _)| i);
+    text.split_at(byte)
+}
+
+// Glob matching where `*` stands for any run of characters (e.g. `logs-*-prod*`)
+pub fn glob_matches(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // split always yields at least one part
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*` at all: the prefix must be the whole name
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}